@@ -0,0 +1,499 @@
+//! Contains the [`Anonymizer`], which replaces the tags and names of players and clubs in models
+//! (see the [`Anonymize`] trait) or in raw API JSON (see [`Anonymizer::anonymize_json`]) with
+//! pseudonyms, so that recorded API data can be shared (e.g. in bug reports) without exposing
+//! anyone's identity.
+//!
+//! The same `Anonymizer` always gives the same pseudonym for the same tag (or name), so the links
+//! between models (e.g. a [`Club`]'s members, the [`BattlePlayer`]s of a battle log and a
+//! [`PlayerRanking`] of the same player) are kept when anonymizing a set of models or files
+//! with it. Everything else (structure, stats, brawlers, events...) is left untouched, except for
+//! club descriptions, which are cleared.
+//!
+//! The `brawl-api anonymize` command (in this crate's binary) applies this to JSON files.
+//!
+//! [`Anonymizer`]: struct.Anonymizer.html
+//! [`Anonymize`]: trait.Anonymize.html
+//! [`Anonymizer::anonymize_json`]: struct.Anonymizer.html#method.anonymize_json
+//! [`Club`]: ../model/clubs/struct.Club.html
+//! [`BattlePlayer`]: ../model/players/battlelog/struct.BattlePlayer.html
+//! [`PlayerRanking`]: ../model/rankings/players/struct.PlayerRanking.html
+
+use std::collections::HashMap;
+use serde_json::Value as JsonValue;
+
+use crate::constants::TAG_CHARS;
+
+#[cfg(feature = "players")]
+use crate::model::players::{
+    Player, PlayerClub,
+    battlelog::{BattleLog, Battle, BattlePlayer},
+};
+
+#[cfg(feature = "clubs")]
+use crate::model::clubs::{Club, ClubMember, ClubMembers};
+
+#[cfg(feature = "rankings")]
+use crate::model::rankings::{
+    players::{PlayerLeaderboard, PlayerRanking},
+    clubs::{ClubLeaderboard, ClubRanking},
+    brawlers::BrawlerLeaderboard,
+};
+
+/// The JSON keys which only occur in club objects.
+const CLUB_KEYS: &[&str] = &["badgeId", "members", "memberCount", "requiredTrophies"];
+
+/// Consistently replaces the tags and names of players and clubs with pseudonyms.
+///
+/// Tags are replaced with valid tags (e.g. `#P0000002`), and names with `Player <n>` or
+/// `Club <n>`, numbered in order of appearance. Tags are compared regardless of their leading
+/// `#` (or `%23`) and of case, so `#abc` and `ABC` give the same pseudonym.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::anonymize::Anonymizer;
+///
+/// let mut anonymizer = Anonymizer::new();
+/// let mut battlelog = serde_json::json!({
+///     "items": [{ "battle": { "starPlayer": {
+///         "tag": "#RRRAAALLL", "name": "Other User",
+///         "brawler": { "id": 16000001, "name": "COLT", "power": 8, "trophies": 510 }
+///     } } }]
+/// });
+/// anonymizer.anonymize_json(&mut battlelog);
+///
+/// let star_player = &battlelog["items"][0]["battle"]["starPlayer"];
+/// assert_eq!(star_player["tag"], "#P0000000");
+/// assert_eq!(star_player["name"], "Player 1");
+/// assert_eq!(star_player["brawler"]["name"], "COLT");  // not an identity
+///
+/// // the same tag always gives the same pseudonym
+/// assert_eq!(anonymizer.tag("rrraaalll"), "#P0000000");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Anonymizer {
+    /// The pseudonym of each (normalized) tag.
+    tags: HashMap<String, String>,
+
+    /// The pseudonym of each player name.
+    player_names: HashMap<String, String>,
+
+    /// The pseudonym of each club name.
+    club_names: HashMap<String, String>,
+}
+
+impl Anonymizer {
+    /// Creates a new `Anonymizer`, which has not given any pseudonyms yet.
+    pub fn new() -> Anonymizer {
+        Anonymizer::default()
+    }
+
+    /// Returns the pseudonym of a player or club tag (always the same for the same tag).
+    pub fn tag(&mut self, tag: &str) -> String {
+        let trimmed = tag.trim();
+        let bare = trimmed.strip_prefix('#')
+            .or_else(|| trimmed.strip_prefix("%23"))
+            .unwrap_or(trimmed)
+            .to_uppercase();
+
+        let count = self.tags.len();
+        self.tags.entry(bare).or_insert_with(|| pseudonym_tag(count)).clone()
+    }
+
+    /// Returns the pseudonym of a player's name (always the same for the same name). Empty names
+    /// are kept empty.
+    pub fn player_name(&mut self, name: &str) -> String {
+        pseudonym_name(&mut self.player_names, "Player", name)
+    }
+
+    /// Returns the pseudonym of a club's name (always the same for the same name). Empty names
+    /// are kept empty.
+    pub fn club_name(&mut self, name: &str) -> String {
+        pseudonym_name(&mut self.club_names, "Club", name)
+    }
+
+    /// Anonymizes a model (or a collection of models) in place. This is the same as calling
+    /// `model.anonymize(self)` (see [`Anonymize`]).
+    ///
+    /// [`Anonymize`]: trait.Anonymize.html
+    pub fn anonymize<T: Anonymize + ?Sized>(&mut self, model: &mut T) {
+        model.anonymize(self);
+    }
+
+    /// Anonymizes raw JSON, as given by the API (for any endpoint), in place.
+    ///
+    /// Player and club identities are objects with a `tag` field, as well as any `club` object
+    /// (e.g. in a player ranking, which only has the club's name). Their `tag` and `name` are
+    /// replaced with pseudonyms, and club descriptions are cleared. Other names (of brawlers,
+    /// maps, star powers...) are kept.
+    pub fn anonymize_json(&mut self, value: &mut JsonValue) {
+        self.anonymize_json_value(value, false);
+    }
+
+    /// Anonymizes a JSON value, which is a club object if `is_club` (i.e., it is under a `club`
+    /// key).
+    fn anonymize_json_value(&mut self, value: &mut JsonValue, is_club: bool) {
+        match value {
+            JsonValue::Array(items) => {
+                for item in items {
+                    self.anonymize_json_value(item, false);
+                }
+            },
+            JsonValue::Object(map) => {
+                let is_club = is_club || CLUB_KEYS.iter().any(|&key| map.contains_key(key));
+                let is_identity = is_club || map.contains_key("tag");
+
+                for (key, field) in map.iter_mut() {
+                    match (key.as_str(), field) {
+                        ("tag", JsonValue::String(tag)) if is_identity && !tag.is_empty() => {
+                            *tag = self.tag(tag);
+                        },
+                        ("name", JsonValue::String(name)) if is_identity => {
+                            *name = if is_club {
+                                self.club_name(name)
+                            } else {
+                                self.player_name(name)
+                            };
+                        },
+                        ("description", JsonValue::String(description)) if is_club => {
+                            description.clear();
+                        },
+                        (key, field) => self.anonymize_json_value(field, key == "club"),
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Creates the `index`-th pseudonym tag: `#P` followed by `index` in base 14, with the tag
+/// characters as digits.
+fn pseudonym_tag(mut index: usize) -> String {
+    let digits = TAG_CHARS.as_bytes();
+    let mut chars = [digits[0]; 7];
+    for slot in chars.iter_mut().rev() {
+        *slot = digits[index % digits.len()];
+        index /= digits.len();
+    }
+    format!("#P{}", String::from_utf8_lossy(&chars))
+}
+
+/// Gets (or creates) the pseudonym of a name, numbered in order of appearance.
+fn pseudonym_name(names: &mut HashMap<String, String>, kind: &str, name: &str) -> String {
+    if name.is_empty() {
+        return String::new();
+    }
+
+    let count = names.len();
+    names.entry(String::from(name))
+        .or_insert_with(|| format!("{} {}", kind, count + 1))
+        .clone()
+}
+
+/// A trait for models which contain the identity (tag or name) of players or clubs, which can be
+/// replaced by pseudonyms through an [`Anonymizer`].
+///
+/// All models holding tags or names of players or clubs implement this trait, as do vectors
+/// and slices of them.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::{Club, ClubMember, ClubMembers};
+/// use brawl_api::anonymize::{Anonymizer, Anonymize};
+///
+/// let member = ClubMember { tag: String::from("#ABC"), ..ClubMember::default() };
+/// let mut club = Club {
+///     tag: String::from("#CLUB"),
+///     members: ClubMembers { tag: String::from("#CLUB"), items: vec![member], paging: None },
+///     ..Club::default()
+/// };
+///
+/// let mut anonymizer = Anonymizer::new();
+/// club.anonymize(&mut anonymizer);
+///
+/// assert_eq!(club.members.tag, club.tag);
+/// assert_eq!(club.members[0].tag, anonymizer.tag("#ABC"));
+/// ```
+///
+/// [`Anonymizer`]: struct.Anonymizer.html
+pub trait Anonymize {
+    /// Replaces the tags and names of players and clubs in this model with their pseudonyms
+    /// given by the `anonymizer`.
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer);
+}
+
+impl<T: Anonymize> Anonymize for [T] {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        for model in self {
+            model.anonymize(anonymizer);
+        }
+    }
+}
+
+impl<T: Anonymize> Anonymize for Vec<T> {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        self.as_mut_slice().anonymize(anonymizer);
+    }
+}
+
+impl<T: Anonymize> Anonymize for Option<T> {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        if let Some(model) = self {
+            model.anonymize(anonymizer);
+        }
+    }
+}
+
+impl Anonymize for JsonValue {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        anonymizer.anonymize_json(self);
+    }
+}
+
+/// Replaces a (non-empty) tag with its pseudonym.
+#[cfg(any(feature = "players", feature = "clubs", feature = "rankings"))]
+fn anonymize_tag(tag: &mut String, anonymizer: &mut Anonymizer) {
+    if !tag.is_empty() {
+        *tag = anonymizer.tag(tag);
+    }
+}
+
+#[cfg(feature = "players")]
+impl Anonymize for Player {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        anonymize_tag(&mut self.tag, anonymizer);
+        self.name = anonymizer.player_name(&self.name);
+        self.club.anonymize(anonymizer);
+    }
+}
+
+#[cfg(feature = "players")]
+impl Anonymize for PlayerClub {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        anonymize_tag(&mut self.tag, anonymizer);
+        self.name = anonymizer.club_name(&self.name);
+    }
+}
+
+#[cfg(feature = "players")]
+impl Anonymize for BattleLog {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        anonymize_tag(&mut self.tag, anonymizer);
+        self.items.anonymize(anonymizer);
+    }
+}
+
+#[cfg(feature = "players")]
+impl Anonymize for Battle {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        self.result.star_player.anonymize(anonymizer);
+        if let Some(teams) = &mut self.result.teams {
+            for team in teams {
+                team.anonymize(anonymizer);
+            }
+        }
+        self.result.players.anonymize(anonymizer);
+    }
+}
+
+#[cfg(feature = "players")]
+impl Anonymize for BattlePlayer {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        anonymize_tag(&mut self.tag, anonymizer);
+        self.name = anonymizer.player_name(&self.name);
+    }
+}
+
+#[cfg(feature = "clubs")]
+impl Anonymize for Club {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        anonymize_tag(&mut self.tag, anonymizer);
+        self.name = anonymizer.club_name(&self.name);
+        if let Some(description) = &mut self.description {
+            description.clear();
+        }
+        self.members.anonymize(anonymizer);
+    }
+}
+
+#[cfg(feature = "clubs")]
+impl Anonymize for ClubMembers {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        anonymize_tag(&mut self.tag, anonymizer);
+        self.items.anonymize(anonymizer);
+    }
+}
+
+#[cfg(feature = "clubs")]
+impl Anonymize for ClubMember {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        anonymize_tag(&mut self.tag, anonymizer);
+        self.name = anonymizer.player_name(&self.name);
+    }
+}
+
+#[cfg(feature = "rankings")]
+impl Anonymize for PlayerLeaderboard {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        self.items.anonymize(anonymizer);
+    }
+}
+
+#[cfg(feature = "rankings")]
+impl Anonymize for PlayerRanking {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        anonymize_tag(&mut self.tag, anonymizer);
+        self.name = anonymizer.player_name(&self.name);
+        self.club.name = anonymizer.club_name(&self.club.name);
+    }
+}
+
+#[cfg(feature = "rankings")]
+impl Anonymize for ClubLeaderboard {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        self.items.anonymize(anonymizer);
+    }
+}
+
+#[cfg(feature = "rankings")]
+impl Anonymize for ClubRanking {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        anonymize_tag(&mut self.tag, anonymizer);
+        self.name = anonymizer.club_name(&self.name);
+    }
+}
+
+#[cfg(feature = "rankings")]
+impl Anonymize for BrawlerLeaderboard {
+    fn anonymize(&mut self, anonymizer: &mut Anonymizer) {
+        self.items.anonymize(anonymizer);
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::Anonymizer;
+
+    /// Tests that identities are replaced consistently across JSON documents, while everything
+    /// else is kept.
+    #[test]
+    fn anonymize_json_consistent() {
+        let mut anonymizer = Anonymizer::new();
+
+        let mut club = json!({
+            "tag": "#CLUB", "name": "My Club", "description": "Join us, I'm Bob!",
+            "trophies": 1000, "requiredTrophies": 0, "badgeId": 8000000,
+            "members": [
+                { "tag": "#BOB", "name": "Bob", "role": "president", "trophies": 600 },
+                { "tag": "#ALICE", "name": "Alice", "role": "member", "trophies": 400 }
+            ]
+        });
+        let mut player = json!({
+            "tag": "#ALICE", "name": "Alice", "trophies": 400,
+            "club": { "tag": "#CLUB", "name": "My Club" },
+            "brawlers": [{ "id": 16000000, "name": "SHELLY", "starPowers": [] }]
+        });
+        let mut rankings = json!({
+            "items": [{ "tag": "#BOB", "name": "Bob", "rank": 1, "club": { "name": "My Club" } }]
+        });
+
+        anonymizer.anonymize_json(&mut club);
+        anonymizer.anonymize_json(&mut player);
+        anonymizer.anonymize_json(&mut rankings);
+
+        assert_eq!(club["tag"], player["club"]["tag"]);
+        assert_eq!(club["name"], "Club 1");
+        assert_eq!(club["name"], player["club"]["name"]);
+        assert_eq!(club["name"], rankings["items"][0]["club"]["name"]);
+        assert_eq!(club["description"], "");
+        assert_eq!(club["members"][1]["tag"], player["tag"]);
+        assert_eq!(club["members"][1]["name"], player["name"]);
+        assert_eq!(club["members"][0]["tag"], rankings["items"][0]["tag"]);
+        assert_ne!(club["members"][0]["tag"], club["members"][1]["tag"]);
+
+        assert_eq!(club["members"][0]["trophies"], 600);
+        assert_eq!(player["brawlers"][0]["name"], "SHELLY");
+        assert!(!club.to_string().contains("Bob") && !player.to_string().contains("Alice"));
+    }
+
+    /// Tests that anonymizing a club, a battle log and a leaderboard with the same anonymizer
+    /// keeps the links between them (the same player or club gets the same pseudonym).
+    #[cfg(all(feature = "players", feature = "clubs", feature = "rankings"))]
+    #[test]
+    fn anonymize_models_linked() -> Result<(), Box<dyn ::std::error::Error>> {
+        use crate::model::clubs::Club;
+        use crate::model::players::BattleLog;
+        use crate::model::rankings::PlayerLeaderboard;
+
+        let mut club: Club = serde_json::from_value(json!({
+            "tag": "#CLUB", "name": "My Club", "description": "Bob's club",
+            "members": [
+                { "tag": "#BOB", "name": "Bob", "role": "president" },
+                { "tag": "#ALICE", "name": "Alice", "role": "member" }
+            ]
+        }))?;
+        let mut battlelog: BattleLog = serde_json::from_value(json!({
+            "items": [{ "battle": {
+                "starPlayer": { "tag": "#ALICE", "name": "Alice" },
+                "teams": [
+                    [{ "tag": "#ALICE", "name": "Alice" }, { "tag": "#EVE", "name": "Eve" }],
+                    [{ "tag": "#BOB", "name": "Bob" }]
+                ]
+            } }]
+        }))?;
+        battlelog.tag = String::from("#ALICE");
+        let mut leaderboard: PlayerLeaderboard = serde_json::from_value(json!({
+            "items": [
+                { "tag": "#BOB", "name": "Bob", "rank": 1, "club": { "name": "My Club" } },
+                { "tag": "#EVE", "name": "Eve", "rank": 2 }
+            ]
+        }))?;
+
+        let mut anonymizer = Anonymizer::new();
+        anonymizer.anonymize(&mut club);
+        anonymizer.anonymize(&mut battlelog);
+        anonymizer.anonymize(&mut leaderboard);
+
+        let (bob, alice) = (&club.members[0], &club.members[1]);
+        let battle = &battlelog[0].result;
+        let teams = battle.teams.as_ref().unwrap();
+        let star_player = battle.star_player.as_ref().unwrap();
+
+        assert_eq!(club.description.as_deref(), Some(""));
+        assert_eq!(battlelog.tag, alice.tag);
+        assert_eq!((&star_player.tag, &star_player.name), (&alice.tag, &alice.name));
+        assert_eq!((&teams[0][0].tag, &teams[0][0].name), (&alice.tag, &alice.name));
+        assert_eq!((&teams[1][0].tag, &teams[1][0].name), (&bob.tag, &bob.name));
+        assert_eq!((&leaderboard[0].tag, &leaderboard[0].name), (&bob.tag, &bob.name));
+        assert_eq!(leaderboard[0].club.name, club.name);
+        let eve = &teams[0][1];
+        assert_eq!((&leaderboard[1].tag, &leaderboard[1].name), (&eve.tag, &eve.name));
+
+        assert_ne!(alice.tag, bob.tag);
+        assert_ne!(eve.tag, bob.tag);
+        assert!(![&club.name, &alice.name, &bob.name, &eve.name].iter()
+            .any(|name| ["My Club", "Alice", "Bob", "Eve"].contains(&name.as_str())));
+
+        Ok(())
+    }
+
+    /// Tests the normalization of tags and the validity of pseudonym tags.
+    #[test]
+    fn anonymize_tags() {
+        let mut anonymizer = Anonymizer::new();
+        assert_eq!(anonymizer.tag("#abc"), "#P0000000");
+        assert_eq!(anonymizer.tag("%23ABC"), "#P0000000");
+        assert_eq!(anonymizer.tag("ABC"), "#P0000000");
+        assert_eq!(anonymizer.tag("#DEF"), "#P0000002");
+
+        for i in 0..20 {
+            anonymizer.tag(&i.to_string());
+        }
+        assert_eq!(anonymizer.tag("19"), "#P000002Q");  // index 21, i.e. "17" in base 14
+    }
+}
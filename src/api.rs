@@ -0,0 +1,766 @@
+//! Contains the [`BrawlApi`] and [`AsyncBrawlApi`] traits, object-safe interfaces with one method
+//! per API endpoint, implemented by [`Client`]. Code which depends on them (e.g. through a
+//! `&dyn BrawlApi` or an `Arc<dyn AsyncBrawlApi>`) instead of on `Client` can then be unit tested
+//! with a [`FakeBrawlApi`], which serves preloaded values from memory.
+//!
+//! [`BrawlApi`]: trait.BrawlApi.html
+//! [`AsyncBrawlApi`]: trait.AsyncBrawlApi.html
+//! [`Client`]: ../http/client/struct.Client.html
+//! [`FakeBrawlApi`]: struct.FakeBrawlApi.html
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use http::StatusCode;
+
+use crate::error::{Result, Error, APIError};
+use crate::http::Client;
+use crate::http::routes::Route;
+use crate::traits::Endpoint;
+
+#[cfg(feature = "async")]
+use async_trait::async_trait;
+
+#[cfg(any(feature = "players", feature = "clubs"))]
+use crate::util::auto_hashtag;
+
+#[cfg(any(feature = "rankings", feature = "brawlers"))]
+use crate::ids::BrawlerId;
+
+#[cfg(feature = "rankings")]
+use crate::traits::{RankingParams, BrawlerRankingParams};
+
+#[cfg(feature = "players")]
+use crate::model::players::{Player, BattleLog};
+
+#[cfg(feature = "clubs")]
+use crate::model::clubs::{Club, ClubMembers};
+
+#[cfg(feature = "rankings")]
+use crate::model::rankings::{PlayerLeaderboard, ClubLeaderboard, BrawlerLeaderboard};
+
+#[cfg(feature = "brawlers")]
+use crate::model::brawlers::{BrawlerList, Brawler};
+
+/// (Sync) An object-safe interface to the API, with one method per endpoint. It is implemented
+/// by [`Client`] (through its facade methods, such as [`Client::player`]) and by
+/// [`FakeBrawlApi`] (for unit testing).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use brawl_api::{Client, api::BrawlApi};
+///
+/// fn trophies(api: &dyn BrawlApi, tag: &str) -> brawl_api::Result<usize> {
+///     Ok(api.player(tag)?.trophies)
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+/// let my_client = Client::new("my auth token");
+/// println!("{}", trophies(&my_client, "#PLAYERTAGHERE")?);
+///
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Client`]: ../http/client/struct.Client.html
+/// [`Client::player`]: ../http/client/struct.Client.html#method.player
+/// [`FakeBrawlApi`]: struct.FakeBrawlApi.html
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+pub trait BrawlApi: Send + Sync {
+    /// (Sync) Fetches a [`Player`] by its tag.
+    ///
+    /// [`Player`]: ../model/players/player/struct.Player.html
+    #[cfg(feature = "players")]
+    fn player(&self, tag: &str) -> Result<Player>;
+
+    /// (Sync) Fetches a player's recent battles ([`BattleLog`]) by their tag.
+    ///
+    /// [`BattleLog`]: ../model/players/battlelog/struct.BattleLog.html
+    #[cfg(feature = "players")]
+    fn battlelog(&self, tag: &str) -> Result<BattleLog>;
+
+    /// (Sync) Fetches a [`Club`] by its tag.
+    ///
+    /// [`Club`]: ../model/clubs/struct.Club.html
+    #[cfg(feature = "clubs")]
+    fn club(&self, tag: &str) -> Result<Club>;
+
+    /// (Sync) Fetches a club's [`ClubMembers`] by its tag.
+    ///
+    /// [`ClubMembers`]: ../model/clubs/struct.ClubMembers.html
+    #[cfg(feature = "clubs")]
+    fn club_members(&self, tag: &str) -> Result<ClubMembers>;
+
+    /// (Sync) Fetches the [`PlayerLeaderboard`] of a country (or `"global"`), up to `limit`
+    /// rankings.
+    ///
+    /// [`PlayerLeaderboard`]: ../model/rankings/players/struct.PlayerLeaderboard.html
+    #[cfg(feature = "rankings")]
+    fn player_rankings(&self, country_code: &str, limit: u8) -> Result<PlayerLeaderboard>;
+
+    /// (Sync) Fetches the [`ClubLeaderboard`] of a country (or `"global"`), up to `limit`
+    /// rankings.
+    ///
+    /// [`ClubLeaderboard`]: ../model/rankings/clubs/struct.ClubLeaderboard.html
+    #[cfg(feature = "rankings")]
+    fn club_rankings(&self, country_code: &str, limit: u8) -> Result<ClubLeaderboard>;
+
+    /// (Sync) Fetches the [`BrawlerLeaderboard`] of a brawler in a country (or `"global"`), up
+    /// to `limit` rankings.
+    ///
+    /// [`BrawlerLeaderboard`]: ../model/rankings/brawlers/struct.BrawlerLeaderboard.html
+    #[cfg(feature = "rankings")]
+    fn brawler_rankings(
+        &self, country_code: &str, brawler_id: BrawlerId, limit: u8
+    ) -> Result<BrawlerLeaderboard>;
+
+    /// (Sync) Fetches the [`BrawlerList`] of all brawlers in the game.
+    ///
+    /// [`BrawlerList`]: ../model/brawlers/struct.BrawlerList.html
+    #[cfg(feature = "brawlers")]
+    fn brawlers(&self) -> Result<BrawlerList>;
+
+    /// (Sync) Fetches a [`Brawler`] by its ID.
+    ///
+    /// [`Brawler`]: ../model/brawlers/struct.Brawler.html
+    #[cfg(feature = "brawlers")]
+    fn brawler(&self, id: BrawlerId) -> Result<Brawler>;
+}
+
+/// (Async) An object-safe interface to the API, with one method per endpoint. It is implemented
+/// by [`Client`] (through its facade methods, such as [`Client::a_player`]) and by
+/// [`FakeBrawlApi`] (for unit testing). See [`BrawlApi`] for the sync version.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use brawl_api::{Client, api::AsyncBrawlApi};
+///
+/// async fn trophies(api: Arc<dyn AsyncBrawlApi>, tag: &str) -> brawl_api::Result<usize> {
+///     Ok(api.a_player(tag).await?.trophies)
+/// }
+///
+/// # async fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+/// let my_client = Arc::new(Client::new("my auth token"));
+/// println!("{}", trophies(my_client, "#PLAYERTAGHERE").await?);
+///
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Client`]: ../http/client/struct.Client.html
+/// [`Client::a_player`]: ../http/client/struct.Client.html#method.a_player
+/// [`FakeBrawlApi`]: struct.FakeBrawlApi.html
+/// [`BrawlApi`]: trait.BrawlApi.html
+#[cfg(feature = "async")]
+#[async_trait]
+pub trait AsyncBrawlApi: Send + Sync {
+    /// (Async) Fetches a [`Player`] by its tag.
+    ///
+    /// [`Player`]: ../model/players/player/struct.Player.html
+    #[cfg(feature = "players")]
+    async fn a_player(&self, tag: &str) -> Result<Player>;
+
+    /// (Async) Fetches a player's recent battles ([`BattleLog`]) by their tag.
+    ///
+    /// [`BattleLog`]: ../model/players/battlelog/struct.BattleLog.html
+    #[cfg(feature = "players")]
+    async fn a_battlelog(&self, tag: &str) -> Result<BattleLog>;
+
+    /// (Async) Fetches a [`Club`] by its tag.
+    ///
+    /// [`Club`]: ../model/clubs/struct.Club.html
+    #[cfg(feature = "clubs")]
+    async fn a_club(&self, tag: &str) -> Result<Club>;
+
+    /// (Async) Fetches a club's [`ClubMembers`] by its tag.
+    ///
+    /// [`ClubMembers`]: ../model/clubs/struct.ClubMembers.html
+    #[cfg(feature = "clubs")]
+    async fn a_club_members(&self, tag: &str) -> Result<ClubMembers>;
+
+    /// (Async) Fetches the [`PlayerLeaderboard`] of a country (or `"global"`), up to `limit`
+    /// rankings.
+    ///
+    /// [`PlayerLeaderboard`]: ../model/rankings/players/struct.PlayerLeaderboard.html
+    #[cfg(feature = "rankings")]
+    async fn a_player_rankings(
+        &self, country_code: &str, limit: u8
+    ) -> Result<PlayerLeaderboard>;
+
+    /// (Async) Fetches the [`ClubLeaderboard`] of a country (or `"global"`), up to `limit`
+    /// rankings.
+    ///
+    /// [`ClubLeaderboard`]: ../model/rankings/clubs/struct.ClubLeaderboard.html
+    #[cfg(feature = "rankings")]
+    async fn a_club_rankings(&self, country_code: &str, limit: u8) -> Result<ClubLeaderboard>;
+
+    /// (Async) Fetches the [`BrawlerLeaderboard`] of a brawler in a country (or `"global"`), up
+    /// to `limit` rankings.
+    ///
+    /// [`BrawlerLeaderboard`]: ../model/rankings/brawlers/struct.BrawlerLeaderboard.html
+    #[cfg(feature = "rankings")]
+    async fn a_brawler_rankings(
+        &self, country_code: &str, brawler_id: BrawlerId, limit: u8
+    ) -> Result<BrawlerLeaderboard>;
+
+    /// (Async) Fetches the [`BrawlerList`] of all brawlers in the game.
+    ///
+    /// [`BrawlerList`]: ../model/brawlers/struct.BrawlerList.html
+    #[cfg(feature = "brawlers")]
+    async fn a_brawlers(&self) -> Result<BrawlerList>;
+
+    /// (Async) Fetches a [`Brawler`] by its ID.
+    ///
+    /// [`Brawler`]: ../model/brawlers/struct.Brawler.html
+    #[cfg(feature = "brawlers")]
+    async fn a_brawler(&self, id: BrawlerId) -> Result<Brawler>;
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+impl BrawlApi for Client {
+    #[cfg(feature = "players")]
+    fn player(&self, tag: &str) -> Result<Player> { Client::player(self, tag) }
+
+    #[cfg(feature = "players")]
+    fn battlelog(&self, tag: &str) -> Result<BattleLog> { Client::battlelog(self, tag) }
+
+    #[cfg(feature = "clubs")]
+    fn club(&self, tag: &str) -> Result<Club> { Client::club(self, tag) }
+
+    #[cfg(feature = "clubs")]
+    fn club_members(&self, tag: &str) -> Result<ClubMembers> { Client::club_members(self, tag) }
+
+    #[cfg(feature = "rankings")]
+    fn player_rankings(&self, country_code: &str, limit: u8) -> Result<PlayerLeaderboard> {
+        Client::player_rankings(self, country_code, limit)
+    }
+
+    #[cfg(feature = "rankings")]
+    fn club_rankings(&self, country_code: &str, limit: u8) -> Result<ClubLeaderboard> {
+        Client::club_rankings(self, country_code, limit)
+    }
+
+    #[cfg(feature = "rankings")]
+    fn brawler_rankings(
+        &self, country_code: &str, brawler_id: BrawlerId, limit: u8
+    ) -> Result<BrawlerLeaderboard> {
+        Client::brawler_rankings(self, country_code, brawler_id, limit)
+    }
+
+    #[cfg(feature = "brawlers")]
+    fn brawlers(&self) -> Result<BrawlerList> { Client::brawlers(self) }
+
+    #[cfg(feature = "brawlers")]
+    fn brawler(&self, id: BrawlerId) -> Result<Brawler> { Client::brawler(self, id) }
+}
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl AsyncBrawlApi for Client {
+    #[cfg(feature = "players")]
+    async fn a_player(&self, tag: &str) -> Result<Player> { Client::a_player(self, tag).await }
+
+    #[cfg(feature = "players")]
+    async fn a_battlelog(&self, tag: &str) -> Result<BattleLog> {
+        Client::a_battlelog(self, tag).await
+    }
+
+    #[cfg(feature = "clubs")]
+    async fn a_club(&self, tag: &str) -> Result<Club> { Client::a_club(self, tag).await }
+
+    #[cfg(feature = "clubs")]
+    async fn a_club_members(&self, tag: &str) -> Result<ClubMembers> {
+        Client::a_club_members(self, tag).await
+    }
+
+    #[cfg(feature = "rankings")]
+    async fn a_player_rankings(
+        &self, country_code: &str, limit: u8
+    ) -> Result<PlayerLeaderboard> {
+        Client::a_player_rankings(self, country_code, limit).await
+    }
+
+    #[cfg(feature = "rankings")]
+    async fn a_club_rankings(&self, country_code: &str, limit: u8) -> Result<ClubLeaderboard> {
+        Client::a_club_rankings(self, country_code, limit).await
+    }
+
+    #[cfg(feature = "rankings")]
+    async fn a_brawler_rankings(
+        &self, country_code: &str, brawler_id: BrawlerId, limit: u8
+    ) -> Result<BrawlerLeaderboard> {
+        Client::a_brawler_rankings(self, country_code, brawler_id, limit).await
+    }
+
+    #[cfg(feature = "brawlers")]
+    async fn a_brawlers(&self) -> Result<BrawlerList> { Client::a_brawlers(self).await }
+
+    #[cfg(feature = "brawlers")]
+    async fn a_brawler(&self, id: BrawlerId) -> Result<Brawler> {
+        Client::a_brawler(self, id).await
+    }
+}
+
+/// A function which creates the error to return for some route (see
+/// [`FakeBrawlApi::with_error`]).
+///
+/// [`FakeBrawlApi::with_error`]: struct.FakeBrawlApi.html#method.with_error
+type ErrorFn = dyn Fn() -> Error + Send + Sync;
+
+/// An in-memory implementation of [`BrawlApi`] and [`AsyncBrawlApi`], for unit testing code
+/// which uses the API without sending any requests.
+///
+/// It serves the values it was preloaded with (see the `with_*` methods), looked up by their
+/// tag, country code and/or brawler ID. Requests for anything else fail with a 404
+/// [`Error::Status`] (with a `notFound` reason), like the API would, while requests for the
+/// routes given to [`with_error`] fail with the chosen error. Parameters are validated like with
+/// a [`Client`] (e.g. leaderboards with a `limit` above 200 give an
+/// [`Error::InvalidParameter`]), and leaderboards are cut down to the requested `limit`.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::{Player, Error, api::{BrawlApi, FakeBrawlApi}};
+/// use brawl_api::http::Route;
+///
+/// let api = FakeBrawlApi::new()
+///     .with_player(Player { tag: String::from("#ABC"), trophies: 500, ..Player::default() })
+///     .with_error(Route::Player(String::from("#DOWN")), || Error::Cancelled);
+///
+/// assert_eq!(api.player("#ABC").unwrap().trophies, 500);
+/// assert!(matches!(api.player("#DOWN"), Err(Error::Cancelled)));
+/// assert!(matches!(api.player("#MISSING"), Err(Error::Status(..))));
+/// ```
+///
+/// [`BrawlApi`]: trait.BrawlApi.html
+/// [`AsyncBrawlApi`]: trait.AsyncBrawlApi.html
+/// [`Error::Status`]: ../error/enum.Error.html#variant.Status
+/// [`Error::InvalidParameter`]: ../error/enum.Error.html#variant.InvalidParameter
+/// [`with_error`]: #method.with_error
+/// [`Client`]: ../http/client/struct.Client.html
+#[derive(Default)]
+pub struct FakeBrawlApi {
+    #[cfg(feature = "players")]
+    players: HashMap<String, Player>,
+
+    #[cfg(feature = "players")]
+    battlelogs: HashMap<String, BattleLog>,
+
+    #[cfg(feature = "clubs")]
+    clubs: HashMap<String, Club>,
+
+    #[cfg(feature = "clubs")]
+    club_members: HashMap<String, ClubMembers>,
+
+    #[cfg(feature = "rankings")]
+    player_rankings: HashMap<String, PlayerLeaderboard>,
+
+    #[cfg(feature = "rankings")]
+    club_rankings: HashMap<String, ClubLeaderboard>,
+
+    #[cfg(feature = "rankings")]
+    brawler_rankings: HashMap<(String, BrawlerId), BrawlerLeaderboard>,
+
+    #[cfg(feature = "brawlers")]
+    brawlers: Vec<Brawler>,
+
+    errors: HashMap<Route, Box<ErrorFn>>,
+}
+
+impl Debug for FakeBrawlApi {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        let mut debug = f.debug_struct("FakeBrawlApi");
+
+        #[cfg(feature = "players")]
+        debug.field("players", &self.players.len()).field("battlelogs", &self.battlelogs.len());
+
+        #[cfg(feature = "clubs")]
+        debug.field("clubs", &self.clubs.len()).field("club_members", &self.club_members.len());
+
+        #[cfg(feature = "rankings")]
+        debug.field("player_rankings", &self.player_rankings.len())
+            .field("club_rankings", &self.club_rankings.len())
+            .field("brawler_rankings", &self.brawler_rankings.len());
+
+        #[cfg(feature = "brawlers")]
+        debug.field("brawlers", &self.brawlers.len());
+
+        debug.field("errors", &self.errors.keys().collect::<Vec<_>>()).finish()
+    }
+}
+
+/// Returns the error given by the API for something which does not exist.
+fn not_found() -> Error {
+    let value = serde_json::json!({ "reason": "notFound" });
+    let api_error = serde_json::from_value::<APIError>(value.clone()).ok();
+    Error::Status(StatusCode::NOT_FOUND, api_error, Some(value))
+}
+
+impl FakeBrawlApi {
+    /// Creates a new `FakeBrawlApi`, with nothing preloaded (i.e., every request fails with a
+    /// 404 error).
+    pub fn new() -> FakeBrawlApi {
+        FakeBrawlApi::default()
+    }
+
+    /// Preloads a [`Player`], served by its `tag`.
+    ///
+    /// [`Player`]: ../model/players/player/struct.Player.html
+    #[cfg(feature = "players")]
+    pub fn with_player(mut self, player: Player) -> FakeBrawlApi {
+        self.players.insert(auto_hashtag(&player.tag), player);
+        self
+    }
+
+    /// Preloads a [`BattleLog`], served by its (player) `tag`.
+    ///
+    /// [`BattleLog`]: ../model/players/battlelog/struct.BattleLog.html
+    #[cfg(feature = "players")]
+    pub fn with_battlelog(mut self, battlelog: BattleLog) -> FakeBrawlApi {
+        self.battlelogs.insert(auto_hashtag(&battlelog.tag), battlelog);
+        self
+    }
+
+    /// Preloads a [`Club`], served by its `tag`. Its `members` are also served as its
+    /// [`ClubMembers`], unless those were preloaded separately.
+    ///
+    /// [`Club`]: ../model/clubs/struct.Club.html
+    /// [`ClubMembers`]: ../model/clubs/struct.ClubMembers.html
+    #[cfg(feature = "clubs")]
+    pub fn with_club(mut self, club: Club) -> FakeBrawlApi {
+        let tag = auto_hashtag(&club.tag);
+        self.club_members.entry(tag.clone()).or_insert_with(|| club.members.clone());
+        self.clubs.insert(tag, club);
+        self
+    }
+
+    /// Preloads a club's [`ClubMembers`], served by their (club) `tag`.
+    ///
+    /// [`ClubMembers`]: ../model/clubs/struct.ClubMembers.html
+    #[cfg(feature = "clubs")]
+    pub fn with_club_members(mut self, members: ClubMembers) -> FakeBrawlApi {
+        self.club_members.insert(auto_hashtag(&members.tag), members);
+        self
+    }
+
+    /// Preloads a [`PlayerLeaderboard`], served for the given `country_code` (for any limit).
+    ///
+    /// The country code is given separately, as leaderboards only know theirs when fetched (e.g.
+    /// not when deserialized from a JSON fixture).
+    ///
+    /// [`PlayerLeaderboard`]: ../model/rankings/players/struct.PlayerLeaderboard.html
+    #[cfg(feature = "rankings")]
+    pub fn with_player_rankings(
+        mut self, country_code: &str, leaderboard: PlayerLeaderboard
+    ) -> FakeBrawlApi {
+        self.player_rankings.insert(String::from(country_code), leaderboard);
+        self
+    }
+
+    /// Preloads a [`ClubLeaderboard`], served for the given `country_code` (for any limit).
+    ///
+    /// The country code is given separately, as leaderboards only know theirs when fetched (e.g.
+    /// not when deserialized from a JSON fixture).
+    ///
+    /// [`ClubLeaderboard`]: ../model/rankings/clubs/struct.ClubLeaderboard.html
+    #[cfg(feature = "rankings")]
+    pub fn with_club_rankings(
+        mut self, country_code: &str, leaderboard: ClubLeaderboard
+    ) -> FakeBrawlApi {
+        self.club_rankings.insert(String::from(country_code), leaderboard);
+        self
+    }
+
+    /// Preloads a [`BrawlerLeaderboard`], served for the given `country_code` and `brawler_id`
+    /// (for any limit).
+    ///
+    /// Those are given separately, as leaderboards only know theirs when fetched (e.g. not when
+    /// deserialized from a JSON fixture).
+    ///
+    /// [`BrawlerLeaderboard`]: ../model/rankings/brawlers/struct.BrawlerLeaderboard.html
+    #[cfg(feature = "rankings")]
+    pub fn with_brawler_rankings(
+        mut self, country_code: &str, brawler_id: BrawlerId, leaderboard: BrawlerLeaderboard
+    ) -> FakeBrawlApi {
+        self.brawler_rankings.insert((String::from(country_code), brawler_id), leaderboard);
+        self
+    }
+
+    /// Preloads a [`Brawler`], served by its `id` (and as part of the [`BrawlerList`]).
+    ///
+    /// [`Brawler`]: ../model/brawlers/struct.Brawler.html
+    /// [`BrawlerList`]: ../model/brawlers/struct.BrawlerList.html
+    #[cfg(feature = "brawlers")]
+    pub fn with_brawler(mut self, brawler: Brawler) -> FakeBrawlApi {
+        self.brawlers.retain(|b| b.id != brawler.id);
+        self.brawlers.push(brawler);
+        self
+    }
+
+    /// Makes every request to the given [`Route`] fail with the error returned by `make_error`
+    /// (called once per request, as errors cannot be cloned), instead of serving a value.
+    ///
+    /// Note that tags in routes begin with a `#`, and that rankings routes include the `limit`.
+    ///
+    /// [`Route`]: ../http/routes/enum.Route.html
+    pub fn with_error<F>(mut self, route: Route, make_error: F) -> FakeBrawlApi
+        where F: Fn() -> Error + Send + Sync + 'static {
+        self.errors.insert(route, Box::new(make_error));
+        self
+    }
+
+    /// Serves a value (if any) for the given parameters, the way a `Client` would: failing with
+    /// the chosen error for its route (if any), validating the route's parameters, and running
+    /// the post-processing of freshly-fetched values.
+    fn serve<E>(&self, params: &E::Params, value: Option<&E>) -> Result<E>
+        where E: Endpoint + Clone {
+        let route = E::route(params);
+        if let Some(make_error) = self.errors.get(&route) {
+            return Err(make_error());
+        }
+
+        route.to_url()?;
+
+        let mut value = value.cloned().ok_or_else(not_found)?;
+        value.on_fetched(params);
+        Ok(value)
+    }
+
+    #[cfg(feature = "players")]
+    fn get_player(&self, tag: &str) -> Result<Player> {
+        self.serve(tag, self.players.get(&auto_hashtag(tag)))
+    }
+
+    #[cfg(feature = "players")]
+    fn get_battlelog(&self, tag: &str) -> Result<BattleLog> {
+        self.serve(tag, self.battlelogs.get(&auto_hashtag(tag)))
+    }
+
+    #[cfg(feature = "clubs")]
+    fn get_club(&self, tag: &str) -> Result<Club> {
+        self.serve(tag, self.clubs.get(&auto_hashtag(tag)))
+    }
+
+    #[cfg(feature = "clubs")]
+    fn get_club_members(&self, tag: &str) -> Result<ClubMembers> {
+        self.serve(tag, self.club_members.get(&auto_hashtag(tag)))
+    }
+
+    #[cfg(feature = "rankings")]
+    fn get_player_rankings(&self, country_code: &str, limit: u8) -> Result<PlayerLeaderboard> {
+        let params = RankingParams::new(country_code, limit);
+        let mut leaderboard = self.serve(&params, self.player_rankings.get(country_code))?;
+        leaderboard.items.truncate(usize::from(limit));
+        Ok(leaderboard)
+    }
+
+    #[cfg(feature = "rankings")]
+    fn get_club_rankings(&self, country_code: &str, limit: u8) -> Result<ClubLeaderboard> {
+        let params = RankingParams::new(country_code, limit);
+        let mut leaderboard = self.serve(&params, self.club_rankings.get(country_code))?;
+        leaderboard.items.truncate(usize::from(limit));
+        Ok(leaderboard)
+    }
+
+    #[cfg(feature = "rankings")]
+    fn get_brawler_rankings(
+        &self, country_code: &str, brawler_id: BrawlerId, limit: u8
+    ) -> Result<BrawlerLeaderboard> {
+        let params = BrawlerRankingParams::new(country_code, brawler_id, limit);
+        let stored = self.brawler_rankings.get(&(String::from(country_code), brawler_id));
+        let mut leaderboard = self.serve(&params, stored)?;
+        leaderboard.items.truncate(usize::from(limit));
+        Ok(leaderboard)
+    }
+
+    #[cfg(feature = "brawlers")]
+    fn get_brawlers(&self) -> Result<BrawlerList> {
+        let mut items = self.brawlers.clone();
+        items.sort_by_key(|brawler| brawler.id);
+        self.serve(&(), Some(&BrawlerList { items }))
+    }
+
+    #[cfg(feature = "brawlers")]
+    fn get_brawler(&self, id: BrawlerId) -> Result<Brawler> {
+        self.serve(&id, self.brawlers.iter().find(|brawler| brawler.id == id))
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+impl BrawlApi for FakeBrawlApi {
+    #[cfg(feature = "players")]
+    fn player(&self, tag: &str) -> Result<Player> { self.get_player(tag) }
+
+    #[cfg(feature = "players")]
+    fn battlelog(&self, tag: &str) -> Result<BattleLog> { self.get_battlelog(tag) }
+
+    #[cfg(feature = "clubs")]
+    fn club(&self, tag: &str) -> Result<Club> { self.get_club(tag) }
+
+    #[cfg(feature = "clubs")]
+    fn club_members(&self, tag: &str) -> Result<ClubMembers> { self.get_club_members(tag) }
+
+    #[cfg(feature = "rankings")]
+    fn player_rankings(&self, country_code: &str, limit: u8) -> Result<PlayerLeaderboard> {
+        self.get_player_rankings(country_code, limit)
+    }
+
+    #[cfg(feature = "rankings")]
+    fn club_rankings(&self, country_code: &str, limit: u8) -> Result<ClubLeaderboard> {
+        self.get_club_rankings(country_code, limit)
+    }
+
+    #[cfg(feature = "rankings")]
+    fn brawler_rankings(
+        &self, country_code: &str, brawler_id: BrawlerId, limit: u8
+    ) -> Result<BrawlerLeaderboard> {
+        self.get_brawler_rankings(country_code, brawler_id, limit)
+    }
+
+    #[cfg(feature = "brawlers")]
+    fn brawlers(&self) -> Result<BrawlerList> { self.get_brawlers() }
+
+    #[cfg(feature = "brawlers")]
+    fn brawler(&self, id: BrawlerId) -> Result<Brawler> { self.get_brawler(id) }
+}
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl AsyncBrawlApi for FakeBrawlApi {
+    #[cfg(feature = "players")]
+    async fn a_player(&self, tag: &str) -> Result<Player> { self.get_player(tag) }
+
+    #[cfg(feature = "players")]
+    async fn a_battlelog(&self, tag: &str) -> Result<BattleLog> { self.get_battlelog(tag) }
+
+    #[cfg(feature = "clubs")]
+    async fn a_club(&self, tag: &str) -> Result<Club> { self.get_club(tag) }
+
+    #[cfg(feature = "clubs")]
+    async fn a_club_members(&self, tag: &str) -> Result<ClubMembers> {
+        self.get_club_members(tag)
+    }
+
+    #[cfg(feature = "rankings")]
+    async fn a_player_rankings(
+        &self, country_code: &str, limit: u8
+    ) -> Result<PlayerLeaderboard> {
+        self.get_player_rankings(country_code, limit)
+    }
+
+    #[cfg(feature = "rankings")]
+    async fn a_club_rankings(&self, country_code: &str, limit: u8) -> Result<ClubLeaderboard> {
+        self.get_club_rankings(country_code, limit)
+    }
+
+    #[cfg(feature = "rankings")]
+    async fn a_brawler_rankings(
+        &self, country_code: &str, brawler_id: BrawlerId, limit: u8
+    ) -> Result<BrawlerLeaderboard> {
+        self.get_brawler_rankings(country_code, brawler_id, limit)
+    }
+
+    #[cfg(feature = "brawlers")]
+    async fn a_brawlers(&self) -> Result<BrawlerList> { self.get_brawlers() }
+
+    #[cfg(feature = "brawlers")]
+    async fn a_brawler(&self, id: BrawlerId) -> Result<Brawler> { self.get_brawler(id) }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(test, feature = "players"))]
+mod tests {
+    use http::StatusCode;
+    use super::FakeBrawlApi;
+    use crate::error::Error;
+    use crate::http::Route;
+    use crate::model::players::Player;
+
+    /// Tests serving preloaded values, through a trait object.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn fake_api_serves_values() {
+        use super::BrawlApi;
+
+        let fake = FakeBrawlApi::new()
+            .with_player(
+                Player { tag: String::from("#ABC"), name: String::from("x"), ..Player::default() }
+            );
+
+        #[cfg(feature = "rankings")]
+        let fake = {
+            let leaderboard: crate::model::rankings::PlayerLeaderboard = serde_json::from_value(
+                serde_json::json!({ "items": [{}, {}, {}, {}, {}] })
+            ).unwrap();
+            fake.with_player_rankings("global", leaderboard)
+        };
+        let api: &dyn BrawlApi = &fake;
+
+        assert_eq!(api.player("#ABC").unwrap().name, "x");
+        assert_eq!(api.player("%23ABC").unwrap().name, "x");
+
+        #[cfg(feature = "rankings")]
+        {
+            let top_3 = api.player_rankings("global", 3).unwrap();
+            assert_eq!(top_3.items.len(), 3);
+            assert_eq!(top_3.limit, 3);
+            assert_eq!(top_3.country_code, "global");
+        }
+    }
+
+    /// Tests the errors given for missing values, chosen inputs and invalid parameters.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn fake_api_errors() {
+        use super::BrawlApi;
+
+        let api = FakeBrawlApi::new()
+            .with_player(Player { tag: String::from("#ABC"), ..Player::default() })
+            .with_error(Route::Player(String::from("#ABC")), || Error::Cancelled);
+
+        assert!(matches!(api.player("#ABC"), Err(Error::Cancelled)));
+
+        match api.player("#MISSING") {
+            Err(Error::Status(StatusCode::NOT_FOUND, Some(ref api_error), Some(_))) => {
+                assert_eq!(api_error.reason, "notFound");
+            },
+            other => panic!("Expected a 404 Status error, got {:?}", other),
+        }
+
+        #[cfg(feature = "rankings")]
+        assert!(matches!(
+            api.player_rankings("global", 201), Err(Error::InvalidParameter { .. })
+        ));
+    }
+
+    /// Tests serving preloaded values and errors asynchronously, through a shared trait object.
+    #[cfg(feature = "async")]
+    #[test]
+    fn fake_api_async() {
+        use std::sync::Arc;
+        use super::AsyncBrawlApi;
+        use crate::http::test_server::block_on;
+        use crate::model::players::BattleLog;
+
+        let fake = FakeBrawlApi::new()
+            .with_player(
+                Player { tag: String::from("#ABC"), name: String::from("x"), ..Player::default() }
+            )
+            .with_battlelog(BattleLog { tag: String::from("#ABC"), items: vec![] })
+            .with_error(Route::Player(String::from("#BAD")), || Error::Cancelled);
+        let api: Arc<dyn AsyncBrawlApi> = Arc::new(fake);
+
+        let player = block_on(api.a_player("#ABC")).unwrap();
+        assert_eq!(player.name, "x");
+        assert_eq!(block_on(api.a_battlelog("#ABC")).unwrap().tag, "#ABC");
+
+        assert!(matches!(block_on(api.a_player("#BAD")), Err(Error::Cancelled)));
+        assert!(matches!(
+            block_on(api.a_player("#MISSING")),
+            Err(Error::Status(StatusCode::NOT_FOUND, _, _))
+        ));
+    }
+}
@@ -0,0 +1,131 @@
+//! Command-line tools for working with Brawl Stars API data.
+//!
+//! ```text
+//! brawl-api anonymize [--out-dir <DIR>] <FILE>...
+//! ```
+//!
+//! - `anonymize`: Replaces the tags and names of players and clubs in the given JSON files (as
+//!   given by the API, or serialized models) with pseudonyms, consistently across all files (see
+//!   the `brawl_api::anonymize` module). A `-` file reads from the standard input. The results are
+//!   written to `<DIR>/<file name>` with `--out-dir` (refusing files with the same name), or to
+//!   the standard output otherwise.
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use brawl_api::anonymize::Anonymizer;
+use serde_json::Value as JsonValue;
+
+const USAGE: &str = "\
+Usage: brawl-api <COMMAND>
+
+Commands:
+  anonymize [--out-dir <DIR>] <FILE>...
+      Consistently replaces player and club tags and names in JSON files with pseudonyms.
+      Use `-` to read from the standard input. Writes to <DIR>/<file name> if --out-dir is
+      given, or to the standard output otherwise.";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("anonymize") => anonymize(&args[1..]),
+        Some("-h") | Some("--help") => {
+            println!("{}", USAGE);
+            return;
+        },
+        _ => usage_error("expected a command"),
+    };
+
+    if let Err(message) = result {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+/// Prints the usage to the standard error and exits with code 2.
+fn usage_error(message: &str) -> ! {
+    eprintln!("error: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+/// Runs the `anonymize` command with the given arguments.
+fn anonymize(args: &[String]) -> Result<(), String> {
+    let mut out_dir: Option<PathBuf> = None;
+    let mut files: Vec<&str> = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out-dir" | "-o" => match args.next() {
+                Some(dir) => out_dir = Some(PathBuf::from(dir)),
+                None => usage_error("--out-dir requires a directory"),
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            },
+            "-" => files.push("-"),
+            flag if flag.starts_with('-') => usage_error(&format!("unknown flag '{}'", flag)),
+            file => files.push(file),
+        }
+    }
+
+    if files.is_empty() {
+        usage_error("expected at least one file to anonymize");
+    }
+
+    if let Some(dir) = &out_dir {
+        // files are written by name alone, so two inputs with the same name would clash
+        let mut written: HashMap<&OsStr, &str> = HashMap::new();
+        for &file in files.iter().filter(|&&file| file != "-") {
+            if let Some(name) = Path::new(file).file_name() {
+                if let Some(other) = written.insert(name, file) {
+                    return Err(format!(
+                        "'{}' and '{}' would both be written to '{}'",
+                        other, file, dir.join(name).display()
+                    ));
+                }
+            }
+        }
+
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("could not create '{}': {}", dir.display(), err))?;
+    }
+
+    // a single anonymizer, so that pseudonyms are consistent across all files
+    let mut anonymizer = Anonymizer::new();
+
+    for file in files {
+        let contents = if file == "-" {
+            let mut contents = String::new();
+            io::stdin().read_to_string(&mut contents)
+                .map_err(|err| format!("could not read the standard input: {}", err))?;
+            contents
+        } else {
+            fs::read_to_string(file).map_err(|err| format!("could not read '{}': {}", file, err))?
+        };
+
+        let mut value: JsonValue = serde_json::from_str(&contents)
+            .map_err(|err| format!("'{}' is not valid JSON: {}", file, err))?;
+        anonymizer.anonymize_json(&mut value);
+
+        let output = serde_json::to_string_pretty(&value)
+            .map_err(|err| format!("could not serialize '{}': {}", file, err))?;
+
+        match (&out_dir, Path::new(file).file_name()) {
+            (Some(dir), Some(name)) if file != "-" => {
+                let path = dir.join(name);
+                fs::write(&path, output + "\n")
+                    .map_err(|err| format!("could not write '{}': {}", path.display(), err))?;
+            },
+            _ => println!("{}", output),
+        }
+    }
+
+    Ok(())
+}
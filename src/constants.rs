@@ -9,6 +9,16 @@ pub const USER_AGENT: &str = concat!(
     " - https://github.com/PgBiel/rust-brawl-api)"
 );
 
+/// The highest power level a brawler can be upgraded to. A brawler at this level is considered
+/// maxed (see [`PlayerBrawlerStat.is_maxed`]).
+///
+/// [`PlayerBrawlerStat.is_maxed`]: ../model/players/player/struct.PlayerBrawlerStat.html#method.is_maxed
+pub const MAX_BRAWLER_POWER: u8 = 11;
+
+/// The characters which player and club tags are made of (after the leading `#`). Tags never
+/// start with `0`.
+pub const TAG_CHARS: &str = "0289PYLQGRJCUV";
+
 /// The format used in [`TimeLike.parse`]. (Feature-gated with the `chrono` feature)
 ///
 /// `"%Y%m%dT%H%M%S%.fZ"`
@@ -21,13 +31,16 @@ pub const USER_AGENT: &str = concat!(
 pub const TIMELIKE_FORMAT: &str = "%Y%m%dT%H%M%S%.fZ";
 
 /// This eunm is an effort to aid the programmer's usage of brawler-related endpoints, by mapping
-/// human-readable brawler names to their respective IDs. (Use by casting to int; e.g. `x as usize`)
+/// human-readable brawler names to their respective IDs. (Use by converting into a [`BrawlerId`];
+/// e.g. `BrawlerId::from(x)` - or by casting to int; e.g. `x as usize`)
 ///
 /// This is by no means a final enum and must be updated on every new Brawler release.
 ///
 /// If a permanently up-to-date list is needed, one can fetch the `/brawlers/` endpoint using
 /// the available models. If still using this enum, though, rest assured that we will do our best
 /// to keep it updated - if it is not, why not contribute with a PR? ;)
+///
+/// [`BrawlerId`]: ../ids/struct.BrawlerId.html
 #[non_exhaustive]
 #[derive(Copy, Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Brawlers {
@@ -6,14 +6,25 @@ use std::error::Error as StdError;
 use serde::{self, Serialize, Deserialize};
 use serde_json::{self, Error as SerdeError, Value as JsonValue};
 use url::ParseError as UrlError;
-use reqwest::blocking::Response;
-use reqwest::{
-    Error as ReqwestError, StatusCode, Response as AResponse,
-    header::{InvalidHeaderValue, HeaderMap}
+use http::{
+    StatusCode,
+    header::InvalidHeaderValue,
 };
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+use reqwest::Error as ReqwestError;
+
+#[cfg(feature = "ureq")]
+use ureq::Error as UreqError;
 use std::fmt::{Formatter, Display};
 use crate::util::JsonMap;
 
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use http::header::HeaderMap;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::http::middleware::RawResponse;
+
 
 /// Represents a `brawl-api` Result type.
 pub type Result<T> = StdResult<T, Error>;
@@ -37,9 +48,18 @@ pub enum Error {
     /// Contains the invalid header value inside (as the `.0` field).
     Authorization(InvalidHeaderValue),
 
-    /// Represents an error occurred while requesting to the API or while receiving its data.
-    /// (A `reqwest` crate error)
-    Request(ReqwestError),
+    /// Represents an error occurred while requesting to the API or while receiving its data,
+    /// regardless of the backend which sent the request (`reqwest` or `ureq`; see
+    /// [`TransportError`]). Responses with a bad status code are not included; those are an
+    /// [`Error::Status`] or [`Error::Ratelimited`]. Note that this is feature-gated by the
+    /// `blocking`, `async` and `ureq` features (meaning that, if all of them are disabled, this
+    /// variant is removed).
+    ///
+    /// [`TransportError`]: struct.TransportError.html
+    /// [`Error::Status`]: #variant.Status
+    /// [`Error::Ratelimited`]: #variant.Ratelimited
+    #[cfg(any(feature = "blocking", feature = "async", feature = "ureq"))]
+    Request(TransportError),
 
     /// Represents an API ratelimit.
     Ratelimited {
@@ -79,6 +99,68 @@ pub enum Error {
     /// [`FetchFrom`]: ../traits/trait.FetchFrom.html
     FetchFrom(String),
 
+    /// Represents an error while creating a typed ID (such as [`BrawlerId`]) from a number that
+    /// does not belong to the respective ID family (see the [`ids`] module).
+    ///
+    /// [`BrawlerId`]: ../ids/struct.BrawlerId.html
+    /// [`ids`]: ../ids/index.html
+    InvalidId {
+        /// The name of the ID type (e.g. `"BrawlerId"`).
+        id_type: &'static str,
+
+        /// The offending (invalid) number.
+        id: usize,
+    },
+
+    /// Represents a fetch that was not performed because the operation it was part of (such as
+    /// a [`BatchFetcher`] batch) was cancelled through a [`CancelToken`].
+    ///
+    /// [`BatchFetcher`]: ../http/batch/struct.BatchFetcher.html
+    /// [`CancelToken`]: ../http/batch/struct.CancelToken.html
+    Cancelled,
+
+    /// Represents an error which occurred in a request that was shared between several callers
+    /// (see [`Client::with_coalescing`]), and which cannot be copied for each of them (such as
+    /// a connection error). The caller which performed the request receives the original error,
+    /// while the others receive its message through this variant, at field `.0`. (Other errors,
+    /// such as [`Error::Status`], are given to every caller as they are.)
+    ///
+    /// This is also given to the callers waiting for a shared request which panicked (or, when
+    /// async, was dropped) before completing.
+    ///
+    /// [`Client::with_coalescing`]: ../http/client/struct.Client.html#method.with_coalescing
+    /// [`Error::Status`]: #variant.Status
+    Coalesced(String),
+
+    /// Represents an error while decoding the claims of an API key (see [`Client::key_info`]),
+    /// which happens when the key is not a valid JWT (JSON Web Token).
+    ///
+    /// At field `.0`, there is a `String` object describing what occurred.
+    ///
+    /// [`Client::key_info`]: ../http/client/struct.Client.html#method.key_info
+    InvalidKey(String),
+
+    /// Represents an error while loading an API key from an environment variable or a file (see
+    /// [`AuthKey::from_env`] and [`AuthKey::from_file`]).
+    ///
+    /// At field `.0`, there is a `String` object describing what occurred.
+    ///
+    /// [`AuthKey::from_env`]: ../http/secret/struct.AuthKey.html#method.from_env
+    /// [`AuthKey::from_file`]: ../http/secret/struct.AuthKey.html#method.from_file
+    LoadKey(String),
+
+    /// Represents a query parameter (or route argument) which is not within the limits
+    /// documented by the API, detected before sending the request (see [`Query::validate`]).
+    ///
+    /// [`Query::validate`]: ../http/query/struct.Query.html#method.validate
+    InvalidParameter {
+        /// The name of the invalid parameter.
+        name: String,
+
+        /// Why the parameter is invalid.
+        reason: String,
+    },
+
     /// Represents an error while using [`TimeLike.parse`]. Note that this is feature-gated
     /// by the `chrono` feature (meaning that, if it is disabled, this variant is removed).
     ///
@@ -137,9 +219,78 @@ impl From<SerdeError> for Error {
     }
 }
 
+/// A transport error (occurred while requesting to the API or while receiving its data), as
+/// given by the HTTP backend which sent the request: `reqwest` (with the `blocking` or `async`
+/// features) or `ureq` (with the `ureq` feature). The backend's own error can be obtained through
+/// [`reqwest_error`] or [`ureq_error`].
+///
+/// [`reqwest_error`]: #method.reqwest_error
+/// [`ureq_error`]: #method.ureq_error
+#[cfg(any(feature = "blocking", feature = "async", feature = "ureq"))]
+#[derive(Debug)]
+pub struct TransportError {
+    inner: Box<dyn StdError + Send + Sync + 'static>,
+}
+
+#[cfg(any(feature = "blocking", feature = "async", feature = "ureq"))]
+impl TransportError {
+    /// Returns the underlying `reqwest` error, if the request was sent through `reqwest`.
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    pub fn reqwest_error(&self) -> Option<&ReqwestError> {
+        self.inner.downcast_ref::<ReqwestError>()
+    }
+
+    /// Returns the underlying `ureq` error, if the request was sent through `ureq`.
+    #[cfg(feature = "ureq")]
+    pub fn ureq_error(&self) -> Option<&UreqError> {
+        self.inner.downcast_ref::<UreqError>()
+    }
+
+    /// Returns the underlying error of the backend, consuming this one.
+    pub fn into_inner(self) -> Box<dyn StdError + Send + Sync + 'static> {
+        self.inner
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async", feature = "ureq"))]
+impl Display for TransportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async", feature = "ureq"))]
+impl StdError for TransportError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.inner.source()
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl From<ReqwestError> for TransportError {
+    fn from(err: ReqwestError) -> TransportError {
+        TransportError { inner: Box::new(err) }
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl From<UreqError> for TransportError {
+    fn from(err: UreqError) -> TransportError {
+        TransportError { inner: Box::new(err) }
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
 impl From<ReqwestError> for Error {
     fn from(err: ReqwestError) -> Error {
-        Error::Request(err)
+        Error::Request(err.into())
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl From<UreqError> for Error {
+    fn from(err: UreqError) -> Error {
+        Error::Request(err.into())
     }
 }
 
@@ -165,6 +316,7 @@ impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match *self {
             Error::Json(ref e) => e.fmt(f),
+            #[cfg(any(feature = "blocking", feature = "async", feature = "ureq"))]
             Error::Request(ref e) => e.fmt(f),
             _ => f.write_str(&*self.description()),
             // _ => f.write_str(self.description())
@@ -180,7 +332,8 @@ impl StdError for Error {
         match *self {
             Error::Json(ref e) => Some(e),
             Error::Url(ref e) => Some(e),
-            Error::Request(ref e) => Some(e),
+            #[cfg(any(feature = "blocking", feature = "async", feature = "ureq"))]
+            Error::Request(ref e) => Some(e.inner.as_ref()),
 
             #[cfg(feature = "chrono")]
             Error::ParseTimeLike { ref original_err, .. } => match original_err {
@@ -227,7 +380,8 @@ impl Error {
                 format!("Ratelimited{}{}{}", dot, lim_part, time_part)
             },
 
-            Error::Request(ref e) => String::from(e.description()),
+            #[cfg(any(feature = "blocking", feature = "async", feature = "ureq"))]
+            Error::Request(ref e) => e.to_string(),
 
 //            Error::Decode(msg, _) => String::from(msg),
 
@@ -239,6 +393,22 @@ impl Error {
 
             Error::FetchFrom(ref string) => string.clone(),
 
+            Error::InvalidId { id_type, id } => format!(
+                "{} is not a valid {} (outside of its ID family).", id, id_type
+            ),
+
+            Error::Cancelled => String::from("The operation was cancelled before fetching."),
+
+            Error::Coalesced(ref message) => message.clone(),
+
+            Error::InvalidKey(ref string) => format!("Invalid API key: {}", string),
+
+            Error::LoadKey(ref string) => format!("Could not load API key from {}", string),
+
+            Error::InvalidParameter { ref name, ref reason } => format!(
+                "Invalid parameter '{}': {}.", name, reason
+            ),
+
             #[cfg(feature = "chrono")]
             Error::ParseTimeLike {
                 ref reason,
@@ -247,65 +417,63 @@ impl Error {
         }
     }
 
-    /// Obtain an Error from a Response (blocking). Optionally specify a pre-parsed JsonValue
-    /// for the body, otherwise that parsing will be done inside this function.
-    #[doc(hidden)]
-    pub(crate) fn from_response(response: Response, value: Option<JsonValue>) -> Error {
-        let status = response.status();
+    /// Returns an equivalent copy of this error, if it is possible to make one. (Errors of other
+    /// crates, such as those of `reqwest` or `serde_json`, can't be copied.)
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    pub(crate) fn try_clone(&self) -> Option<Error> {
+        let copy = match *self {
+            Error::Url(err) => Error::Url(err),
+
+            Error::Ratelimited { limit, remaining, ref time_until_reset } => Error::Ratelimited {
+                limit,
+                remaining,
+                time_until_reset: time_until_reset.clone(),
+            },
 
-        let headers: &HeaderMap = response.headers();
-        let headers = headers.clone();
+            Error::Status(status, ref api_error, ref value) => Error::Status(
+                status, api_error.clone(), value.clone()
+            ),
 
-        let value: Option<JsonValue> = match value {
-            Some(val) => Some(val),
-            None => serde_json::from_reader(response).ok()
-        };
+            Error::FetchFrom(ref string) => Error::FetchFrom(string.clone()),
 
-        let reset_header = headers.get("x-ratelimit-reset");
-        if let Some(reset_header) = reset_header {  // ratelimited
-            let reset_header = reset_header.to_str();
-            if let Ok(reset) = reset_header {
-                return Error::Ratelimited {
-                    limit: match headers.get("x-ratelimit-limit") {
-                        Some(lim_header) => lim_header.to_str().ok().and_then(
-                            |s| { s.parse().ok() }
-                        ),
-                        None => None,
-                    },
+            Error::InvalidId { id_type, id } => Error::InvalidId { id_type, id },
 
-                    remaining: match headers.get("x-ratelimit-remaining") {
-                        Some(rem_header) => rem_header.to_str().ok().and_then(
-                            |s| { s.parse().ok() }
-                        ),
-                        None => None,
-                    },
+            Error::Cancelled => Error::Cancelled,
 
-                    time_until_reset: Some(String::from(reset)),
+            Error::Coalesced(ref message) => Error::Coalesced(message.clone()),
+
+            Error::InvalidKey(ref string) => Error::InvalidKey(string.clone()),
+
+            Error::LoadKey(ref string) => Error::LoadKey(string.clone()),
+
+            Error::InvalidParameter { ref name, ref reason } => Error::InvalidParameter {
+                name: name.clone(),
+                reason: reason.clone(),
+            },
+
+            #[cfg(feature = "chrono")]
+            Error::ParseTimeLike { ref reason, ref offender, original_err } => {
+                Error::ParseTimeLike {
+                    reason: reason.clone(),
+                    offender: offender.clone(),
+                    original_err,
                 }
-            }
-        }
+            },
 
-        let api_error: Option<APIError> = match value {
-            Some(ref val) => serde_json::from_value(val.clone()).ok(),
-            None => None,
+            _ => return None,
         };
 
-        Error::Status(status, api_error, value)
+        Some(copy)
     }
 
-    /// Obtain an Error from a Response (non-blocking). Optionally specify a pre-parsed JsonValue
-    /// for the body, otherwise that parsing will be done inside this function.
+    /// Obtain an Error from a (complete) response, parsing its body as JSON if possible.
     #[doc(hidden)]
-    #[cfg(feature = "async")]
-    pub(crate) async fn a_from_response(response: AResponse, value: Option<JsonValue>) -> Error {
-        let status = response.status();
-        let headers: &HeaderMap = response.headers();
-        let headers = headers.clone();
-
-        let value: Option<JsonValue> = match value {
-            Some(val) => Some(val),
-            None => response.json().await.ok()
-        };
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    pub(crate) fn from_raw_response(response: &RawResponse) -> Error {
+        let status = response.status;
+        let headers: &HeaderMap = &response.headers;
+
+        let value: Option<JsonValue> = serde_json::from_slice(&response.body).ok();
 
         let reset_header = headers.get("x-ratelimit-reset");
         if let Some(reset_header) = reset_header {  // ratelimited
@@ -339,5 +507,4 @@ impl Error {
         Error::Status(status, api_error, value)
     }
 
-
 }
@@ -0,0 +1,859 @@
+//! Contains the [`Faker`], a seeded generator of realistic and internally consistent models, for
+//! writing tests (and property tests) without hand-written JSON fixtures. (Feature-gated with the
+//! `fake` feature)
+//!
+//! Generated models respect the invariants of the data given by the API, such as:
+//!
+//! - Tags are valid (a `#` followed by characters from the game's tag alphabet);
+//! - A player's trophies are the sum of their brawlers' trophies, and never above their highest
+//!   trophies (likewise for each brawler);
+//! - Brawlers only have star powers, gadgets, gears and hypercharges which they could have
+//!   unlocked at their power level;
+//! - Battles have correctly sized teams for their mode (2 teams of 3 in 3v3 modes, 5 teams of 2 in
+//!   Duo Showdown and 10 players in Solo Showdown), including the player whose battle log it is,
+//!   and a rank or result matching the trophy change;
+//! - Clubs have between 1 and 30 members, exactly one president, and trophies equal to the sum of
+//!   their members' trophies;
+//! - Leaderboards have `limit` rankings (at most 200, like the API's), ordered by trophies.
+//!
+//! The same seed always generates the same models (no matter the platform or the crate version's
+//! dependencies), so failures can be reproduced by reusing the seed.
+//!
+//! [`Faker`]: struct.Faker.html
+
+use std::ops::RangeInclusive;
+use crate::constants::TAG_CHARS;
+
+#[cfg(feature = "clubs")]
+use std::cmp::Reverse;
+
+#[cfg(any(feature = "players", feature = "clubs", feature = "rankings"))]
+use crate::model::common::PlayerIcon;
+
+#[cfg(any(feature = "players", feature = "brawlers"))]
+use crate::model::common::{StarPower, Gadget};
+
+#[cfg(feature = "rankings")]
+use crate::model::common::Paging;
+
+#[cfg(feature = "rankings")]
+use crate::http::query::MAX_LIMIT;
+
+#[cfg(any(feature = "players", feature = "brawlers"))]
+use crate::constants::Brawlers;
+
+#[cfg(any(feature = "players", feature = "brawlers", feature = "rankings"))]
+use crate::ids::BrawlerId;
+
+#[cfg(any(feature = "players", feature = "brawlers"))]
+use crate::ids::{StarPowerId, GadgetId};
+
+#[cfg(feature = "players")]
+use crate::constants::MAX_BRAWLER_POWER;
+
+#[cfg(feature = "players")]
+use crate::ids::EventId;
+
+#[cfg(feature = "players")]
+use crate::time::TimeLike;
+
+#[cfg(feature = "players")]
+use crate::model::players::{
+    Player, PlayerClub, PlayerBrawlerStat, Gear, HyperCharge, BrawlerSkin,
+    battlelog::{
+        BattleLog, Battle, BattleEvent, BattleResultInfo, BattlePlayer, BattleBrawler,
+        BattleOutcome,
+    },
+};
+
+#[cfg(feature = "clubs")]
+use crate::model::clubs::{Club, ClubMember, ClubMembers, ClubMemberRole, ClubType};
+
+#[cfg(feature = "rankings")]
+use crate::model::rankings::{
+    players::{PlayerLeaderboard, PlayerRanking, PlayerRankingClub},
+    clubs::{ClubLeaderboard, ClubRanking},
+    brawlers::BrawlerLeaderboard,
+};
+
+#[cfg(feature = "brawlers")]
+use crate::model::brawlers::{BrawlerList, Brawler};
+
+/// The two halves combined to generate player and club names.
+const NAME_PARTS: (&[&str], &[&str]) = (
+    &[
+        "Shadow", "Pixel", "Turbo", "Lucky", "Crazy", "Silent", "Golden", "Dark", "Happy",
+        "Mega", "Frost", "Ninja", "Royal", "Cosmic", "Wild", "Brave",
+    ],
+    &[
+        "Fox", "King", "Wolf", "Star", "Gamer", "Dragon", "Storm", "Hunter", "Knight", "Brawler",
+        "Legend", "Rider", "Tiger", "Ghost", "Panda", "Hero",
+    ],
+);
+
+/// The name colors used in the game (in the `0xAARRGGBB` format given by the API).
+#[cfg(any(feature = "players", feature = "clubs", feature = "rankings"))]
+const NAME_COLORS: &[u64] = &[
+    0xffffffff, 0xffa2e3fe, 0xff4ddba2, 0xffff9727, 0xfff05637, 0xfff9c908, 0xffff8afb,
+    0xffcb5aff, 0xfff9775d, 0xff1ba5f5,
+];
+
+/// The brawlers known by the lib (see [`Brawlers`]), with their names as given by the API.
+///
+/// [`Brawlers`]: ../constants/enum.Brawlers.html
+#[cfg(any(feature = "players", feature = "brawlers"))]
+const BRAWLERS: &[(Brawlers, &str)] = &[
+    (Brawlers::Shelly, "SHELLY"), (Brawlers::Colt, "COLT"), (Brawlers::Bull, "BULL"),
+    (Brawlers::Brock, "BROCK"), (Brawlers::Rico, "RICO"), (Brawlers::Spike, "SPIKE"),
+    (Brawlers::Barley, "BARLEY"), (Brawlers::Jessie, "JESSIE"), (Brawlers::Nita, "NITA"),
+    (Brawlers::Dynamike, "DYNAMIKE"), (Brawlers::ElPrimo, "EL PRIMO"),
+    (Brawlers::Mortis, "MORTIS"), (Brawlers::Crow, "CROW"), (Brawlers::Poco, "POCO"),
+    (Brawlers::Bo, "BO"), (Brawlers::Piper, "PIPER"), (Brawlers::Pam, "PAM"),
+    (Brawlers::Tara, "TARA"), (Brawlers::Darryl, "DARRYL"), (Brawlers::Penny, "PENNY"),
+    (Brawlers::Frank, "FRANK"), (Brawlers::Gene, "GENE"), (Brawlers::Tick, "TICK"),
+    (Brawlers::Leon, "LEON"), (Brawlers::Rosa, "ROSA"), (Brawlers::Carl, "CARL"),
+    (Brawlers::Bibi, "BIBI"), (Brawlers::EightBit, "8-BIT"), (Brawlers::Sandy, "SANDY"),
+    (Brawlers::Bea, "BEA"), (Brawlers::Emz, "EMZ"), (Brawlers::MrP, "MR. P"),
+    (Brawlers::Max, "MAX"),
+];
+
+/// The gears which can be equipped on brawlers (from power 8).
+#[cfg(feature = "players")]
+const GEARS: &[&str] = &["SPEED", "VISION", "HEALTH", "SHIELD", "DAMAGE", "GADGET CHARGE"];
+
+/// The maps of each event mode (the event ID of a map is its index plus
+/// [`EventId::FAMILY_START`]).
+///
+/// [`EventId::FAMILY_START`]: ../ids/struct.EventId.html#associatedconstant.FAMILY_START
+#[cfg(feature = "players")]
+const MAPS: &[(&str, &str)] = &[
+    ("gemGrab", "Hard Rock Mine"), ("gemGrab", "Crystal Arcade"),
+    ("brawlBall", "Backyard Bowl"), ("brawlBall", "Coarse Course"),
+    ("heist", "Safe Zone"), ("heist", "Hot Potato"),
+    ("bounty", "Shooting Star"), ("bounty", "Layer Cake"),
+    ("siege", "Nuts & Bolts"),
+    ("hotZone", "Ring of Fire"), ("hotZone", "Dueling Beetles"),
+    ("soloShowdown", "Skull Creek"), ("soloShowdown", "Rockwall Brawl"),
+    ("duoShowdown", "Feast or Famine"), ("duoShowdown", "Double Trouble"),
+];
+
+/// The trophy change for each rank in Solo Showdown.
+#[cfg(feature = "players")]
+const SOLO_TROPHY_CHANGES: [isize; 10] = [10, 8, 7, 6, 4, 2, 0, -1, -2, -3];
+
+/// The trophy change for each rank in Duo Showdown.
+#[cfg(feature = "players")]
+const DUO_TROPHY_CHANGES: [isize; 5] = [9, 7, 4, 0, -2];
+
+/// The amount of battles in a battle log given by the API.
+#[cfg(feature = "players")]
+const BATTLELOG_SIZE: usize = 25;
+
+/// The latest (Unix) timestamp at which generated battles occur (2020-01-01, plus three years).
+#[cfg(feature = "players")]
+const LATEST_BATTLE_TIME: u64 = 1_577_836_800 + 3 * 365 * 24 * 3600;
+
+/// A seeded generator of realistic, internally consistent models. (Feature-gated with the `fake`
+/// feature)
+///
+/// See the [module-level docs] for the invariants respected by generated models.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::fake::Faker;
+///
+/// let mut faker = Faker::new(42);
+/// let player = faker.player();
+/// assert!(player.highest_trophies >= player.trophies);
+///
+/// let battlelog = faker.battlelog(&player);
+/// assert_eq!(battlelog.tag, player.tag);
+///
+/// // the same seed always generates the same models
+/// assert_eq!(Faker::new(42).player(), player);
+/// ```
+///
+/// [module-level docs]: index.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Faker {
+    /// The state of the (SplitMix64) pseudo-random number generator.
+    state: u64,
+}
+
+impl Faker {
+    /// Creates a new `Faker` from the given seed. Fakers created with the same seed generate the
+    /// same sequence of models.
+    pub fn new(seed: u64) -> Faker {
+        Faker { state: seed }
+    }
+
+    /// Generates the next pseudo-random number (using SplitMix64).
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Generates a number in the given (inclusive) range.
+    fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        let span = (end - start) as u64 + 1;
+        start + (self.next_u64() % span) as usize
+    }
+
+    /// Returns `true` with the given chance (in percent).
+    fn chance(&mut self, percent: usize) -> bool {
+        self.range(0..=99) < percent
+    }
+
+    /// Picks one of the given items.
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    /// Shuffles the given items in place (Fisher-Yates).
+    #[cfg(feature = "players")]
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range(0..=i);
+            items.swap(i, j);
+        }
+    }
+
+    /// Generates a valid player or club tag, starting with a `#`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::fake::Faker;
+    ///
+    /// let tag = Faker::new(1).tag();
+    /// assert!(tag.starts_with('#'));
+    /// assert!(tag[1..].chars().all(|c| "0289PYLQGRJCUV".contains(c)));
+    /// ```
+    pub fn tag(&mut self) -> String {
+        let len = self.range(8..=9);
+        let mut tag = String::with_capacity(len + 1);
+        tag.push('#');
+        tag.push(char::from(*self.pick(&TAG_CHARS.as_bytes()[1..])));  // tags don't start with '0'
+        for _ in 1..len {
+            tag.push(char::from(*self.pick(TAG_CHARS.as_bytes())));
+        }
+        tag
+    }
+
+    /// Generates a player or club name.
+    pub fn name(&mut self) -> String {
+        let mut name = format!("{}{}", self.pick(NAME_PARTS.0), self.pick(NAME_PARTS.1));
+        if self.chance(40) {
+            name.push_str(&self.range(1..=99).to_string());
+        }
+        name
+    }
+
+    /// Picks a name color.
+    #[cfg(any(feature = "players", feature = "clubs", feature = "rankings"))]
+    fn name_color(&mut self) -> u64 {
+        *self.pick(NAME_COLORS)
+    }
+
+    /// Picks a profile icon.
+    #[cfg(any(feature = "players", feature = "clubs", feature = "rankings"))]
+    fn icon(&mut self) -> PlayerIcon {
+        PlayerIcon { id: 28_000_000 + self.range(0..=99) }
+    }
+}
+
+/// Returns the star powers of the brawler at the given index of [`BRAWLERS`].
+///
+/// [`BRAWLERS`]: constant.BRAWLERS.html
+#[cfg(any(feature = "players", feature = "brawlers"))]
+fn star_powers_of(index: usize) -> Vec<StarPower> {
+    let (_, name) = BRAWLERS[index];
+    (0..2).map(|i| StarPower {
+        name: format!("{} STAR POWER {}", name, i + 1),
+        id: StarPowerId(StarPowerId::FAMILY_START + index * 4 + i),
+    }).collect()
+}
+
+/// Returns the gadgets of the brawler at the given index of [`BRAWLERS`].
+///
+/// [`BRAWLERS`]: constant.BRAWLERS.html
+#[cfg(any(feature = "players", feature = "brawlers"))]
+fn gadgets_of(index: usize) -> Vec<Gadget> {
+    let (_, name) = BRAWLERS[index];
+    (0..2).map(|i| Gadget {
+        name: format!("{} GADGET {}", name, i + 1),
+        id: GadgetId(GadgetId::FAMILY_START + index * 4 + 2 + i),
+    }).collect()
+}
+
+/// Returns the brawler at the given index of [`BRAWLERS`], with all of its accessories.
+///
+/// [`BRAWLERS`]: constant.BRAWLERS.html
+#[cfg(feature = "brawlers")]
+fn brawler_at(index: usize) -> Brawler {
+    let (brawler, name) = BRAWLERS[index];
+    Brawler {
+        name: String::from(name),
+        id: BrawlerId::from(brawler),
+        star_powers: star_powers_of(index),
+        gadgets: gadgets_of(index),
+    }
+}
+
+/// Returns a brawler's rank, based on their highest trophies.
+#[cfg(feature = "players")]
+fn rank_for(highest_trophies: usize) -> u16 {
+    let rank = match highest_trophies {
+        0..=99 => highest_trophies / 10,
+        100..=179 => 10 + (highest_trophies - 100) / 20,
+        180..=499 => 14 + (highest_trophies - 180) / 40,
+        _ => 22 + (highest_trophies - 500) / 50,
+    };
+    (rank.min(34) + 1) as u16
+}
+
+/// Formats a (Unix) timestamp like the API's battle times (e.g. `20200131T003432.000Z`).
+#[cfg(feature = "players")]
+fn format_battle_time(timestamp: u64) -> String {
+    let (days, secs) = ((timestamp / 86400) as i64, timestamp % 86400);
+
+    // converts days since the Unix epoch into a civil date (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}.000Z",
+        year, month, day, secs / 3600, secs / 60 % 60, secs % 60
+    )
+}
+
+#[cfg(feature = "players")]
+impl Faker {
+    /// Generates the stats of the brawler at the given index of [`BRAWLERS`], as owned by a
+    /// player.
+    ///
+    /// [`BRAWLERS`]: constant.BRAWLERS.html
+    fn player_brawler_stat(&mut self, index: usize) -> PlayerBrawlerStat {
+        let (brawler, name) = BRAWLERS[index];
+        let power = self.range(1..=usize::from(MAX_BRAWLER_POWER)) as u8;
+        let highest_trophies = self.range(0..=(100 + usize::from(power) * 60));
+        let trophies = highest_trophies - self.range(0..=highest_trophies / 4);
+
+        let star_powers = match power {
+            9 => star_powers_of(index).into_iter().take(1).collect(),
+            10..=11 => star_powers_of(index).into_iter().take(self.range(1..=2)).collect(),
+            _ => Vec::new(),
+        };
+        let gadgets = if power >= 7 {
+            gadgets_of(index).into_iter().take(self.range(1..=2)).collect()
+        } else {
+            Vec::new()
+        };
+        let gears = if power >= 8 {
+            let mut gears: Vec<usize> = (0..GEARS.len()).collect();
+            self.shuffle(&mut gears);
+            gears.into_iter().take(self.range(0..=2)).map(|i| Gear {
+                id: 62_000_000 + i,
+                name: String::from(GEARS[i]),
+                level: 1,
+            }).collect()
+        } else {
+            Vec::new()
+        };
+        let hyper_charges = if power == MAX_BRAWLER_POWER && self.chance(30) {
+            vec![HyperCharge { id: 23_100_000 + index, name: format!("{} HYPERCHARGE", name) }]
+        } else {
+            Vec::new()
+        };
+
+        PlayerBrawlerStat {
+            star_powers,
+            id: BrawlerId::from(brawler),
+            rank: rank_for(highest_trophies),
+            trophies,
+            highest_trophies,
+            power,
+            name: String::from(name),
+            gadgets,
+            gears,
+            hyper_charges,
+            skin: Some(BrawlerSkin { id: 29_000_000 + index, name: String::from(name) }),
+        }
+    }
+
+    /// Generates a [`Player`], with between 1 and all (known) brawlers.
+    ///
+    /// [`Player`]: ../model/players/player/struct.Player.html
+    pub fn player(&mut self) -> Player {
+        let mut indexes: Vec<usize> = (0..BRAWLERS.len()).collect();
+        self.shuffle(&mut indexes);
+        indexes.truncate(self.range(1..=BRAWLERS.len()));
+        indexes.sort_unstable();
+
+        let brawlers: Vec<PlayerBrawlerStat> = indexes.into_iter()
+            .map(|index| self.player_brawler_stat(index))
+            .collect();
+        let trophies = brawlers.iter().map(|b| b.trophies).sum();
+        let highest_trophies = brawlers.iter().map(|b| b.highest_trophies).sum();
+
+        let highest_power_play_points = if self.chance(50) { self.range(0..=1400) } else { 0 };
+        let club = if self.chance(70) {
+            Some(PlayerClub { tag: self.tag(), name: self.name() })
+        } else {
+            None
+        };
+
+        Player {
+            club,
+            is_qualified_from_championship_challenge: self.chance(5),
+            tvt_victories: self.range(0..=trophies),
+            tag: self.tag(),
+            name: self.name(),
+            icon: self.icon(),
+            trophies,
+            highest_trophies,
+            exp_level: self.range(1..=(1 + highest_trophies / 100)),
+            exp_points: self.range(0..=(50 * highest_trophies)),
+            power_play_points: self.range(0..=highest_power_play_points),
+            highest_power_play_points,
+            solo_victories: self.range(0..=trophies / 4),
+            duo_victories: self.range(0..=trophies / 4),
+            best_robo_rumble_time: self.range(0..=600),
+            best_time_as_big_brawler: self.range(0..=300),
+            brawlers,
+            name_color: self.name_color(),
+        }
+    }
+
+    /// Generates a participant of a battle, with trophies around the given amount.
+    fn battle_player(&mut self, trophies: usize) -> BattlePlayer {
+        let (brawler, name) = *self.pick(BRAWLERS);
+        BattlePlayer {
+            tag: self.tag(),
+            name: self.name(),
+            brawler: BattleBrawler {
+                id: BrawlerId::from(brawler),
+                name: String::from(name),
+                power: self.range(1..=usize::from(MAX_BRAWLER_POWER)) as u8,
+                trophies: self.range(trophies.saturating_sub(50)..=(trophies + 50)),
+            },
+        }
+    }
+
+    /// Generates a battle of the given player, which ended at the given (Unix) timestamp.
+    fn battle_at(&mut self, player: &Player, timestamp: u64) -> Battle {
+        let me = match player.brawlers.len() {
+            0 => BattlePlayer {
+                tag: player.tag.clone(), name: player.name.clone(), ..self.battle_player(0)
+            },
+            len => {
+                let stat = &player.brawlers[self.range(0..=len - 1)];
+                BattlePlayer {
+                    tag: player.tag.clone(),
+                    name: player.name.clone(),
+                    brawler: BattleBrawler {
+                        id: stat.id,
+                        name: stat.name.clone(),
+                        power: stat.power,
+                        trophies: stat.trophies,
+                    },
+                }
+            },
+        };
+        let trophies = me.brawler.trophies;
+
+        let map_index = match self.range(0..=99) {
+            0..=59 => self.range(0..=10),  // 3v3
+            60..=84 => self.range(11..=12),  // solo showdown
+            _ => self.range(13..=14),  // duo showdown
+        };
+        let (mode, map) = MAPS[map_index];
+
+        let mut result = BattleResultInfo {
+            mode: String::from(mode),
+            battle_type: Some(String::from("ranked")),
+            duration: self.range(60..=180),
+            ..BattleResultInfo::default()
+        };
+
+        match mode {
+            "soloShowdown" => {
+                let rank = self.range(1..=10);
+                let mut players: Vec<BattlePlayer> = (1..10)
+                    .map(|_| self.battle_player(trophies))
+                    .collect();
+                players.insert(rank - 1, me);
+
+                result.rank = Some(rank as u8);
+                result.trophy_change = SOLO_TROPHY_CHANGES[rank - 1];
+                result.players = Some(players);
+            },
+            "duoShowdown" => {
+                let rank = self.range(1..=5);
+                let mut teams: Vec<Vec<BattlePlayer>> = (0..5)
+                    .map(|_| vec![self.battle_player(trophies), self.battle_player(trophies)])
+                    .collect();
+                teams[rank - 1][0] = me;
+
+                result.rank = Some(rank as u8);
+                result.trophy_change = DUO_TROPHY_CHANGES[rank - 1];
+                result.teams = Some(teams);
+            },
+            _ => {
+                let mine = self.range(0..=1);
+                let mut teams: Vec<Vec<BattlePlayer>> = (0..2)
+                    .map(|_| (0..3).map(|_| self.battle_player(trophies)).collect())
+                    .collect();
+                teams[mine][self.range(0..=2)] = me;
+
+                let (outcome, winners, trophy_change) = match self.range(0..=99) {
+                    0..=49 => (BattleOutcome::Victory, mine, self.range(3..=8) as isize),
+                    50..=89 => (BattleOutcome::Defeat, 1 - mine, -(self.range(0..=6) as isize)),
+                    _ => (BattleOutcome::Draw, self.range(0..=1), 0),
+                };
+
+                result.result = Some(outcome);
+                result.trophy_change = trophy_change;
+                result.star_player = Some(teams[winners][self.range(0..=2)].clone());
+                result.teams = Some(teams);
+            },
+        }
+
+        Battle {
+            battle_time: TimeLike(format_battle_time(timestamp)),
+            event: BattleEvent {
+                id: EventId(EventId::FAMILY_START + map_index),
+                mode: String::from(mode),
+                map: String::from(map),
+            },
+            result,
+        }
+    }
+
+    /// Generates a [`Battle`] of the given player (who is among its participants, using one of
+    /// their brawlers).
+    ///
+    /// [`Battle`]: ../model/players/battlelog/struct.Battle.html
+    pub fn battle(&mut self, player: &Player) -> Battle {
+        let timestamp = LATEST_BATTLE_TIME - self.range(0..=86400) as u64;
+        self.battle_at(player, timestamp)
+    }
+
+    /// Generates the [`BattleLog`] of the given player, with their 25 most recent battles
+    /// (sorted from the most recent).
+    ///
+    /// [`BattleLog`]: ../model/players/battlelog/struct.BattleLog.html
+    pub fn battlelog(&mut self, player: &Player) -> BattleLog {
+        let mut timestamp = LATEST_BATTLE_TIME - self.range(0..=86400) as u64;
+        let items = (0..BATTLELOG_SIZE).map(|_| {
+            let battle = self.battle_at(player, timestamp);
+            timestamp -= (battle.result.duration + self.range(30..=3600)) as u64;
+            battle
+        }).collect();
+
+        BattleLog { tag: player.tag.clone(), items }
+    }
+}
+
+#[cfg(feature = "clubs")]
+impl Faker {
+    /// Generates a [`Club`], with between 1 and 30 members (sorted by trophies), exactly one of
+    /// which is its president.
+    ///
+    /// [`Club`]: ../model/clubs/struct.Club.html
+    pub fn club(&mut self) -> Club {
+        let tag = self.tag();
+        let member_count = self.range(1..=30);
+        let mut items: Vec<ClubMember> = (0..member_count).map(|_| ClubMember {
+            tag: self.tag(),
+            name: self.name(),
+            icon: self.icon(),
+            trophies: self.range(500..=40000),
+            role: match self.range(0..=99) {
+                0..=9 => ClubMemberRole::VicePresident,
+                10..=39 => ClubMemberRole::Senior,
+                _ => ClubMemberRole::Member,
+            },
+            name_color: self.name_color(),
+        }).collect();
+        items.sort_by_key(|member| Reverse(member.trophies));
+        items[self.range(0..=member_count - 1)].role = ClubMemberRole::President;
+
+        let trophies = items.iter().map(|m| m.trophies).sum();
+        let lowest_trophies = items.last().map(|m| m.trophies).unwrap_or(0);
+
+        Club {
+            tag: tag.clone(),
+            name: self.name(),
+            description: if self.chance(80) {
+                Some(format!("Welcome to {}! Be active and have fun.", self.name()))
+            } else {
+                None
+            },
+            trophies,
+            required_trophies: lowest_trophies / 1000 * 1000,
+            members: ClubMembers { tag, items, paging: None },
+            club_type: match self.range(0..=99) {
+                0..=59 => ClubType::Open,
+                60..=89 => ClubType::InviteOnly,
+                _ => ClubType::Closed,
+            },
+            badge_id: 8_000_000 + self.range(0..=80),
+            is_family_friendly: self.chance(30),
+        }
+    }
+}
+
+#[cfg(feature = "rankings")]
+impl Faker {
+    /// Generates `limit` player rankings, starting at (at most) the given trophies and ordered by
+    /// trophies.
+    fn player_rankings(
+        &mut self, limit: u8, top_trophies: usize, step: usize
+    ) -> Vec<PlayerRanking> {
+        let mut trophies = top_trophies;
+        (1..=limit).map(|rank| {
+            trophies = trophies.saturating_sub(self.range(0..=step));
+            PlayerRanking {
+                club: PlayerRankingClub::new(
+                    &if self.chance(85) { self.name() } else { String::new() }
+                ),
+                tag: self.tag(),
+                name: self.name(),
+                icon: self.icon(),
+                trophies,
+                rank,
+                name_color: self.name_color(),
+            }
+        }).collect()
+    }
+
+    /// Generates a [`PlayerLeaderboard`] of the given country code (or `"global"`), with `limit`
+    /// rankings (clamped to 200, the greatest limit the API accepts).
+    ///
+    /// [`PlayerLeaderboard`]: ../model/rankings/players/struct.PlayerLeaderboard.html
+    pub fn player_leaderboard(&mut self, country_code: &str, limit: u8) -> PlayerLeaderboard {
+        let limit = limit.min(MAX_LIMIT as u8);
+        let top_trophies = self.range(40000..=60000);
+        PlayerLeaderboard {
+            country_code: String::from(country_code),
+            limit,
+            items: self.player_rankings(limit, top_trophies, 150),
+            paging: Some(Paging::default()),
+        }
+    }
+
+    /// Generates a [`ClubLeaderboard`] of the given country code (or `"global"`), with `limit`
+    /// rankings (clamped to 200, the greatest limit the API accepts).
+    ///
+    /// [`ClubLeaderboard`]: ../model/rankings/clubs/struct.ClubLeaderboard.html
+    pub fn club_leaderboard(&mut self, country_code: &str, limit: u8) -> ClubLeaderboard {
+        let limit = limit.min(MAX_LIMIT as u8);
+        let mut trophies = self.range(1_000_000..=1_400_000);
+        let items = (1..=limit).map(|rank| {
+            trophies = trophies.saturating_sub(self.range(0..=3000));
+            ClubRanking {
+                tag: self.tag(),
+                name: self.name(),
+                badge_id: 8_000_000 + self.range(0..=80),
+                trophies,
+                rank,
+                member_count: self.range(25..=30),
+            }
+        }).collect();
+
+        ClubLeaderboard {
+            country_code: String::from(country_code),
+            limit,
+            items,
+            paging: Some(Paging::default()),
+        }
+    }
+
+    /// Generates a [`BrawlerLeaderboard`] of the given brawler, in the given country code (or
+    /// `"global"`), with `limit` rankings (clamped to 200, the greatest limit the API accepts).
+    ///
+    /// [`BrawlerLeaderboard`]: ../model/rankings/brawlers/struct.BrawlerLeaderboard.html
+    pub fn brawler_leaderboard(
+        &mut self, country_code: &str, brawler_id: BrawlerId, limit: u8
+    ) -> BrawlerLeaderboard {
+        let limit = limit.min(MAX_LIMIT as u8);
+        let top_trophies = self.range(1200..=1600);
+        BrawlerLeaderboard {
+            country_code: String::from(country_code),
+            brawler_id,
+            limit,
+            items: self.player_rankings(limit, top_trophies, 4),
+            paging: Some(Paging::default()),
+        }
+    }
+}
+
+#[cfg(feature = "brawlers")]
+impl Faker {
+    /// Picks one of the brawlers known by the lib (see [`Brawlers`]), as a [`Brawler`] with all
+    /// of its star powers and gadgets.
+    ///
+    /// [`Brawlers`]: ../constants/enum.Brawlers.html
+    /// [`Brawler`]: ../model/brawlers/struct.Brawler.html
+    pub fn brawler(&mut self) -> Brawler {
+        brawler_at(self.range(0..=BRAWLERS.len() - 1))
+    }
+
+    /// Returns a [`BrawlerList`] of all brawlers known by the lib (see [`Brawlers`]). The star
+    /// powers and gadgets of each brawler are the same as in the players generated by
+    /// [`player`].
+    ///
+    /// [`BrawlerList`]: ../model/brawlers/struct.BrawlerList.html
+    /// [`Brawlers`]: ../constants/enum.Brawlers.html
+    /// [`player`]: #method.player
+    pub fn brawler_list(&self) -> BrawlerList {
+        BrawlerList { items: (0..BRAWLERS.len()).map(brawler_at).collect() }
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(test, feature = "players", feature = "clubs", feature = "rankings"))]
+mod tests {
+    use super::{Faker, format_battle_time};
+    use crate::constants::TAG_CHARS;
+    use crate::model::clubs::ClubMemberRole;
+
+    fn assert_valid_tag(tag: &str) {
+        assert!(tag.starts_with('#'), "tag {:?} has no hashtag", tag);
+        assert!(
+            tag.len() > 1 && tag[1..].chars().all(|c| TAG_CHARS.contains(c)),
+            "tag {:?} is invalid", tag
+        );
+    }
+
+    /// Tests that the same seed generates the same models.
+    #[test]
+    fn fake_is_deterministic() {
+        let (mut first, mut second) = (Faker::new(7), Faker::new(7));
+        assert_eq!(first.player(), second.player());
+        assert_eq!(first.club(), second.club());
+        assert_eq!(
+            first.player_leaderboard("global", 10), second.player_leaderboard("global", 10)
+        );
+
+        assert_ne!(Faker::new(7).player(), Faker::new(8).player());
+    }
+
+    /// Tests the consistency of generated players and battle logs.
+    #[test]
+    fn fake_players_consistent() {
+        for seed in 0..50 {
+            let mut faker = Faker::new(seed);
+            let player = faker.player();
+            assert_valid_tag(&player.tag);
+            assert!(player.highest_trophies >= player.trophies);
+            assert_eq!(player.trophies, player.brawlers.iter().map(|b| b.trophies).sum::<usize>());
+            for brawler in &player.brawlers {
+                assert!(brawler.highest_trophies >= brawler.trophies);
+                assert!(brawler.power >= 1 && brawler.power <= 11);
+                assert!(brawler.star_powers.is_empty() || brawler.power >= 9);
+                assert!(brawler.gadgets.is_empty() || brawler.power >= 7);
+            }
+
+            let battlelog = faker.battlelog(&player);
+            assert_eq!(battlelog.items.len(), 25);
+            for (i, battle) in battlelog.items.iter().enumerate() {
+                if i > 0 {
+                    assert!(battle.battle_time.0 < battlelog.items[i - 1].battle_time.0);
+                }
+                let result = &battle.result;
+                let participants: Vec<_> = match result.mode.as_str() {
+                    "soloShowdown" => {
+                        assert!(result.teams.is_none());
+                        let players = result.players.as_ref().unwrap();
+                        assert_eq!(players.len(), 10);
+                        let rank = usize::from(result.rank.unwrap());
+                        assert_eq!(players[rank - 1].tag, player.tag);
+                        players.iter().collect()
+                    },
+                    "duoShowdown" => {
+                        let teams = result.teams.as_ref().unwrap();
+                        assert_eq!(teams.len(), 5);
+                        assert!(teams.iter().all(|team| team.len() == 2));
+                        teams.iter().flatten().collect()
+                    },
+                    _ => {
+                        let teams = result.teams.as_ref().unwrap();
+                        assert_eq!(teams.len(), 2);
+                        assert!(teams.iter().all(|team| team.len() == 3));
+                        assert!(result.result.is_some() && result.star_player.is_some());
+                        teams.iter().flatten().collect()
+                    },
+                };
+                assert_eq!(participants.iter().filter(|p| p.tag == player.tag).count(), 1);
+                participants.iter().for_each(|p| assert_valid_tag(&p.tag));
+                assert_eq!(battle.event.mode, result.mode);
+            }
+        }
+    }
+
+    /// Tests the consistency of generated clubs and leaderboards.
+    #[test]
+    fn fake_clubs_and_rankings_consistent() {
+        for seed in 0..50 {
+            let mut faker = Faker::new(seed);
+            let club = faker.club();
+            assert_valid_tag(&club.tag);
+            assert_eq!(club.members.tag, club.tag);
+            assert!(!club.members.is_empty() && club.members.len() <= 30);
+            assert_eq!(
+                club.members.iter().filter(|m| m.role == ClubMemberRole::President).count(), 1
+            );
+            assert_eq!(club.trophies, club.members.iter().map(|m| m.trophies).sum::<usize>());
+
+            let leaderboard = faker.player_leaderboard("global", 200);
+            assert_eq!(leaderboard.items.len(), 200);
+            for (i, ranking) in leaderboard.items.iter().enumerate() {
+                assert_eq!(usize::from(ranking.rank), i + 1);
+                if i > 0 {
+                    assert!(ranking.trophies <= leaderboard.items[i - 1].trophies);
+                }
+            }
+        }
+    }
+
+    /// Tests that leaderboards never have more rankings than the API gives, so that their limit
+    /// can be used to fetch them again.
+    #[test]
+    fn fake_leaderboards_max_limit() {
+        use crate::ids::BrawlerId;
+        use crate::traits::PropLimRouteable;
+        use crate::model::rankings::{PlayerLeaderboard, ClubLeaderboard};
+
+        let mut faker = Faker::new(0);
+        let players = faker.player_leaderboard("global", 255);
+        let clubs = faker.club_leaderboard("global", 255);
+        let brawlers = faker.brawler_leaderboard("global", BrawlerId(16000000), 255);
+
+        assert_eq!((players.limit, clubs.limit, brawlers.limit), (200, 200, 200));
+        assert_eq!((players.len(), clubs.len(), brawlers.len()), (200, 200, 200));
+        assert!(PlayerLeaderboard::get_route("global", players.limit).to_url().is_ok());
+        assert!(ClubLeaderboard::get_route("global", clubs.limit).to_url().is_ok());
+    }
+
+    /// Tests the formatting of battle times.
+    #[test]
+    fn fake_battle_time_format() {
+        assert_eq!(format_battle_time(1_580_430_872), "20200131T003432.000Z");
+        assert_eq!(format_battle_time(951_782_400), "20000229T000000.000Z");
+    }
+}
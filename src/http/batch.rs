@@ -0,0 +1,344 @@
+//! Contains the [`BatchFetcher`] struct, used for fetching many models at once with the blocking
+//! (sync) client, by spreading the requests over a pool of worker threads.
+//!
+//! [`BatchFetcher`]: struct.BatchFetcher.html
+
+use std::borrow::Borrow;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::fmt::{Debug, Formatter};
+
+use crate::error::{Result, Error};
+use crate::traits::Endpoint;
+use super::Client;
+
+/// The default amount of worker threads used by a [`BatchFetcher`].
+///
+/// [`BatchFetcher`]: struct.BatchFetcher.html
+pub const DEFAULT_BATCH_THREADS: usize = 4;
+
+/// A token which can be used to cancel a running (or not yet started) batch. Cloning it yields
+/// a token linked to the same batch(es); cancelling any of the clones cancels all of them.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::http::CancelToken;
+///
+/// let token = CancelToken::new();
+/// let other = token.clone();
+///
+/// other.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Creates a new, non-cancelled token.
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// Cancels every batch using this token. Requests that are already in-flight are completed,
+    /// but no new ones are started.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns whether or not this token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// A progress callback, receiving the amount of completed fetches and the total.
+type ProgressFn<'a> = dyn Fn(usize, usize) + Send + Sync + 'a;
+
+/// (Sync) Fetches many instances of a model at once, spreading the requests over a configurable
+/// pool of worker threads which share the same [`Client`].
+///
+/// Results are returned **in input order**, each of them being independent of the others (one
+/// failed fetch does not stop the batch). Progress can be reported through a callback (see
+/// [`on_progress`]), and the batch may be cancelled through a [`CancelToken`] (see
+/// [`cancel_token`]), in which case all fetches that were not started yield an
+/// [`Error::Cancelled`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use brawl_api::{Client, Player, http::BatchFetcher, traits::*};
+///
+/// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+/// let my_client = Client::new("my auth token");
+/// let tags = vec!["#PLAYER1", "#PLAYER2", "#PLAYER3"];
+///
+/// let players = BatchFetcher::new(&my_client)
+///     .threads(8)
+///     .on_progress(|done, total| println!("{}/{}", done, total))
+///     .fetch::<Player, _>(&tags);
+///
+/// for (tag, result) in tags.iter().zip(players) {
+///     // ...
+/// }
+///
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Client`]: ../client/struct.Client.html
+/// [`on_progress`]: #method.on_progress
+/// [`cancel_token`]: #method.cancel_token
+/// [`CancelToken`]: struct.CancelToken.html
+/// [`Error::Cancelled`]: ../../error/enum.Error.html#variant.Cancelled
+pub struct BatchFetcher<'a> {
+    client: &'a Client,
+    threads: usize,
+    progress: Option<Box<ProgressFn<'a>>>,
+    cancel: CancelToken,
+}
+
+impl<'a> Debug for BatchFetcher<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        f.debug_struct("BatchFetcher")
+            .field("threads", &self.threads)
+            .field("progress", &self.progress.as_ref().map(|_| "Fn(usize, usize)"))
+            .field("cancel", &self.cancel)
+            .finish()
+    }
+}
+
+impl<'a> BatchFetcher<'a> {
+    /// Creates a new `BatchFetcher` using the given client, with [`DEFAULT_BATCH_THREADS`]
+    /// worker threads, no progress callback and a new [`CancelToken`].
+    ///
+    /// [`DEFAULT_BATCH_THREADS`]: constant.DEFAULT_BATCH_THREADS.html
+    /// [`CancelToken`]: struct.CancelToken.html
+    pub fn new(client: &'a Client) -> BatchFetcher<'a> {
+        BatchFetcher {
+            client,
+            threads: DEFAULT_BATCH_THREADS,
+            progress: None,
+            cancel: CancelToken::new(),
+        }
+    }
+
+    /// Sets the amount of worker threads (a value of `0` is treated as `1`).
+    pub fn threads(mut self, threads: usize) -> BatchFetcher<'a> {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Sets a callback which is called, from the worker threads, after each fetch is completed
+    /// (successfully or not), receiving the amount of completed fetches and the total.
+    pub fn on_progress<F>(mut self, callback: F) -> BatchFetcher<'a>
+        where F: Fn(usize, usize) + Send + Sync + 'a {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the token used for cancelling this batch. (Keep a clone of it in order to cancel.)
+    pub fn cancel_token(mut self, token: CancelToken) -> BatchFetcher<'a> {
+        self.cancel = token;
+        self
+    }
+
+    /// Returns (a clone of) the token used for cancelling this batch.
+    pub fn get_cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+
+    /// (Sync) Fetches one instance of the model `E` for each of the given parameters (e.g.
+    /// tags), blocking until all of them are done (or the batch is cancelled).
+    ///
+    /// The returned vector has the same length and order as `params`.
+    ///
+    /// # Errors
+    ///
+    /// Each item may independently be an `Err`; see the respective model's `fetch` function for
+    /// possible errors. Additionally, fetches which were not started due to cancellation result
+    /// in an [`Error::Cancelled`].
+    ///
+    /// [`Error::Cancelled`]: ../../error/enum.Error.html#variant.Cancelled
+    pub fn fetch<E, P>(&self, params: &[P]) -> Vec<Result<E>>
+        where E: Endpoint + Send,
+              P: Borrow<E::Params> + Sync,
+    {
+        let total = params.len();
+        let next = AtomicUsize::new(0);
+        let completed = AtomicUsize::new(0);
+        let results: Mutex<Vec<Option<Result<E>>>> = Mutex::new(
+            (0..total).map(|_| None).collect()
+        );
+
+        thread::scope(|scope| {
+            for _ in 0..self.threads.min(total) {
+                scope.spawn(|| loop {
+                    if self.cancel.is_cancelled() {
+                        break;
+                    }
+
+                    let index = next.fetch_add(1, Ordering::SeqCst);
+                    let param = match params.get(index) {
+                        Some(param) => param,
+                        None => break,
+                    };
+
+                    let result = self.client.get::<E>(param.borrow());
+                    results.lock().unwrap()[index] = Some(result);
+
+                    let done = completed.fetch_add(1, Ordering::SeqCst) + 1;
+                    if let Some(ref progress) = self.progress {
+                        progress(done, total);
+                    }
+                });
+            }
+        });
+
+        results.into_inner().unwrap()
+            .into_iter()
+            .map(|result| result.unwrap_or(Err(Error::Cancelled)))
+            .collect()
+    }
+}
+
+impl Client {
+    /// Creates a [`BatchFetcher`] for fetching many models at once using this client.
+    ///
+    /// [`BatchFetcher`]: ../batch/struct.BatchFetcher.html
+    pub fn batch(&self) -> BatchFetcher<'_> {
+        BatchFetcher::new(self)
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(test, feature = "players"))]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use super::{BatchFetcher, CancelToken};
+    use crate::http::Client;
+    use crate::error::Error;
+    use crate::model::players::Player;
+
+    /// Tests that a cancelled batch does not fetch and keeps input order and length.
+    #[test]
+    fn batch_cancelled() {
+        let client = Client::new("my auth key");
+        let token = CancelToken::new();
+        let calls = AtomicUsize::new(0);
+        token.cancel();
+
+        let results = client.batch()
+            .threads(3)
+            .cancel_token(token)
+            .on_progress(|_, _| { calls.fetch_add(1, Ordering::SeqCst); })
+            .fetch::<Player, _>(&["#AAAAAAA", "#BBBBBBB", "#CCCCCCC", "#DDDDDDD"]);
+
+        assert_eq!(results.len(), 4);
+        assert!(results.iter().all(|result| matches!(result, Err(Error::Cancelled))));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    /// Tests that cancelling while the batch runs (here, from the progress callback) lets the
+    /// fetch in progress complete, while the remaining ones yield `Error::Cancelled`.
+    #[test]
+    fn batch_cancel_midway() {
+        use http::StatusCode;
+        use crate::http::{Middleware, RawResponse};
+        use crate::http::request::Request;
+
+        /// Answers every request with a default player.
+        struct Players;
+
+        impl Middleware for Players {
+            fn on_request(&self, _request: &mut Request<'_>) -> Option<RawResponse> {
+                let player = serde_json::to_string(&Player::default()).unwrap();
+                Some(RawResponse::new(StatusCode::OK, player))
+            }
+        }
+
+        let client = Client::new("my auth key").with_middleware(Players);
+        let token = CancelToken::new();
+        let canceller = token.clone();
+
+        let results = client.batch()
+            .threads(1)
+            .cancel_token(token)
+            .on_progress(move |done, _| if done == 2 { canceller.cancel() })
+            .fetch::<Player, _>(&["#AAAAAAA", "#BBBBBBB", "#CCCCCCC", "#DDDDDDD", "#GGGGGGG"]);
+
+        assert_eq!(results.len(), 5);
+        assert!(results[..2].iter().all(Result::is_ok));
+        assert!(results[2..].iter().all(|result| matches!(result, Err(Error::Cancelled))));
+    }
+
+    /// Tests that an empty batch returns no results.
+    #[test]
+    fn batch_empty() {
+        let client = Client::new("my auth key");
+        let results = BatchFetcher::new(&client).fetch::<Player, &str>(&[]);
+
+        assert!(results.is_empty());
+    }
+
+    /// Tests that results keep input order (even if fetches complete in another order), that
+    /// failed fetches don't stop the others, and that progress is reported for every fetch.
+    #[test]
+    fn batch_order_progress_errors() {
+        use std::sync::Mutex;
+        use std::thread;
+        use std::time::Duration;
+        use http::StatusCode;
+        use crate::http::{Middleware, RawResponse};
+        use crate::http::request::Request;
+
+        /// Answers for `#BAD` with a 404, and for other tags with a player, taking less time for
+        /// later tags (so that they complete first).
+        struct Players;
+
+        impl Middleware for Players {
+            fn on_request(&self, request: &mut Request<'_>) -> Option<RawResponse> {
+                let tag = request.endpoint.rsplit('/').next().unwrap().replace("%23", "#");
+                if tag == "#BAD" {
+                    return Some(RawResponse::new(StatusCode::NOT_FOUND, "{}"));
+                }
+
+                let delay = 5 * u64::from(b'Z' - tag.as_bytes()[1]);
+                thread::sleep(Duration::from_millis(delay));
+
+                let player = Player { tag, ..Player::default() };
+                Some(RawResponse::new(StatusCode::OK, serde_json::to_string(&player).unwrap()))
+            }
+        }
+
+        let client = Client::new("my auth key").with_middleware(Players);
+        let tags = ["#AAA", "#CCC", "#BAD", "#GGG", "#JJJ", "#LLL", "#PPP", "#QQQ", "#UUU"];
+        let progress = Mutex::new(Vec::new());
+
+        let results = client.batch()
+            .threads(3)
+            .on_progress(|done, total| progress.lock().unwrap().push((done, total)))
+            .fetch::<Player, _>(&tags);
+
+        assert_eq!(results.len(), tags.len());
+        for (&tag, result) in tags.iter().zip(&results) {
+            match result {
+                Ok(player) => assert_eq!(player.tag, tag),
+                Err(Error::Status(status, ..)) => {
+                    assert_eq!(tag, "#BAD");
+                    assert_eq!(*status, StatusCode::NOT_FOUND);
+                },
+                Err(err) => panic!("Unexpected error for {}: {:?}", tag, err),
+            }
+        }
+
+        let mut progress = progress.into_inner().unwrap();
+        progress.sort_unstable();
+        let expected: Vec<_> = (1..=tags.len()).map(|done| (done, tags.len())).collect();
+        assert_eq!(progress, expected);
+    }
+}
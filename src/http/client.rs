@@ -1,27 +1,101 @@
 //! Contains the `Client` class, responsible for API authentication.
 
+#[cfg(feature = "blocking")]
 use reqwest::blocking::{
     Client as ReqClient, ClientBuilder as ReqClientBuilder,
-    RequestBuilder
 };
 
 #[cfg(feature = "async")]
 use reqwest::{
     Client as AReqClient, ClientBuilder as AReqClientBuilder,
-    RequestBuilder as ARequestBuilder
 };
 
-use crate::constants::USER_AGENT as BRAWL_USER_AGENT;
+#[cfg(feature = "ureq")]
+use ureq::{Agent as UreqAgent, AgentBuilder as UreqAgentBuilder};
+
+#[cfg(feature = "ureq")]
+use std::time::Duration;
+
 use crate::http::request::Request;
+use crate::http::coalesce::Coalescer;
+use crate::http::keys::KeyPool;
+use crate::http::key_info::KeyInfo;
+use crate::http::secret::AuthKey;
+use crate::http::middleware::{Middleware, MiddlewareChain};
+use crate::http::metrics::{Metrics, MetricsSnapshot};
+use std::sync::Arc;
 use crate::error::Result;
 
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::constants::USER_AGENT as BRAWL_USER_AGENT;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::traits::Endpoint;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use serde::de::DeserializeOwned;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::http::fetched::Fetched;
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+use crate::util::{fetch_route, fetch_route_meta, request_json};
+
+#[cfg(feature = "async")]
+use crate::util::{a_fetch_route, a_fetch_route_meta, a_request_json};
+
+#[cfg(feature = "async")]
+use std::borrow::Borrow;
+
+#[cfg(feature = "async")]
+use futures_util::stream::{self, Stream, StreamExt};
+
+#[cfg(all(
+    any(feature = "rankings", feature = "brawlers"),
+    any(feature = "blocking", feature = "ureq", feature = "async"),
+))]
+use crate::ids::BrawlerId;
+
+#[cfg(all(feature = "rankings", any(feature = "blocking", feature = "ureq", feature = "async")))]
+use crate::traits::{RankingParams, BrawlerRankingParams};
+
+#[cfg(all(feature = "players", any(feature = "blocking", feature = "ureq", feature = "async")))]
+use crate::model::players::{Player, BattleLog};
+
+#[cfg(all(feature = "clubs", any(feature = "blocking", feature = "ureq", feature = "async")))]
+use crate::model::clubs::{Club, ClubMembers};
+
+#[cfg(all(feature = "rankings", any(feature = "blocking", feature = "ureq", feature = "async")))]
+use crate::model::rankings::{PlayerLeaderboard, ClubLeaderboard, BrawlerLeaderboard};
+
+#[cfg(all(feature = "brawlers", any(feature = "blocking", feature = "ureq", feature = "async")))]
+use crate::model::brawlers::{BrawlerList, Brawler};
+
 #[derive(Debug, Clone)]
 pub struct Client {
-    pub auth_key: String,
+    pub auth_key: AuthKey,
+
+    #[cfg(feature = "blocking")]
     pub(crate) inner: ReqClient,
 
     #[cfg(feature = "async")]
     pub(crate) a_inner: AReqClient,
+
+    /// The `ureq` agent used for sync requests instead of `inner`, with the `ureq` feature.
+    #[cfg(feature = "ureq")]
+    pub(crate) u_inner: UreqAgent,
+
+    /// The requests in-flight, if request coalescing is enabled (see `with_coalescing`).
+    pub(crate) coalescer: Option<Arc<Coalescer>>,
+
+    /// The pool of keys to use instead of `auth_key`, if any (see `with_key_pool`).
+    pub(crate) key_pool: Option<Arc<KeyPool>>,
+
+    /// The middleware run on every request (see `with_middleware`).
+    pub(crate) middleware: MiddlewareChain,
+
+    /// The metrics of this client and its clones (see `metrics`).
+    pub(crate) metrics: Arc<Metrics>,
 }
 
 /// Represents an HTTP client which holds the user's API auth key, and is required on every fetch
@@ -41,30 +115,203 @@ impl Client {
     /// let my_client = Client::new("my auth key");
     /// ```
     pub fn new(auth_key: &str) -> Client {
+        Client::from_key(AuthKey::new(auth_key))
+    }
+
+    /// Creates a new Client with a given API auth key, already wrapped in an [`AuthKey`] (e.g.
+    /// one loaded through [`AuthKey::from_env`] or [`AuthKey::from_file`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::{Client, http::AuthKey};
+    ///
+    /// let my_client = Client::from_key(AuthKey::new("my auth key"));
+    /// ```
+    ///
+    /// [`AuthKey`]: ../secret/struct.AuthKey.html
+    /// [`AuthKey::from_env`]: ../secret/struct.AuthKey.html#method.from_env
+    /// [`AuthKey::from_file`]: ../secret/struct.AuthKey.html#method.from_file
+    pub fn from_key(auth_key: AuthKey) -> Client {
+        #[cfg(feature = "blocking")]
         let inner_b: ReqClientBuilder = ReqClient::builder().user_agent(BRAWL_USER_AGENT);
 
         #[cfg(feature = "async")]
         let a_inner_b: AReqClientBuilder = AReqClient::builder().user_agent(BRAWL_USER_AGENT);
 
+        #[cfg(feature = "ureq")]
+        let u_inner_b: UreqAgentBuilder = UreqAgentBuilder::new()
+            .user_agent(BRAWL_USER_AGENT)
+            .timeout(Duration::from_secs(30));  // same as reqwest's blocking client
+
         Client {
-            auth_key: String::from(auth_key),
+            auth_key,
+            #[cfg(feature = "blocking")]
             inner: inner_b.build().unwrap(),
 
             #[cfg(feature = "async")]
             a_inner: a_inner_b.build().unwrap(),
+
+            #[cfg(feature = "ureq")]
+            u_inner: u_inner_b.build(),
+
+            coalescer: None,
+            key_pool: None,
+            middleware: MiddlewareChain::default(),
+            metrics: Arc::new(Metrics::default()),
         }
     }
 
+    /// Creates a new Client which spreads its requests over a pool of API keys (see
+    /// [`KeyPool`]). The [`auth_key`] field is set to the pool's first key, but is otherwise
+    /// unused.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::Client;
+    /// use brawl_api::http::KeyPool;
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::with_key_pool(KeyPool::new(&["first key", "second key"])?);
+    /// assert_eq!(my_client.key_pool().unwrap().len(), 2);
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`KeyPool`]: ../keys/struct.KeyPool.html
+    /// [`auth_key`]: #structfield.auth_key
+    pub fn with_key_pool(pool: KeyPool) -> Client {
+        let mut client = Client::from_key(pool.first().clone());
+        client.key_pool = Some(Arc::new(pool));
+        client
+    }
+
+    /// Returns the pool of keys used by this client, if any (see [`with_key_pool`]). This can
+    /// be used to inspect per-key counters, through [`KeyPool::stats`].
+    ///
+    /// [`with_key_pool`]: #method.with_key_pool
+    /// [`KeyPool::stats`]: ../keys/struct.KeyPool.html#method.stats
+    pub fn key_pool(&self) -> Option<&KeyPool> {
+        self.key_pool.as_deref()
+    }
+
+    /// Decodes the information contained in this client's [`auth_key`] (issuer, developer ID,
+    /// scopes, allowed IP ranges and rate tiers), without requesting to the API.
+    ///
+    /// This can be used, for instance, to check whether the current IP address is allowed for
+    /// the key (through [`KeyInfo::allows_ip`]) before the API refuses a request with a 403.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::InvalidKey`] if the key could not be decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::Client;
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let info = my_client.key_info()?;
+    ///
+    /// if !info.allows_ip("203.0.113.7".parse()?) {
+    ///     println!("This key can't be used from here!");
+    /// }
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`auth_key`]: #structfield.auth_key
+    /// [`KeyInfo::allows_ip`]: ../key_info/struct.KeyInfo.html#method.allows_ip
+    /// [`Error::InvalidKey`]: ../../error/enum.Error.html#variant.InvalidKey
+    pub fn key_info(&self) -> Result<KeyInfo> {
+        KeyInfo::decode(self.auth_key.expose_secret())
+    }
+
+    /// Enables (or disables) in-flight request coalescing: while a request to some route is
+    /// running, any other identical request made through this client (or any of its clones)
+    /// waits for it and receives a copy of its result, instead of being sent again. This applies
+    /// to both sync and async fetching. Disabled by default.
+    ///
+    /// When a shared request fails, every caller receives the same error (e.g. an
+    /// [`Error::Status`]), except for errors which cannot be copied, such as connection errors:
+    /// then, the callers which waited for the request receive an [`Error::Coalesced`] with the
+    /// original error's message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::Client;
+    ///
+    /// let my_client = Client::new("my auth key").with_coalescing(true);
+    /// assert!(my_client.is_coalescing());
+    /// ```
+    ///
+    /// [`Error::Status`]: ../../error/enum.Error.html#variant.Status
+    /// [`Error::Coalesced`]: ../../error/enum.Error.html#variant.Coalesced
+    pub fn with_coalescing(mut self, enabled: bool) -> Client {
+        self.coalescer = if enabled {
+            Some(self.coalescer.unwrap_or_default())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Returns whether or not in-flight request coalescing is enabled (see [`with_coalescing`]).
+    ///
+    /// [`with_coalescing`]: #method.with_coalescing
+    pub fn is_coalescing(&self) -> bool {
+        self.coalescer.is_some()
+    }
+
+    /// Adds a middleware to this client (after any previously added ones), which will run on
+    /// every request it sends, be it sync or async. Middleware is shared between clones of the
+    /// client made after this call.
+    ///
+    /// See [`Middleware`] for details and examples.
+    ///
+    /// [`Middleware`]: ../middleware/trait.Middleware.html
+    pub fn with_middleware<M: Middleware + 'static>(mut self, middleware: M) -> Client {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Returns a snapshot of the metrics of this client (shared with all of its clones):
+    /// requests, status classes, latency, bytes received and ratelimits per kind of route, as
+    /// well as request coalescing hits and misses. These are recorded for both sync and async
+    /// requests.
+    ///
+    /// See [`MetricsSnapshot`] for details (including Prometheus export, with the
+    /// `prometheus` feature).
+    ///
+    /// [`MetricsSnapshot`]: ../metrics/struct.MetricsSnapshot.html
+    pub fn metrics(&self) -> MetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
     /// (For sync usage) Provides an immutable reference to the [`inner`] field.
     ///
     /// [`inner`]: #structfield.inner
+    #[cfg(feature = "blocking")]
     pub fn inner(&self) -> &ReqClient { &self.inner }
 
     /// (For sync usage) Provides a mutable reference to the [`inner`] field.
     ///
     /// [`inner`]: #structfield.inner
+    #[cfg(feature = "blocking")]
     pub fn inner_mut(&mut self) -> &mut ReqClient { &mut self.inner }
 
+    /// (For sync usage, with the `ureq` feature) Provides an immutable reference to the `ureq`
+    /// agent used to send sync requests (instead of [`inner`]).
+    ///
+    /// [`inner`]: #structfield.inner
+    #[cfg(feature = "ureq")]
+    pub fn u_inner(&self) -> &UreqAgent { &self.u_inner }
+
     /// (For async usage) Provides an immutable reference to the [`a_inner`] field.
     ///
     /// [`a_inner`]: #structfield.a_inner
@@ -77,25 +324,464 @@ impl Client {
     #[cfg(feature = "async")]
     pub fn a_inner_mut(&mut self) -> &mut AReqClient { &mut self.a_inner }
 
-    /// Creates a Request instance for one specific endpoint and returns it.
+    /// Creates a Request instance for one specific endpoint (a full URL, or one relative to the
+    /// API's base URL) and returns it. It can then be sent with [`request_json`].
+    ///
+    /// [`request_json`]: #method.request_json
     pub fn endpoint_request(&self, endpoint: &str) -> Request<'_> {
         let mut req = Request::<'_>::default();
         req.endpoint = String::from(endpoint);
         req
     }
 
-    /// (For sync usage) Creates a Request instance for one specific endpoint and calls
-    /// [`Request::build`] on the newly-made instance, returning a (blocking) `RequestBuilder`.
-    /// (GET)
-    pub(crate) fn build_endpoint_get(&self, endpoint: &str) -> Result<RequestBuilder> {
-        self.endpoint_request(endpoint).build(&self)
+    /// (Sync) Sends an arbitrary [`Request`] (with this client's authentication), deserializing
+    /// the response's JSON body into any deserializable type - such as `serde_json::Value`, or a
+    /// custom struct. This allows using API endpoints which do not have models in this library.
+    ///
+    /// The request's `endpoint` may be either a full URL or relative to the API's base URL
+    /// (e.g. `"events/rotation"`).
+    ///
+    /// # Errors
+    ///
+    /// This function may error:
+    /// - While building the request (an invalid URL - [`Error::Url`] - or an invalid auth key format - [`Error::Authorization`]);
+    /// - While requesting (will return an [`Error::Request`]);
+    /// - After receiving a bad status code (API or other error - returns an [`Error::Status`]);
+    /// - After a ratelimit is indicated by the API, while also specifying when it is lifted ([`Error::Ratelimited`]);
+    /// - While parsing incoming JSON (will return an [`Error::Json`]).
+    ///
+    /// (All of those, of course, wrapped inside an `Err`.)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::Client;
+    /// use serde_json::Value;
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let request = my_client.endpoint_request("events/rotation");
+    /// let rotation: Value = my_client.request_json(&request)?;
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Request`]: ../request/struct.Request.html
+    /// [`Error::Url`]: ../../error/enum.Error.html#variant.Url
+    /// [`Error::Authorization`]: ../../error/enum.Error.html#variant.Authorization
+    /// [`Error::Request`]: ../../error/enum.Error.html#variant.Request
+    /// [`Error::Status`]: ../../error/enum.Error.html#variant.Status
+    /// [`Error::Ratelimited`]: ../../error/enum.Error.html#variant.Ratelimited
+    /// [`Error::Json`]: ../../error/enum.Error.html#variant.Json
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    pub fn request_json<T: DeserializeOwned>(&self, request: &Request<'_>) -> Result<T> {
+        request_json::<T>(self, request)
+    }
+
+    /// (Async) Sends an arbitrary [`Request`] (with this client's authentication), deserializing
+    /// the response's JSON body into any deserializable type - such as `serde_json::Value`, or a
+    /// custom struct. See [`Client::request_json`] for more info.
+    ///
+    /// # Errors
+    ///
+    /// See [`Client::request_json`].
+    ///
+    /// [`Request`]: ../request/struct.Request.html
+    /// [`Client::request_json`]: #method.request_json
+    #[cfg(feature = "async")]
+    pub async fn a_request_json<T: DeserializeOwned>(&self, request: &Request<'_>) -> Result<T> {
+        a_request_json::<T>(self, request).await
+    }
+}
+
+/// Generic fetching methods, used by every model's `fetch` function.
+impl Client {
+    /// (Sync) Fetches any model implementing [`Endpoint`], given its parameters (e.g. a tag).
+    ///
+    /// # Errors
+    ///
+    /// This function may error:
+    /// - While requesting (will return an [`Error::Request`]);
+    /// - After receiving a bad status code (API or other error - returns an [`Error::Status`]);
+    /// - After a ratelimit is indicated by the API, while also specifying when it is lifted ([`Error::Ratelimited`]);
+    /// - While parsing incoming JSON (will return an [`Error::Json`]).
+    ///
+    /// (All of those, of course, wrapped inside an `Err`.)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, Player, ClubMembers, PlayerLeaderboard, traits::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let player = my_client.get::<Player>("#PLAYERTAGHERE")?;
+    /// let members = my_client.get::<ClubMembers>("#CLUBTAGHERE")?;
+    /// let top_5 = my_client.get::<PlayerLeaderboard>(&RankingParams::new("global", 5))?;
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Endpoint`]: ../../traits/endpoint/trait.Endpoint.html
+    /// [`Error::Request`]: ../../error/enum.Error.html#variant.Request
+    /// [`Error::Status`]: ../../error/enum.Error.html#variant.Status
+    /// [`Error::Ratelimited`]: ../../error/enum.Error.html#variant.Ratelimited
+    /// [`Error::Json`]: ../../error/enum.Error.html#variant.Json
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    pub fn get<E: Endpoint>(&self, params: &E::Params) -> Result<E> {
+        let route = E::route(params);
+        let mut value = fetch_route::<E>(self, &route)?;
+        value.on_fetched(params);
+        Ok(value)
+    }
+
+    /// (Sync) Like [`Client::get`], but also returns metadata about the response (status,
+    /// headers, fetch time and ratelimit state), through a [`Fetched`] instance. Note that these
+    /// requests are never coalesced (see [`with_coalescing`]).
+    ///
+    /// # Errors
+    ///
+    /// See [`Client::get`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, Club, traits::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let club = my_client.get_with_meta::<Club>("#CLUBTAGHERE")?;
+    ///
+    /// println!("Requests remaining: {:?}", club.ratelimit.remaining);
+    /// println!("Fresh for: {:?}", club.max_age());
+    /// println!("Club name: {}", club.name);  // dereferences to the Club
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Client::get`]: #method.get
+    /// [`Fetched`]: ../fetched/struct.Fetched.html
+    /// [`with_coalescing`]: #method.with_coalescing
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    pub fn get_with_meta<E: Endpoint>(&self, params: &E::Params) -> Result<Fetched<E>> {
+        let route = E::route(params);
+        let mut fetched = fetch_route_meta::<E>(self, &route)?;
+        fetched.value.on_fetched(params);
+        Ok(fetched)
+    }
+
+    /// (Async) Fetches any model implementing [`Endpoint`], given its parameters (e.g. a tag).
+    ///
+    /// # Errors
+    ///
+    /// See [`Client::get`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, Player, traits::*};
+    ///
+    /// # async fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let player = my_client.a_get::<Player>("#PLAYERTAGHERE").await?;
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Endpoint`]: ../../traits/endpoint/trait.Endpoint.html
+    /// [`Client::get`]: #method.get
+    #[cfg(feature = "async")]
+    pub async fn a_get<E: Endpoint>(&self, params: &E::Params) -> Result<E> {
+        let route = E::route(params);
+        let mut value = a_fetch_route::<E>(self, &route).await?;
+        value.on_fetched(params);
+        Ok(value)
+    }
+
+    /// (Async) Like [`Client::a_get`], but also returns metadata about the response (status,
+    /// headers, fetch time and ratelimit state), through a [`Fetched`] instance. Note that these
+    /// requests are never coalesced (see [`with_coalescing`]).
+    ///
+    /// # Errors
+    ///
+    /// See [`Client::get`].
+    ///
+    /// [`Client::a_get`]: #method.a_get
+    /// [`Client::get`]: #method.get
+    /// [`Fetched`]: ../fetched/struct.Fetched.html
+    /// [`with_coalescing`]: #method.with_coalescing
+    #[cfg(feature = "async")]
+    pub async fn a_get_with_meta<E: Endpoint>(&self, params: &E::Params) -> Result<Fetched<E>> {
+        let route = E::route(params);
+        let mut fetched = a_fetch_route_meta::<E>(self, &route).await?;
+        fetched.value.on_fetched(params);
+        Ok(fetched)
     }
 
-    /// (For async usage) Creates a Request instance for one specific endpoint and calls
-    /// [`Request::build`] on the newly-made instance, returning a (non-blocking) `RequestBuilder`.
-    /// (GET)
+    /// (Async) Fetches many instances of a model implementing [`Endpoint`] concurrently, one for
+    /// each of the given parameters (e.g. tags), while keeping at most `concurrency` requests
+    /// in-flight at once (a `concurrency` of `0` is treated as `1`).
+    ///
+    /// Returns a `Stream` which yields, **in completion order** (not in input order), each given
+    /// parameter alongside the result of its fetching. Errors are kept per-item; that is, a failed
+    /// fetch (e.g. a 404 on an invalid tag) does not stop the others.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, Player, traits::*};
+    /// use futures::stream::StreamExt;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let tags = vec!["#PLAYER1", "#PLAYER2", "#PLAYER3"];
+    ///
+    /// let mut players = my_client.a_get_many::<Player, _>(tags, 2);
+    /// while let Some((tag, result)) = players.next().await {
+    ///     match result {
+    ///         Ok(player) => println!("{}: {} trophies", tag, player.trophies),
+    ///         Err(err) => println!("{}: failed ({})", tag, err),
+    ///     }
+    /// }
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Endpoint`]: ../../traits/endpoint/trait.Endpoint.html
     #[cfg(feature = "async")]
-    pub(crate) fn a_build_endpoint_get(&self, endpoint: &str) -> Result<ARequestBuilder> {
-        self.endpoint_request(endpoint).a_build(&self)
+    pub fn a_get_many<'a, E, I>(
+        &'a self, params: I, concurrency: usize
+    ) -> impl Stream<Item = (I::Item, Result<E>)> + 'a
+        where E: Endpoint + 'a,
+              I: IntoIterator,
+              I::IntoIter: 'a,
+              I::Item: Borrow<E::Params> + 'a,
+    {
+        stream::iter(params)
+            .map(move |param| async move {
+                let result = self.a_get::<E>(param.borrow()).await;
+                (param, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+}
+
+/// Convenience methods for fetching each endpoint's model. All of those are equivalent to calling
+/// [`Client::get`] (or [`Client::a_get`]) with the respective model, and may error in the same
+/// ways.
+///
+/// [`Client::get`]: #method.get
+/// [`Client::a_get`]: #method.a_get
+impl Client {
+    /// (Sync) Fetches a [`Player`] by its tag.
+    ///
+    /// [`Player`]: ../../model/players/player/struct.Player.html
+    #[cfg(all(feature = "players", any(feature = "blocking", feature = "ureq")))]
+    pub fn player(&self, tag: &str) -> Result<Player> {
+        self.get::<Player>(tag)
+    }
+
+    /// (Async) Fetches a [`Player`] by its tag.
+    ///
+    /// [`Player`]: ../../model/players/player/struct.Player.html
+    #[cfg(all(feature = "players", feature = "async"))]
+    pub async fn a_player(&self, tag: &str) -> Result<Player> {
+        self.a_get::<Player>(tag).await
+    }
+
+    /// (Sync) Fetches a player's [`BattleLog`] by their tag.
+    ///
+    /// [`BattleLog`]: ../../model/players/battlelog/struct.BattleLog.html
+    #[cfg(all(feature = "players", any(feature = "blocking", feature = "ureq")))]
+    pub fn battlelog(&self, tag: &str) -> Result<BattleLog> {
+        self.get::<BattleLog>(tag)
+    }
+
+    /// (Async) Fetches a player's [`BattleLog`] by their tag.
+    ///
+    /// [`BattleLog`]: ../../model/players/battlelog/struct.BattleLog.html
+    #[cfg(all(feature = "players", feature = "async"))]
+    pub async fn a_battlelog(&self, tag: &str) -> Result<BattleLog> {
+        self.a_get::<BattleLog>(tag).await
+    }
+
+    /// (Sync) Fetches a [`Club`] by its tag.
+    ///
+    /// [`Club`]: ../../model/clubs/struct.Club.html
+    #[cfg(all(feature = "clubs", any(feature = "blocking", feature = "ureq")))]
+    pub fn club(&self, tag: &str) -> Result<Club> {
+        self.get::<Club>(tag)
+    }
+
+    /// (Async) Fetches a [`Club`] by its tag.
+    ///
+    /// [`Club`]: ../../model/clubs/struct.Club.html
+    #[cfg(all(feature = "clubs", feature = "async"))]
+    pub async fn a_club(&self, tag: &str) -> Result<Club> {
+        self.a_get::<Club>(tag).await
+    }
+
+    /// (Sync) Fetches a club's [`ClubMembers`] by its tag.
+    ///
+    /// [`ClubMembers`]: ../../model/clubs/members/struct.ClubMembers.html
+    #[cfg(all(feature = "clubs", any(feature = "blocking", feature = "ureq")))]
+    pub fn club_members(&self, tag: &str) -> Result<ClubMembers> {
+        self.get::<ClubMembers>(tag)
+    }
+
+    /// (Async) Fetches a club's [`ClubMembers`] by its tag.
+    ///
+    /// [`ClubMembers`]: ../../model/clubs/members/struct.ClubMembers.html
+    #[cfg(all(feature = "clubs", feature = "async"))]
+    pub async fn a_club_members(&self, tag: &str) -> Result<ClubMembers> {
+        self.a_get::<ClubMembers>(tag).await
+    }
+
+    /// (Sync) Fetches the [`PlayerLeaderboard`] of a country (or `"global"`), up to `limit`
+    /// rankings.
+    ///
+    /// [`PlayerLeaderboard`]: ../../model/rankings/players/struct.PlayerLeaderboard.html
+    #[cfg(all(feature = "rankings", any(feature = "blocking", feature = "ureq")))]
+    pub fn player_rankings(&self, country_code: &str, limit: u8) -> Result<PlayerLeaderboard> {
+        self.get::<PlayerLeaderboard>(&RankingParams::new(country_code, limit))
+    }
+
+    /// (Async) Fetches the [`PlayerLeaderboard`] of a country (or `"global"`), up to `limit`
+    /// rankings.
+    ///
+    /// [`PlayerLeaderboard`]: ../../model/rankings/players/struct.PlayerLeaderboard.html
+    #[cfg(all(feature = "rankings", feature = "async"))]
+    pub async fn a_player_rankings(
+        &self, country_code: &str, limit: u8
+    ) -> Result<PlayerLeaderboard> {
+        self.a_get::<PlayerLeaderboard>(&RankingParams::new(country_code, limit)).await
+    }
+
+    /// (Sync) Fetches the [`ClubLeaderboard`] of a country (or `"global"`), up to `limit`
+    /// rankings.
+    ///
+    /// [`ClubLeaderboard`]: ../../model/rankings/clubs/struct.ClubLeaderboard.html
+    #[cfg(all(feature = "rankings", any(feature = "blocking", feature = "ureq")))]
+    pub fn club_rankings(&self, country_code: &str, limit: u8) -> Result<ClubLeaderboard> {
+        self.get::<ClubLeaderboard>(&RankingParams::new(country_code, limit))
+    }
+
+    /// (Async) Fetches the [`ClubLeaderboard`] of a country (or `"global"`), up to `limit`
+    /// rankings.
+    ///
+    /// [`ClubLeaderboard`]: ../../model/rankings/clubs/struct.ClubLeaderboard.html
+    #[cfg(all(feature = "rankings", feature = "async"))]
+    pub async fn a_club_rankings(
+        &self, country_code: &str, limit: u8
+    ) -> Result<ClubLeaderboard> {
+        self.a_get::<ClubLeaderboard>(&RankingParams::new(country_code, limit)).await
+    }
+
+    /// (Sync) Fetches the [`BrawlerLeaderboard`] of a brawler in a country (or `"global"`), up
+    /// to `limit` rankings.
+    ///
+    /// [`BrawlerLeaderboard`]: ../../model/rankings/brawlers/struct.BrawlerLeaderboard.html
+    #[cfg(all(feature = "rankings", any(feature = "blocking", feature = "ureq")))]
+    pub fn brawler_rankings(
+        &self, country_code: &str, brawler_id: BrawlerId, limit: u8
+    ) -> Result<BrawlerLeaderboard> {
+        let params = BrawlerRankingParams::new(country_code, brawler_id, limit);
+        self.get::<BrawlerLeaderboard>(&params)
+    }
+
+    /// (Async) Fetches the [`BrawlerLeaderboard`] of a brawler in a country (or `"global"`), up
+    /// to `limit` rankings.
+    ///
+    /// [`BrawlerLeaderboard`]: ../../model/rankings/brawlers/struct.BrawlerLeaderboard.html
+    #[cfg(all(feature = "rankings", feature = "async"))]
+    pub async fn a_brawler_rankings(
+        &self, country_code: &str, brawler_id: BrawlerId, limit: u8
+    ) -> Result<BrawlerLeaderboard> {
+        let params = BrawlerRankingParams::new(country_code, brawler_id, limit);
+        self.a_get::<BrawlerLeaderboard>(&params).await
+    }
+
+    /// (Sync) Fetches the [`BrawlerList`] of all brawlers in the game.
+    ///
+    /// [`BrawlerList`]: ../../model/brawlers/struct.BrawlerList.html
+    #[cfg(all(feature = "brawlers", any(feature = "blocking", feature = "ureq")))]
+    pub fn brawlers(&self) -> Result<BrawlerList> {
+        self.get::<BrawlerList>(&())
+    }
+
+    /// (Async) Fetches the [`BrawlerList`] of all brawlers in the game.
+    ///
+    /// [`BrawlerList`]: ../../model/brawlers/struct.BrawlerList.html
+    #[cfg(all(feature = "brawlers", feature = "async"))]
+    pub async fn a_brawlers(&self) -> Result<BrawlerList> {
+        self.a_get::<BrawlerList>(&()).await
+    }
+
+    /// (Sync) Fetches a [`Brawler`] by its ID.
+    ///
+    /// [`Brawler`]: ../../model/brawlers/struct.Brawler.html
+    #[cfg(all(feature = "brawlers", any(feature = "blocking", feature = "ureq")))]
+    pub fn brawler(&self, id: BrawlerId) -> Result<Brawler> {
+        self.get::<Brawler>(&id)
+    }
+
+    /// (Async) Fetches a [`Brawler`] by its ID.
+    ///
+    /// [`Brawler`]: ../../model/brawlers/struct.Brawler.html
+    #[cfg(all(feature = "brawlers", feature = "async"))]
+    pub async fn a_brawler(&self, id: BrawlerId) -> Result<Brawler> {
+        self.a_get::<Brawler>(&id).await
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    /// Tests that `a_get_many` keeps at most `concurrency` requests in-flight, and that an item
+    /// which fails to be fetched doesn't stop the others.
+    #[cfg(all(feature = "async", feature = "players"))]
+    #[test]
+    fn client_a_get_many() {
+        use std::time::Duration;
+        use futures_util::stream::StreamExt;
+        use crate::error::Error;
+        use crate::http::test_server::{TestServer, block_on};
+        use crate::model::players::Player;
+        use super::Client;
+
+        let server = TestServer::start(Duration::from_millis(50), |path| {
+            let tag = path.trim_start_matches("/players/").replace("%23", "#");
+            if tag == "#BAD" {
+                (404, String::from(r#"{"reason": "notFound"}"#))
+            } else {
+                (200, serde_json::to_string(&Player { tag, ..Player::default() }).unwrap())
+            }
+        });
+        let client = Client::new("my auth key").with_middleware(server.clone());
+
+        let tags = vec!["#AAA", "#BAD", "#CCC", "#GGG", "#JJJ", "#LLL", "#PPP", "#QQQ"];
+        let results: Vec<_> = block_on(client.a_get_many::<Player, _>(tags.clone(), 3).collect());
+
+        assert_eq!(server.total(), tags.len());
+        assert!(server.peak() <= 3, "{} requests were in-flight at once", server.peak());
+        assert!(server.peak() > 1, "the requests weren't concurrent");
+
+        assert_eq!(results.len(), tags.len());
+        for (tag, result) in results {
+            match result {
+                Ok(player) => assert_eq!(player.tag, tag),
+                Err(Error::Status(status, ..)) => {
+                    assert_eq!(tag, "#BAD");
+                    assert_eq!(status.as_u16(), 404);
+                },
+                Err(err) => panic!("Unexpected error for {}: {:?}", tag, err),
+            }
+        }
     }
 }
@@ -0,0 +1,443 @@
+//! Contains the internals of in-flight request coalescing ("single-flight"): while a request to
+//! some route is running, every other request to the same route waits for it and receives (a
+//! copy of) its result, instead of sending a new request.
+//!
+//! This is enabled through [`Client::with_coalescing`].
+//!
+//! [`Client::with_coalescing`]: ../client/struct.Client.html#method.with_coalescing
+
+use std::fmt::{Debug, Formatter};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use std::collections::HashMap;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use std::sync::{Arc, Mutex, Condvar, PoisonError};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::error::{Result, Error};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use super::{Client, routes::Route};
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+use crate::util::fetch_route_raw;
+
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin, task::{Context, Poll, Waker}};
+
+#[cfg(feature = "async")]
+use crate::util::a_fetch_route_raw;
+
+/// The outcome of a shared request, as seen by the callers which waited for it.
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+type Outcome = Result<Arc<[u8]>>;
+
+/// Copies an error for another caller: an equivalent error if possible (see
+/// [`Error::try_clone`]), or an [`Error::Coalesced`] with its message otherwise.
+///
+/// [`Error::try_clone`]: ../../error/enum.Error.html#method.try_clone
+/// [`Error::Coalesced`]: ../../error/enum.Error.html#variant.Coalesced
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+fn copy_error(err: &Error) -> Error {
+    err.try_clone().unwrap_or_else(|| Error::Coalesced(err.to_string()))
+}
+
+/// Gives a copy of a shared request's outcome to one of the callers which waited for it.
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+fn unshare(outcome: &Outcome) -> Outcome {
+    match *outcome {
+        Ok(ref body) => Ok(Arc::clone(body)),
+        Err(ref err) => Err(copy_error(err)),
+    }
+}
+
+/// The state of a request in-flight: its outcome once completed, and the async callers to wake
+/// up when that happens.
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+#[derive(Default)]
+struct FlightState {
+    outcome: Option<Outcome>,
+
+    #[cfg(feature = "async")]
+    wakers: Vec<Waker>,
+}
+
+/// A request in-flight, which other (sync or async) callers may wait for.
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+#[derive(Default)]
+struct Flight {
+    state: Mutex<FlightState>,
+    done: Condvar,
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+impl Flight {
+    /// Sets the outcome of this flight, and wakes up every caller waiting for it.
+    fn complete(&self, outcome: Outcome) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.outcome = Some(outcome);
+
+        #[cfg(feature = "async")]
+        for waker in state.wakers.drain(..) {
+            waker.wake();
+        }
+
+        self.done.notify_all();
+    }
+
+    /// (Sync) Blocks until this flight is completed, and takes a copy of its outcome.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    fn wait(&self) -> Outcome {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(ref outcome) = state.outcome {
+                return unshare(outcome);
+            }
+            state = self.done.wait(state).unwrap();
+        }
+    }
+}
+
+/// (Async) A future which waits for a flight to be completed, and takes a copy of its outcome.
+#[cfg(feature = "async")]
+struct FlightWait(Arc<Flight>);
+
+#[cfg(feature = "async")]
+impl Future for FlightWait {
+    type Output = Outcome;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Outcome> {
+        let mut state = self.0.state.lock().unwrap();
+        if let Some(ref outcome) = state.outcome {
+            return Poll::Ready(unshare(outcome));
+        }
+
+        if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+/// Held by the caller performing a shared request. When dropped, it removes the request from
+/// the ones in-flight and completes it, with an error if no outcome was given (i.e. if the
+/// request panicked or, when async, was dropped), so that no waiting caller is left hanging.
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+struct Leader<'a> {
+    coalescer: &'a Coalescer,
+    key: String,
+    flight: Arc<Flight>,
+    outcome: Option<Outcome>,
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+impl<'a> Leader<'a> {
+    /// Completes the request with the given result, returning it back (the original, for the
+    /// caller which performed the request), while waiting callers receive copies of it.
+    fn finish(mut self, result: Result<Arc<[u8]>>) -> Result<Arc<[u8]>> {
+        self.outcome = Some(unshare(&result));
+        result
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+impl<'a> Drop for Leader<'a> {
+    fn drop(&mut self) {
+        // after this, no new caller can join this flight
+        self.coalescer.flights.lock().unwrap_or_else(PoisonError::into_inner).remove(&self.key);
+
+        let outcome = self.outcome.take().unwrap_or_else(|| Err(Error::Coalesced(String::from(
+            "The shared request was aborted before completing."
+        ))));
+        self.flight.complete(outcome);
+    }
+}
+
+/// Keeps track of the requests in-flight for each route (by URL), for both sync and async usage.
+#[derive(Default)]
+pub(crate) struct Coalescer {
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    flights: Mutex<HashMap<String, Arc<Flight>>>,
+}
+
+impl Debug for Coalescer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        let mut debug = f.debug_struct("Coalescer");
+        #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+        debug.field("in_flight", &self.flights.lock().unwrap().len());
+
+        debug.finish()
+    }
+}
+
+/// The result of joining a route's flight.
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+enum Joined<'a> {
+    /// There already was a request in-flight, which the caller can wait for.
+    Waiter(Arc<Flight>),
+
+    /// There was none, so the caller must perform the request.
+    Leader(Leader<'a>),
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+impl Coalescer {
+    /// Joins the flight of the given route (if any), or starts a new one, in which case the
+    /// caller is its leader and must perform the request.
+    fn join(&self, client: &Client, route: &Route) -> Joined<'_> {
+        let key = route.to_url_str();
+        let mut flights = self.flights.lock().unwrap();
+
+        let joined = match flights.get(&key) {
+            Some(flight) => Joined::Waiter(Arc::clone(flight)),
+            None => {
+                let flight = Arc::new(Flight::default());
+                flights.insert(key.clone(), Arc::clone(&flight));
+                Joined::Leader(Leader { coalescer: self, key, flight, outcome: None })
+            }
+        };
+
+        client.metrics.record_coalesce(matches!(joined, Joined::Waiter(_)));
+        joined
+    }
+
+    /// (Sync) Fetches the raw body of a route, or waits for an identical request in-flight (if
+    /// any) and takes a copy of its result.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    pub(crate) fn fetch(&self, client: &Client, route: &Route) -> Result<Arc<[u8]>> {
+        match self.join(client, route) {
+            Joined::Waiter(flight) => flight.wait(),
+            Joined::Leader(leader) => leader.finish(fetch_route_raw(client, route).map(Arc::from)),
+        }
+    }
+
+    /// (Async) Fetches the raw body of a route, or waits for an identical request in-flight (if
+    /// any) and takes a copy of its result.
+    #[cfg(feature = "async")]
+    pub(crate) async fn a_fetch(&self, client: &Client, route: &Route) -> Result<Arc<[u8]>> {
+        let leader = match self.join(client, route) {
+            Joined::Waiter(flight) => return FlightWait(flight).await,
+            Joined::Leader(leader) => leader,
+        };
+
+        leader.finish(a_fetch_route_raw(client, route).await.map(Arc::from))
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(
+    test, feature = "players", any(feature = "blocking", feature = "ureq", feature = "async")
+))]
+mod tests {
+    use crate::error::Error;
+    use crate::http::Client;
+    use crate::model::players::Player;
+
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    use std::sync::{Arc, Condvar, Mutex, atomic::{AtomicUsize, Ordering}};
+
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    use http::StatusCode;
+
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    use crate::http::{Middleware, RawResponse, request::Request};
+
+    /// The body of a successful response for a player.
+    fn player_body(tag: &str) -> String {
+        serde_json::to_string(&Player { tag: String::from(tag), ..Player::default() }).unwrap()
+    }
+
+    /// (Sync) Middleware which answers with the given status and body, but only once the test
+    /// opens it (after all callers joined the request in-flight). Panics instead, if asked to.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    struct Gate {
+        calls: AtomicUsize,
+        open: Mutex<bool>,
+        opened: Condvar,
+        status: StatusCode,
+        panic_first: bool,
+    }
+
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    impl Gate {
+        fn new(status: StatusCode, panic_first: bool) -> Arc<Gate> {
+            Arc::new(Gate {
+                calls: Default::default(),
+                open: Default::default(),
+                opened: Default::default(),
+                status,
+                panic_first,
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+
+        fn open(&self) {
+            *self.open.lock().unwrap() = true;
+            self.opened.notify_all();
+        }
+    }
+
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    impl Middleware for Arc<Gate> {
+        fn on_request(&self, _request: &mut Request<'_>) -> Option<RawResponse> {
+            let first = self.calls.fetch_add(1, Ordering::SeqCst) == 0;
+
+            let mut open = self.open.lock().unwrap();
+            while !*open {
+                open = self.opened.wait(open).unwrap();
+            }
+            drop(open);
+
+            if first && self.panic_first {
+                panic!("the middleware panicked");
+            }
+            Some(RawResponse::new(self.status, player_body("#AAA")))
+        }
+    }
+
+    /// (Sync) Fetches the same player from `callers` threads at once through a coalescing
+    /// client, opening the gate once all of them joined. Returns what each thread gave.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    fn sync_fetch_all(
+        client: &Client, gate: &Gate, callers: usize
+    ) -> Vec<::std::thread::Result<crate::error::Result<Player>>> {
+        use std::sync::Barrier;
+        use std::thread;
+        use std::time::{Duration, Instant};
+
+        let barrier = Barrier::new(callers);
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..callers)
+                .map(|_| scope.spawn(|| {
+                    barrier.wait();
+                    client.get::<Player>("#AAA")
+                }))
+                .collect();
+
+            // every caller but the first one joins the first one's request
+            let start = Instant::now();
+            while client.metrics().coalesce_hits < callers as u64 - 1 {
+                assert!(start.elapsed() < Duration::from_secs(10), "the callers didn't join");
+                thread::sleep(Duration::from_millis(1));
+            }
+            gate.open();
+
+            handles.into_iter().map(|handle| handle.join()).collect()
+        })
+    }
+
+    /// Tests that simultaneous identical requests are sent once, and share the same body.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn coalesce_shared() {
+        let gate = Gate::new(StatusCode::OK, false);
+        let client = Client::new("my auth key")
+            .with_coalescing(true)
+            .with_middleware(Arc::clone(&gate));
+
+        let results = sync_fetch_all(&client, &gate, 8);
+
+        assert_eq!(gate.calls(), 1);
+        assert_eq!(client.metrics().coalesce_misses, 1);
+        for result in results {
+            assert_eq!(result.unwrap().unwrap().tag, "#AAA");
+        }
+    }
+
+    /// Tests that every caller of a failed shared request gets the same (status) error.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn coalesce_error() {
+        let gate = Gate::new(StatusCode::NOT_FOUND, false);
+        let client = Client::new("my auth key")
+            .with_coalescing(true)
+            .with_middleware(Arc::clone(&gate));
+
+        let results = sync_fetch_all(&client, &gate, 8);
+
+        assert_eq!(gate.calls(), 1);
+        for result in results {
+            match result.unwrap() {
+                Err(Error::Status(StatusCode::NOT_FOUND, ..)) => {},
+                other => panic!("Expected a 404 status error, got {:?}", other),
+            }
+        }
+    }
+
+    /// Tests that, if the shared request panics, the callers waiting for it get an error instead
+    /// of hanging, and that the route can be requested again afterwards.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn coalesce_leader_panic() {
+        let gate = Gate::new(StatusCode::OK, true);
+        let client = Client::new("my auth key")
+            .with_coalescing(true)
+            .with_middleware(Arc::clone(&gate));
+
+        let results = sync_fetch_all(&client, &gate, 4);
+
+        assert_eq!(results.iter().filter(|result| result.is_err()).count(), 1);
+        for result in results.into_iter().flatten() {
+            assert!(matches!(result, Err(Error::Coalesced(_))), "{:?}", result);
+        }
+
+        assert_eq!(client.get::<Player>("#AAA").unwrap().tag, "#AAA");
+        assert_eq!(gate.calls(), 2);
+    }
+
+    /// (Async) Tests that simultaneous identical requests are sent once, and share the same body.
+    #[cfg(feature = "async")]
+    #[test]
+    fn coalesce_async_shared() {
+        use std::time::Duration;
+        use futures_util::future::join_all;
+        use crate::http::test_server::{TestServer, block_on};
+
+        let server = TestServer::start(Duration::from_millis(50), |_| (200, player_body("#AAA")));
+        let client = Client::new("my auth key")
+            .with_coalescing(true)
+            .with_middleware(server.clone());
+
+        let results = block_on(join_all((0..8).map(|_| client.a_get::<Player>("#AAA"))));
+
+        assert_eq!(server.total(), 1);
+        for result in results {
+            assert_eq!(result.unwrap().tag, "#AAA");
+        }
+    }
+
+    /// (Async) Tests that, if the future performing the shared request is dropped, the callers
+    /// waiting for it get an error instead of hanging, and the route can be requested again.
+    #[cfg(feature = "async")]
+    #[test]
+    fn coalesce_async_leader_dropped() {
+        use std::time::Duration;
+        use futures_util::poll;
+        use crate::http::test_server::{TestServer, block_on};
+
+        let server = TestServer::start(Duration::from_millis(50), |_| (200, player_body("#AAA")));
+        let client = Client::new("my auth key")
+            .with_coalescing(true)
+            .with_middleware(server.clone());
+
+        block_on(async {
+            let mut leader = Box::pin(client.a_get::<Player>("#AAA"));
+            let mut waiter = Box::pin(client.a_get::<Player>("#AAA"));
+            assert!(poll!(leader.as_mut()).is_pending());
+            assert!(poll!(waiter.as_mut()).is_pending());
+
+            drop(leader);
+            match waiter.await {
+                Err(Error::Coalesced(_)) => {},
+                other => panic!("Expected a coalesced error, got {:?}", other),
+            }
+
+            assert_eq!(client.a_get::<Player>("#AAA").await.unwrap().tag, "#AAA");
+        });
+    }
+}
@@ -0,0 +1,170 @@
+//! Contains the [`Fetched`] struct, which holds a fetched model alongside metadata about the
+//! response it came from (status, headers, fetch time and ratelimit state).
+//!
+//! [`Fetched`]: struct.Fetched.html
+
+use std::ops::{Deref, DerefMut};
+use std::time::{Duration, SystemTime};
+use http::StatusCode;
+use http::header::{HeaderMap, CACHE_CONTROL};
+
+/// Represents the ratelimit state indicated by the API's `x-ratelimit-*` headers in a response.
+/// Each field is `None` if the respective header was absent or invalid.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+pub struct RatelimitInfo {
+    /// Maximum amount of requests allowed per period (`x-ratelimit-limit`).
+    pub limit: Option<usize>,
+
+    /// Amount of requests remaining in the current period (`x-ratelimit-remaining`).
+    pub remaining: Option<usize>,
+
+    /// Stringified timestamp (seconds) at which the current period ends
+    /// (`x-ratelimit-reset`).
+    pub reset: Option<String>,
+}
+
+impl RatelimitInfo {
+    /// Reads the ratelimit state from a response's headers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::http::RatelimitInfo;
+    /// use http::header::{HeaderMap, HeaderValue};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-ratelimit-limit", HeaderValue::from_static("100"));
+    /// headers.insert("x-ratelimit-remaining", HeaderValue::from_static("42"));
+    ///
+    /// assert_eq!(
+    ///     RatelimitInfo::from_headers(&headers),
+    ///     RatelimitInfo { limit: Some(100), remaining: Some(42), reset: None },
+    /// );
+    /// ```
+    pub fn from_headers(headers: &HeaderMap) -> RatelimitInfo {
+        let header_str = |name: &str| headers.get(name).and_then(|val| val.to_str().ok());
+
+        RatelimitInfo {
+            limit: header_str("x-ratelimit-limit").and_then(|s| s.parse().ok()),
+            remaining: header_str("x-ratelimit-remaining").and_then(|s| s.parse().ok()),
+            reset: header_str("x-ratelimit-reset").map(String::from),
+        }
+    }
+}
+
+/// Represents a fetched model (at the [`value`] field) alongside metadata about the response it
+/// came from. Obtained through the `fetch_with_meta` functions of the fetching traits, or
+/// through [`Client::get_with_meta`].
+///
+/// This follows the Smart Pointer model, dereferencing to the fetched value.
+///
+/// [`value`]: #structfield.value
+/// [`Client::get_with_meta`]: ../client/struct.Client.html#method.get_with_meta
+#[derive(Debug, Clone)]
+pub struct Fetched<T> {
+    /// The fetched model.
+    pub value: T,
+
+    /// The status code of the response.
+    pub status: StatusCode,
+
+    /// The headers of the response.
+    pub headers: HeaderMap,
+
+    /// The time at which the response was received.
+    pub fetched_at: SystemTime,
+
+    /// The ratelimit state indicated by the response.
+    pub ratelimit: RatelimitInfo,
+}
+
+impl<T> Fetched<T> {
+    /// Returns the `max-age` directive of the response's `cache-control` header, if any. That is
+    /// the duration for which the data is considered fresh (counting from [`fetched_at`]).
+    ///
+    /// [`fetched_at`]: #structfield.fetched_at
+    pub fn max_age(&self) -> Option<Duration> {
+        let cache_control = self.headers.get(CACHE_CONTROL)?.to_str().ok()?;
+
+        cache_control.split(',')
+            .map(str::trim)
+            .find_map(|directive| directive.strip_prefix("max-age="))
+            .and_then(|secs| secs.parse().ok())
+            .map(Duration::from_secs)
+    }
+
+    /// Returns the time at which the data stops being fresh, according to [`max_age`], if any.
+    ///
+    /// [`max_age`]: #method.max_age
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.max_age().map(|max_age| self.fetched_at + max_age)
+    }
+
+    /// Discards the metadata, returning the fetched model.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Applies a function to the fetched model, keeping the metadata.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Fetched<U> {
+        Fetched {
+            value: f(self.value),
+            status: self.status,
+            headers: self.headers,
+            fetched_at: self.fetched_at,
+            ratelimit: self.ratelimit,
+        }
+    }
+}
+
+impl<T> Deref for Fetched<T> {
+    type Target = T;
+
+    /// Obtain the fetched model - dereferencing returns the [`value`] field.
+    ///
+    /// [`value`]: #structfield.value
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Fetched<T> {
+    /// Obtain the fetched model - dereferencing returns the [`value`] field.
+    ///
+    /// [`value`]: #structfield.value
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+    use http::StatusCode;
+    use http::header::{HeaderMap, HeaderValue, CACHE_CONTROL};
+    use super::{Fetched, RatelimitInfo};
+
+    /// Tests reading the `max-age` cache directive.
+    #[test]
+    fn fetched_max_age() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("public, max-age=120"));
+
+        let fetched = Fetched {
+            value: 5,
+            status: StatusCode::OK,
+            headers,
+            fetched_at: SystemTime::UNIX_EPOCH,
+            ratelimit: RatelimitInfo::default(),
+        };
+
+        assert_eq!(fetched.max_age(), Some(Duration::from_secs(120)));
+        assert_eq!(
+            fetched.expires_at(),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(120))
+        );
+        assert_eq!(*fetched.map(|val| val * 2), 10);
+    }
+}
@@ -0,0 +1,431 @@
+//! Contains the [`Health`] struct, returned by [`Client::health_check`], which indicates whether
+//! the API can currently be used with a client (i.e., whether its key works, whether the
+//! current IP address is allowed, and whether the API is in maintenance).
+//!
+//! [`Health`]: struct.Health.html
+//! [`Client::health_check`]: ../client/struct.Client.html#method.health_check
+
+use std::time::{Duration, SystemTime};
+
+use crate::error::Error;
+use super::Client;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use http::StatusCode;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::error::Result;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use super::keys::INVALID_IP_REASON;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use std::time::Instant;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use super::routes::Route;
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+use std::thread;
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+use crate::util::fetch_route_raw;
+
+#[cfg(feature = "async")]
+use crate::util::a_fetch_route_raw;
+
+/// The `reason` given by the API (with a 503 status) when it is in maintenance.
+pub const MAINTENANCE_REASON: &str = "inMaintenance";
+
+/// The delay before the first retry of [`Client::wait_until_available`] (doubled after each
+/// retry, up to [`MAX_HEALTH_BACKOFF`]).
+///
+/// [`Client::wait_until_available`]: ../client/struct.Client.html#method.wait_until_available
+/// [`MAX_HEALTH_BACKOFF`]: constant.MAX_HEALTH_BACKOFF.html
+pub const INITIAL_HEALTH_BACKOFF: Duration = Duration::from_secs(1);
+
+/// The maximum delay between retries of [`Client::wait_until_available`].
+///
+/// [`Client::wait_until_available`]: ../client/struct.Client.html#method.wait_until_available
+pub const MAX_HEALTH_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Represents the status of the API, as seen by a client, in a [`Health`] check.
+///
+/// [`Health`]: struct.Health.html
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum HealthStatus {
+    /// The API is available, and the client's key works.
+    Available,
+
+    /// The client's key was refused (e.g. it is invalid, or was revoked).
+    InvalidKey,
+
+    /// The client's key is not allowed to be used from the current IP address.
+    InvalidIp,
+
+    /// The API is in maintenance.
+    Maintenance,
+
+    /// The client's key is ratelimited.
+    Ratelimited,
+
+    /// The API could not be used for any other reason (e.g. connection errors, or an unexpected
+    /// status), as described by the contained error.
+    Unavailable(Error),
+}
+
+impl HealthStatus {
+    /// Determines the status of the API from the result of a request to it.
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    fn from_result<T>(result: Result<T>) -> HealthStatus {
+        let error = match result {
+            Ok(_) => return HealthStatus::Available,
+            Err(error) => error,
+        };
+
+        let reason = match error {
+            Error::Status(_, Some(ref api_error), _) => api_error.reason.as_str(),
+            _ => "",
+        };
+
+        match error {
+            Error::Ratelimited { .. } | Error::Status(StatusCode::TOO_MANY_REQUESTS, _, _) => {
+                HealthStatus::Ratelimited
+            },
+
+            Error::Status(StatusCode::FORBIDDEN, _, _) if reason == INVALID_IP_REASON => {
+                HealthStatus::InvalidIp
+            },
+
+            Error::Status(StatusCode::FORBIDDEN, _, _)
+                | Error::Status(StatusCode::UNAUTHORIZED, _, _) => HealthStatus::InvalidKey,
+
+            Error::Status(StatusCode::SERVICE_UNAVAILABLE, _, _)
+                if reason == MAINTENANCE_REASON => HealthStatus::Maintenance,
+
+            error => HealthStatus::Unavailable(error),
+        }
+    }
+}
+
+/// Represents the result of a health check (see [`Client::health_check`]).
+///
+/// [`Client::health_check`]: ../client/struct.Client.html#method.health_check
+#[derive(Debug)]
+pub struct Health {
+    /// The status of the API.
+    pub status: HealthStatus,
+
+    /// How long the check's request took.
+    pub latency: Duration,
+
+    /// The time at which the check was completed.
+    pub checked_at: SystemTime,
+}
+
+impl Health {
+    /// Returns whether or not the API is available (and the client's key works).
+    pub fn is_available(&self) -> bool {
+        matches!(self.status, HealthStatus::Available)
+    }
+
+    /// Returns whether or not the API is in maintenance.
+    pub fn is_in_maintenance(&self) -> bool {
+        matches!(self.status, HealthStatus::Maintenance)
+    }
+}
+
+/// Returns the delay before the next retry, given the current one.
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+fn next_backoff(delay: Duration) -> Duration {
+    (delay * 2).min(MAX_HEALTH_BACKOFF)
+}
+
+impl Client {
+    /// (Sync) Checks whether the API can currently be used with this client, through a single
+    /// (cheap) request to the `/brawlers/` endpoint. This never fails; any problem is indicated
+    /// by the returned [`Health`]'s status.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::Client;
+    /// use brawl_api::http::HealthStatus;
+    ///
+    /// let my_client = Client::new("my auth token");
+    /// let health = my_client.health_check();
+    ///
+    /// match health.status {
+    ///     HealthStatus::Available => println!("OK ({:?})", health.latency),
+    ///     HealthStatus::InvalidIp => println!("This key can't be used from here!"),
+    ///     HealthStatus::Maintenance => println!("The API is in maintenance."),
+    ///     other => println!("Unavailable: {:?}", other),
+    /// }
+    /// ```
+    ///
+    /// [`Health`]: ../health/struct.Health.html
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    pub fn health_check(&self) -> Health {
+        let start = Instant::now();
+        let result = fetch_route_raw(self, &Route::Brawlers);
+
+        Health {
+            latency: start.elapsed(),
+            checked_at: SystemTime::now(),
+            status: HealthStatus::from_result(result),
+        }
+    }
+
+    /// (Async) Checks whether the API can currently be used with this client, through a single
+    /// (cheap) request to the `/brawlers/` endpoint. This never fails; any problem is indicated
+    /// by the returned [`Health`]'s status.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::Client;
+    ///
+    /// # async fn main() {
+    /// let my_client = Client::new("my auth token");
+    /// let health = my_client.a_health_check().await;
+    ///
+    /// if !health.is_available() {
+    ///     println!("Can't use the API: {:?}", health.status);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`Health`]: ../health/struct.Health.html
+    #[cfg(feature = "async")]
+    pub async fn a_health_check(&self) -> Health {
+        let start = Instant::now();
+        let result = a_fetch_route_raw(self, &Route::Brawlers).await;
+
+        Health {
+            latency: start.elapsed(),
+            checked_at: SystemTime::now(),
+            status: HealthStatus::from_result(result),
+        }
+    }
+
+    /// (Sync) Performs [`health_check`]s while the API is in maintenance, waiting between them
+    /// with exponential backoff (from [`INITIAL_HEALTH_BACKOFF`] up to [`MAX_HEALTH_BACKOFF`]),
+    /// for up to `max_wait`. Returns the last check's result; that is, the API's status once
+    /// maintenance ended, or a [`HealthStatus::Maintenance`] if it did not end in time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use std::time::Duration;
+    /// use brawl_api::Client;
+    ///
+    /// let my_client = Client::new("my auth token");
+    /// let health = my_client.wait_until_available(Duration::from_secs(15 * 60));
+    ///
+    /// if health.is_available() {
+    ///     // start crawling...
+    /// }
+    /// ```
+    ///
+    /// [`health_check`]: #method.health_check
+    /// [`INITIAL_HEALTH_BACKOFF`]: ../health/constant.INITIAL_HEALTH_BACKOFF.html
+    /// [`MAX_HEALTH_BACKOFF`]: ../health/constant.MAX_HEALTH_BACKOFF.html
+    /// [`HealthStatus::Maintenance`]: ../health/enum.HealthStatus.html#variant.Maintenance
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    pub fn wait_until_available(&self, max_wait: Duration) -> Health {
+        let deadline = Instant::now() + max_wait;
+        let mut delay = INITIAL_HEALTH_BACKOFF;
+
+        loop {
+            let health = self.health_check();
+            let now = Instant::now();
+            if !health.is_in_maintenance() || now >= deadline {
+                return health;
+            }
+
+            thread::sleep(delay.min(deadline - now));
+            delay = next_backoff(delay);
+        }
+    }
+
+    /// (Async) Performs [`a_health_check`]s while the API is in maintenance, waiting between
+    /// them with exponential backoff (from [`INITIAL_HEALTH_BACKOFF`] up to
+    /// [`MAX_HEALTH_BACKOFF`]), for up to `max_wait`. Returns the last check's result; that is,
+    /// the API's status once maintenance ended, or a [`HealthStatus::Maintenance`] if it did not
+    /// end in time.
+    ///
+    /// The waiting does not depend on any specific async runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use std::time::Duration;
+    /// use brawl_api::Client;
+    ///
+    /// # async fn main() {
+    /// let my_client = Client::new("my auth token");
+    /// let health = my_client.a_wait_until_available(Duration::from_secs(15 * 60)).await;
+    ///
+    /// if health.is_available() {
+    ///     // start crawling...
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`a_health_check`]: #method.a_health_check
+    /// [`INITIAL_HEALTH_BACKOFF`]: ../health/constant.INITIAL_HEALTH_BACKOFF.html
+    /// [`MAX_HEALTH_BACKOFF`]: ../health/constant.MAX_HEALTH_BACKOFF.html
+    /// [`HealthStatus::Maintenance`]: ../health/enum.HealthStatus.html#variant.Maintenance
+    #[cfg(feature = "async")]
+    pub async fn a_wait_until_available(&self, max_wait: Duration) -> Health {
+        let deadline = Instant::now() + max_wait;
+        let mut delay = INITIAL_HEALTH_BACKOFF;
+
+        loop {
+            let health = self.a_health_check().await;
+            let now = Instant::now();
+            if !health.is_in_maintenance() || now >= deadline {
+                return health;
+            }
+
+            futures_timer::Delay::new(delay.min(deadline - now)).await;
+            delay = next_backoff(delay);
+        }
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(test, any(feature = "blocking", feature = "ureq", feature = "async")))]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use http::StatusCode;
+    use crate::http::{Client, Middleware, RawResponse, request::Request};
+
+    /// Answers every request with a fixed response, counting the requests.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    struct Fixed {
+        status: StatusCode,
+        body: &'static str,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    impl Middleware for Fixed {
+        fn on_request(&self, _request: &mut Request<'_>) -> Option<RawResponse> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Some(RawResponse::new(self.status, self.body))
+        }
+    }
+
+    /// Creates a client whose requests are answered with the given response.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    fn fixed_client(status: StatusCode, body: &'static str) -> (Client, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let client = Client::new("my auth key")
+            .with_middleware(Fixed { status, body, calls: Arc::clone(&calls) });
+        (client, calls)
+    }
+
+    /// Answers the first `remaining` requests with a maintenance error, and the following ones
+    /// successfully, counting the requests.
+    struct MaintenanceFor {
+        remaining: AtomicUsize,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Middleware for MaintenanceFor {
+        fn on_request(&self, _request: &mut Request<'_>) -> Option<RawResponse> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let in_maintenance = self.remaining
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| left.checked_sub(1))
+                .is_ok();
+
+            Some(if in_maintenance {
+                RawResponse::new(StatusCode::SERVICE_UNAVAILABLE, r#"{"reason": "inMaintenance"}"#)
+            } else {
+                RawResponse::new(StatusCode::OK, r#"{"items": []}"#)
+            })
+        }
+    }
+
+    /// Creates a client whose first `times` requests find the API in maintenance.
+    fn maintenance_client(times: usize) -> (Client, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let client = Client::new("my auth key").with_middleware(MaintenanceFor {
+            remaining: AtomicUsize::new(times),
+            calls: Arc::clone(&calls),
+        });
+        (client, calls)
+    }
+
+    /// Tests determining the API's status from its responses.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn health_check_status() {
+        use super::HealthStatus;
+
+        let (client, _) = fixed_client(StatusCode::OK, r#"{"items": []}"#);
+        assert!(client.health_check().is_available());
+
+        let (client, _) = fixed_client(
+            StatusCode::FORBIDDEN, r#"{"reason": "accessDenied.invalidIp"}"#
+        );
+        assert!(matches!(client.health_check().status, HealthStatus::InvalidIp));
+
+        let (client, _) = fixed_client(StatusCode::FORBIDDEN, r#"{"reason": "accessDenied"}"#);
+        assert!(matches!(client.health_check().status, HealthStatus::InvalidKey));
+
+        let (client, _) = fixed_client(StatusCode::INTERNAL_SERVER_ERROR, "{}");
+        assert!(matches!(client.health_check().status, HealthStatus::Unavailable(_)));
+    }
+
+    /// Tests that waiting gives up once the maximum wait is reached.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn health_wait_maintenance() {
+        let (client, calls) = fixed_client(
+            StatusCode::SERVICE_UNAVAILABLE, r#"{"reason": "inMaintenance"}"#
+        );
+
+        let health = client.wait_until_available(Duration::from_millis(0));
+        assert!(health.is_in_maintenance());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    /// Tests that waiting retries, with backoff, until maintenance ends.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn health_wait_until_available() {
+        use std::time::Instant;
+        use super::INITIAL_HEALTH_BACKOFF;
+
+        let (client, calls) = maintenance_client(2);
+
+        let start = Instant::now();
+        let health = client.wait_until_available(Duration::from_secs(30));
+        assert!(health.is_available());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(start.elapsed() >= INITIAL_HEALTH_BACKOFF * 3);  // slept 1x, then 2x
+    }
+
+    /// Tests that waiting asynchronously retries, with backoff, until maintenance ends.
+    #[cfg(feature = "async")]
+    #[test]
+    fn health_a_wait_until_available() {
+        use std::time::Instant;
+        use crate::http::test_server::block_on;
+        use super::INITIAL_HEALTH_BACKOFF;
+
+        let (client, calls) = maintenance_client(2);
+
+        let start = Instant::now();
+        let health = block_on(client.a_wait_until_available(Duration::from_secs(30)));
+        assert!(health.is_available());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(start.elapsed() >= INITIAL_HEALTH_BACKOFF * 3);
+    }
+}
@@ -0,0 +1,281 @@
+//! Contains the [`KeyInfo`] struct, which holds the claims of an API key (decoded offline, without
+//! requesting to the API), and helpers for checking IP addresses against its allowed ranges.
+//!
+//! [`KeyInfo`]: struct.KeyInfo.html
+
+use std::net::IpAddr;
+use std::str::FromStr;
+use ipnet::IpNet;
+use serde::{self, Serialize, Deserialize};
+
+use crate::error::{Result, Error};
+
+/// The `type` of a key limit which specifies the key's rate tier.
+const THROTTLING_LIMIT: &str = "throttling";
+
+/// The `type` of a key limit which specifies the IP ranges the key may be used from.
+const CLIENT_LIMIT: &str = "client";
+
+/// Represents one of the limits in the claims of an API key, as given in the key itself.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyLimit {
+    /// The kind of limit (`"throttling"` for rate tiers, `"client"` for allowed IP ranges).
+    #[serde(default)]
+    #[serde(rename = "type")]
+    pub limit_type: String,
+
+    /// The rate tier (e.g. `"developer/silver"`), for `"throttling"` limits.
+    #[serde(default)]
+    pub tier: Option<String>,
+
+    /// The allowed IP ranges (CIDRs, or single addresses), for `"client"` limits.
+    #[serde(default)]
+    pub cidrs: Vec<String>,
+}
+
+/// The raw claims in the payload of an API key.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct KeyClaims {
+    #[serde(default)]
+    iss: String,
+
+    #[serde(default)]
+    aud: String,
+
+    #[serde(default)]
+    jti: String,
+
+    #[serde(default)]
+    iat: Option<u64>,
+
+    #[serde(default)]
+    sub: String,
+
+    #[serde(default)]
+    scopes: Vec<String>,
+
+    #[serde(default)]
+    limits: Vec<KeyLimit>,
+}
+
+/// Represents the information contained in an API key, which is a JWT (JSON Web Token).
+/// Obtained through [`Client::key_info`] or [`KeyInfo::decode`] - both of which work offline.
+///
+/// **Note:** The key's signature is **not** verified; this is meant for inspecting keys (e.g.
+/// checking whether the current machine's IP address is allowed), not for validating them.
+///
+/// [`Client::key_info`]: ../client/struct.Client.html#method.key_info
+/// [`KeyInfo::decode`]: #method.decode
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyInfo {
+    /// The issuer of the key (normally `"supercell"`).
+    pub issuer: String,
+
+    /// The audience of the key (normally `"supercell:gameapi"`).
+    pub audience: String,
+
+    /// The unique ID of this key.
+    pub key_id: String,
+
+    /// The timestamp (seconds since the UNIX epoch) at which the key was issued, if given.
+    pub issued_at: Option<u64>,
+
+    /// The ID of the developer account which owns this key.
+    pub developer_id: String,
+
+    /// The scopes of the key (e.g. `"brawlstars"`).
+    pub scopes: Vec<String>,
+
+    /// The IP ranges (CIDRs, or single addresses) the key may be used from.
+    pub allowed_ips: Vec<String>,
+
+    /// The rate tiers of the key (e.g. `"developer/silver"`).
+    pub tiers: Vec<String>,
+
+    /// All of the key's limits, as given in the key.
+    pub limits: Vec<KeyLimit>,
+}
+
+impl KeyInfo {
+    /// Decodes the claims of an API key (with or without a `"Bearer "` prefix), without
+    /// requesting to the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::InvalidKey`] if the key is not a JWT, or if its payload could not be
+    /// decoded.
+    ///
+    /// [`Error::InvalidKey`]: ../../error/enum.Error.html#variant.InvalidKey
+    pub fn decode(key: &str) -> Result<KeyInfo> {
+        let key = key.trim();
+        let key = key.strip_prefix("Bearer ").unwrap_or(key);
+
+        let mut parts = key.split('.');
+        let payload = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(_), Some(payload), Some(_), None) => payload,
+            _ => return Err(Error::InvalidKey(
+                String::from("expected a JWT (three dot-separated parts).")
+            )),
+        };
+
+        let payload = base64::decode_config(payload, base64::URL_SAFE_NO_PAD)
+            .map_err(|err| Error::InvalidKey(format!("invalid payload encoding ({}).", err)))?;
+
+        let claims = serde_json::from_slice::<KeyClaims>(&payload)
+            .map_err(|err| Error::InvalidKey(format!("invalid payload ({}).", err)))?;
+
+        Ok(KeyInfo::from(claims))
+    }
+
+    /// Returns whether or not the key may be used from the given IP address, according to its
+    /// allowed IP ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::http::KeyInfo;
+    ///
+    /// # let info = KeyInfo {
+    /// #     issuer: String::from("supercell"),
+    /// #     audience: String::from("supercell:gameapi"),
+    /// #     key_id: String::new(),
+    /// #     issued_at: None,
+    /// #     developer_id: String::new(),
+    /// #     scopes: vec![String::from("brawlstars")],
+    /// #     allowed_ips: vec![String::from("203.0.113.7"), String::from("198.51.100.0/24")],
+    /// #     tiers: vec![],
+    /// #     limits: vec![],
+    /// # };
+    /// // after obtaining `info` through `Client::key_info`...
+    ///
+    /// assert!(info.allows_ip("203.0.113.7".parse().unwrap()));
+    /// assert!(info.allows_ip("198.51.100.42".parse().unwrap()));
+    /// assert!(!info.allows_ip("192.0.2.1".parse().unwrap()));
+    /// ```
+    pub fn allows_ip(&self, ip: IpAddr) -> bool {
+        self.allowed_ips.iter().any(|cidr| ip_in_cidr(ip, cidr))
+    }
+}
+
+impl From<KeyClaims> for KeyInfo {
+    fn from(claims: KeyClaims) -> KeyInfo {
+        let allowed_ips = claims.limits.iter()
+            .filter(|limit| limit.limit_type == CLIENT_LIMIT)
+            .flat_map(|limit| limit.cidrs.iter().cloned())
+            .collect();
+
+        let tiers = claims.limits.iter()
+            .filter(|limit| limit.limit_type == THROTTLING_LIMIT)
+            .filter_map(|limit| limit.tier.clone())
+            .collect();
+
+        KeyInfo {
+            issuer: claims.iss,
+            audience: claims.aud,
+            key_id: claims.jti,
+            issued_at: claims.iat,
+            developer_id: claims.sub.trim_start_matches("developer/").to_owned(),
+            scopes: claims.scopes,
+            allowed_ips,
+            tiers,
+            limits: claims.limits,
+        }
+    }
+}
+
+/// Returns whether or not the given IP address is in the given range, which may be either a CIDR
+/// (e.g. `"198.51.100.0/24"`) or a single address (e.g. `"203.0.113.7"`). Invalid ranges never
+/// contain any address.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::http::ip_in_cidr;
+///
+/// assert!(ip_in_cidr("10.1.2.3".parse().unwrap(), "10.0.0.0/8"));
+/// assert!(ip_in_cidr("2001:db8::1".parse().unwrap(), "2001:db8::/32"));
+/// assert!(!ip_in_cidr("10.1.2.3".parse().unwrap(), "10.1.2.4"));
+/// assert!(!ip_in_cidr("10.1.2.3".parse().unwrap(), "not a range"));
+/// ```
+pub fn ip_in_cidr(ip: IpAddr, cidr: &str) -> bool {
+    let cidr = cidr.trim();
+
+    match IpNet::from_str(cidr) {
+        Ok(net) => net.contains(&ip),
+        Err(_) => IpAddr::from_str(cidr).map(|addr| addr == ip).unwrap_or(false),
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::KeyInfo;
+    use crate::error::Error;
+
+    /// Tests decoding of an API key's claims.
+    #[test]
+    fn key_info_decode() -> Result<(), Box<dyn ::std::error::Error>> {
+        let payload = r#"{
+  "iss": "supercell",
+  "aud": "supercell:gameapi",
+  "jti": "01234567-89ab-cdef-0123-456789abcdef",
+  "iat": 1583000000,
+  "sub": "developer/fedcba98-7654-3210-fedc-ba9876543210",
+  "scopes": [
+    "brawlstars"
+  ],
+  "limits": [
+    {
+      "tier": "developer/silver",
+      "type": "throttling"
+    },
+    {
+      "cidrs": [
+        "203.0.113.7",
+        "198.51.100.0/24"
+      ],
+      "type": "client"
+    }
+  ]
+}"#;
+        let key = format!(
+            "eyJ0eXAiOiJKV1QiLCJhbGciOiJIUzUxMiJ9.{}.c2lnbmF0dXJl",
+            base64::encode_config(payload, base64::URL_SAFE_NO_PAD)
+        );
+
+        let info = KeyInfo::decode(&format!("Bearer {}", key))?;
+
+        assert_eq!(info.issuer, "supercell");
+        assert_eq!(info.audience, "supercell:gameapi");
+        assert_eq!(info.issued_at, Some(1583000000));
+        assert_eq!(info.developer_id, "fedcba98-7654-3210-fedc-ba9876543210");
+        assert_eq!(info.scopes, vec![String::from("brawlstars")]);
+        assert_eq!(info.allowed_ips, vec![
+            String::from("203.0.113.7"), String::from("198.51.100.0/24")
+        ]);
+        assert_eq!(info.tiers, vec![String::from("developer/silver")]);
+        assert_eq!(info.limits.len(), 2);
+
+        assert!(info.allows_ip("198.51.100.200".parse()?));
+        assert!(!info.allows_ip("198.51.101.1".parse()?));
+
+        Ok(())
+    }
+
+    /// Tests that keys which are not JWTs are refused.
+    #[test]
+    fn key_info_invalid() {
+        match KeyInfo::decode("my auth key") {
+            Err(Error::InvalidKey(_)) => {},
+            other => panic!("Expected an InvalidKey error, got {:?}", other),
+        }
+
+        match KeyInfo::decode("a.!!!.c") {
+            Err(Error::InvalidKey(_)) => {},
+            other => panic!("Expected an InvalidKey error, got {:?}", other),
+        }
+    }
+}
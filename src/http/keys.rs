@@ -0,0 +1,353 @@
+//! Contains the [`KeyPool`] struct, which allows a [`Client`] to spread its requests over several
+//! API keys.
+//!
+//! [`KeyPool`]: struct.KeyPool.html
+//! [`Client`]: ../client/struct.Client.html
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::{Result, Error};
+use super::secret::AuthKey;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use http::StatusCode;
+
+/// The `reason` given by the API when a key is used from an IP address it is not bound to.
+pub const INVALID_IP_REASON: &str = "accessDenied.invalidIp";
+
+/// The default duration for which a key is taken out of rotation (see [`KeyPool::cooldown`]).
+///
+/// [`KeyPool::cooldown`]: struct.KeyPool.html#method.cooldown
+pub const DEFAULT_KEY_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Represents the strategy used by a [`KeyPool`] to choose a key for each request.
+///
+/// [`KeyPool`]: struct.KeyPool.html
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum KeySelection {
+    /// Use each key in turn.
+    RoundRobin,
+
+    /// Use the key with the least requests made so far.
+    LeastUsed,
+}
+
+impl Default for KeySelection {
+    /// Returns the default key selection strategy, `KeySelection::RoundRobin`.
+    fn default() -> KeySelection {
+        KeySelection::RoundRobin
+    }
+}
+
+/// Represents the counters of a single key in a [`KeyPool`], as returned by [`KeyPool::stats`].
+///
+/// [`KeyPool`]: struct.KeyPool.html
+/// [`KeyPool::stats`]: struct.KeyPool.html#method.stats
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+pub struct KeyStats {
+    /// The position of the key in the pool.
+    pub index: usize,
+
+    /// The amount of requests made with this key.
+    pub requests: u64,
+
+    /// The amount of successful requests made with this key.
+    pub successes: u64,
+
+    /// The amount of failed requests made with this key (including the ones below).
+    pub failures: u64,
+
+    /// The amount of times this key was ratelimited.
+    pub ratelimited: u64,
+
+    /// The amount of times this key was refused for being used from an invalid IP address.
+    pub invalid_ip: u64,
+
+    /// For how much longer this key is out of rotation, if it is.
+    pub disabled_for: Option<Duration>,
+}
+
+/// The counters of a key, kept internally.
+#[derive(Debug, Default)]
+struct KeyState {
+    stats: KeyStats,
+    disabled_until: Option<Instant>,
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+impl KeyState {
+    /// Returns whether or not this key is currently in rotation.
+    fn is_enabled(&self, now: Instant) -> bool {
+        match self.disabled_until {
+            Some(until) => until <= now,
+            None => true,
+        }
+    }
+}
+
+/// Represents a pool of API keys, used by a [`Client`] (see [`Client::with_key_pool`]) in order
+/// to spread requests over several keys.
+///
+/// A key is chosen for each request according to the pool's [`KeySelection`]. When a key is
+/// refused for being used from an invalid IP address (`accessDenied.invalidIp`) or is
+/// ratelimited, it is taken out of rotation for some time (see [`cooldown`]). If all keys are out
+/// of rotation, the one which would be back the soonest is used.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::Client;
+/// use brawl_api::http::{KeyPool, KeySelection};
+///
+/// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+/// let pool = KeyPool::new(&["first key", "second key"])?
+///     .selection(KeySelection::LeastUsed);
+/// let my_client = Client::with_key_pool(pool);
+///
+/// let stats = my_client.key_pool().unwrap().stats();
+/// assert_eq!(stats.len(), 2);
+/// assert_eq!(stats[0].requests, 0);
+///
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Client`]: ../client/struct.Client.html
+/// [`Client::with_key_pool`]: ../client/struct.Client.html#method.with_key_pool
+/// [`KeySelection`]: enum.KeySelection.html
+/// [`cooldown`]: #method.cooldown
+#[derive(Debug)]
+pub struct KeyPool {
+    keys: Vec<AuthKey>,
+    states: Mutex<Vec<KeyState>>,
+    selection: KeySelection,
+    cooldown: Duration,
+
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    next: AtomicUsize,
+}
+
+impl KeyPool {
+    /// Creates a new pool with the given keys, using round-robin selection and a cooldown of
+    /// [`DEFAULT_KEY_COOLDOWN`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::InvalidParameter`] if `keys` is empty (e.g. if no keys were
+    /// configured).
+    ///
+    /// [`DEFAULT_KEY_COOLDOWN`]: constant.DEFAULT_KEY_COOLDOWN.html
+    /// [`Error::InvalidParameter`]: ../../error/enum.Error.html#variant.InvalidParameter
+    pub fn new<S: AsRef<str>>(keys: &[S]) -> Result<KeyPool> {
+        if keys.is_empty() {
+            return Err(Error::InvalidParameter {
+                name: String::from("keys"),
+                reason: String::from("a key pool must have at least one key"),
+            });
+        }
+
+        Ok(KeyPool {
+            keys: keys.iter().map(|key| AuthKey::new(key.as_ref())).collect(),
+            states: Mutex::new(
+                (0..keys.len())
+                    .map(|index| KeyState {
+                        stats: KeyStats { index, ..KeyStats::default() },
+                        disabled_until: None,
+                    })
+                    .collect()
+            ),
+            selection: KeySelection::default(),
+            cooldown: DEFAULT_KEY_COOLDOWN,
+
+            #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Sets the strategy used to choose a key for each request.
+    pub fn selection(mut self, selection: KeySelection) -> KeyPool {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets for how long a key is taken out of rotation after being ratelimited or refused for
+    /// an invalid IP address.
+    pub fn cooldown(mut self, cooldown: Duration) -> KeyPool {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Returns the amount of keys in this pool.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns whether or not this pool has no keys. (Always `false`, as pools can't be empty.)
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the first key of the pool.
+    pub(crate) fn first(&self) -> &AuthKey {
+        &self.keys[0]
+    }
+
+    /// Returns the counters of every key, in the order the keys were given.
+    pub fn stats(&self) -> Vec<KeyStats> {
+        let now = Instant::now();
+        let states = self.states.lock().unwrap();
+
+        states.iter()
+            .map(|state| KeyStats {
+                disabled_for: state.disabled_until
+                    .filter(|until| *until > now)
+                    .map(|until| until - now),
+                ..state.stats.clone()
+            })
+            .collect()
+    }
+
+    /// Chooses a key for a request, counting the request, and returns its index and the key.
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    pub(crate) fn pick(&self) -> (usize, &AuthKey) {
+        let now = Instant::now();
+        let mut states = self.states.lock().unwrap();
+        let amount = states.len();
+
+        let enabled = |index: &usize| states[*index].is_enabled(now);
+        let index = match self.selection {
+            KeySelection::RoundRobin => {
+                let start = self.next.load(Ordering::SeqCst);
+                let found = (0..amount).map(|offset| (start + offset) % amount).find(enabled);
+                self.next.store(found.unwrap_or(start) + 1, Ordering::SeqCst);
+                found
+            },
+
+            KeySelection::LeastUsed => {
+                (0..amount).filter(enabled).min_by_key(|index| states[*index].stats.requests)
+            },
+        };
+
+        // if every key is out of rotation, use the one that will be back the soonest
+        let index = index.unwrap_or_else(|| {
+            (0..amount).min_by_key(|index| states[*index].disabled_until).unwrap_or(0)
+        });
+
+        states[index].stats.requests += 1;
+        (index, &self.keys[index])
+    }
+
+    /// Records the outcome of a request made with the key at the given index, taking it out of
+    /// rotation if needed.
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    pub(crate) fn report(&self, index: usize, error: Option<&Error>) {
+        let mut states = self.states.lock().unwrap();
+        let state = &mut states[index];
+
+        let error = match error {
+            Some(error) => error,
+            None => {
+                state.stats.successes += 1;
+                return;
+            }
+        };
+
+        state.stats.failures += 1;
+
+        let disable = match *error {
+            Error::Ratelimited { .. } | Error::Status(StatusCode::TOO_MANY_REQUESTS, _, _) => {
+                state.stats.ratelimited += 1;
+                true
+            },
+
+            Error::Status(StatusCode::FORBIDDEN, Some(ref api_error), _)
+                if api_error.reason == INVALID_IP_REASON => {
+                state.stats.invalid_ip += 1;
+                true
+            },
+
+            _ => false,
+        };
+
+        if disable {
+            state.disabled_until = Some(Instant::now() + self.cooldown);
+        }
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(test, any(feature = "blocking", feature = "ureq", feature = "async")))]
+mod tests {
+    use http::StatusCode;
+    use super::{KeyPool, KeySelection, INVALID_IP_REASON};
+    use crate::error::{Error, APIError};
+
+    /// Asserts that the next key picked from the pool is the expected one.
+    fn assert_picked(pool: &KeyPool, index: usize, key: &str) {
+        let (picked_index, picked_key) = pool.pick();
+        assert_eq!((picked_index, picked_key.expose_secret()), (index, key));
+    }
+
+    /// Tests round-robin selection, skipping keys out of rotation.
+    #[test]
+    fn key_pool_round_robin() {
+        let pool = KeyPool::new(&["a", "b", "c"]).unwrap();
+
+        assert_picked(&pool, 0, "a");
+        assert_picked(&pool, 1, "b");
+        assert_picked(&pool, 2, "c");
+
+        let invalid_ip = Error::Status(
+            StatusCode::FORBIDDEN,
+            Some(APIError { reason: String::from(INVALID_IP_REASON), ..APIError::default() }),
+            None,
+        );
+        pool.report(0, Some(&invalid_ip));
+
+        assert_picked(&pool, 1, "b");
+        assert_picked(&pool, 2, "c");
+        assert_picked(&pool, 1, "b");
+
+        let stats = pool.stats();
+        assert_eq!(stats[0].invalid_ip, 1);
+        assert_eq!(stats[0].failures, 1);
+        assert!(stats[0].disabled_for.is_some());
+        assert_eq!(stats[1].requests, 3);
+    }
+
+    /// Tests least-used selection and the fallback when all keys are out of rotation.
+    #[test]
+    fn key_pool_least_used() {
+        let pool = KeyPool::new(&["a", "b"]).unwrap().selection(KeySelection::LeastUsed);
+        let ratelimited = Error::Ratelimited {
+            limit: None, remaining: Some(0), time_until_reset: None
+        };
+
+        assert_eq!(pool.pick().0, 0);
+        pool.report(0, None);
+        assert_eq!(pool.pick().0, 1);
+        pool.report(1, Some(&ratelimited));
+        assert_eq!(pool.pick().0, 0);
+        pool.report(0, Some(&ratelimited));
+
+        // both are out of rotation; "b" will be back first
+        assert_eq!(pool.pick().0, 1);
+
+        let stats = pool.stats();
+        assert_eq!(stats[0].successes, 1);
+        assert_eq!(stats[0].ratelimited, 1);
+        assert_eq!(stats[1].ratelimited, 1);
+    }
+
+    /// Tests that a pool can't be created without keys.
+    #[test]
+    fn key_pool_empty() {
+        let keys: [&str; 0] = [];
+        assert!(matches!(KeyPool::new(&keys), Err(Error::InvalidParameter { .. })));
+    }
+}
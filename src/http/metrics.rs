@@ -0,0 +1,719 @@
+//! Contains the metrics kept by each [`Client`] (requests, status classes, latency, bytes
+//! received, coalescing hits/misses and ratelimits - per kind of route), obtainable as a
+//! [`MetricsSnapshot`] through [`Client::metrics`].
+//!
+//! With the `prometheus` feature, snapshots can also be rendered in the Prometheus text format
+//! (see [`MetricsSnapshot::to_prometheus`]), and served over HTTP at `/metrics` (see
+//! [`Client::serve_metrics`]).
+//!
+//! [`Client`]: ../client/struct.Client.html
+//! [`MetricsSnapshot`]: struct.MetricsSnapshot.html
+//! [`Client::metrics`]: ../client/struct.Client.html#method.metrics
+//! [`MetricsSnapshot::to_prometheus`]: struct.MetricsSnapshot.html#method.to_prometheus
+//! [`Client::serve_metrics`]: ../client/struct.Client.html#method.serve_metrics
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use super::routes::RouteKind;
+
+#[cfg(any(
+    feature = "blocking", feature = "ureq", feature = "async", feature = "prometheus"
+))]
+use std::time::Duration;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use http::StatusCode;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::error::Error;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use super::middleware::RawResponse;
+
+#[cfg(feature = "prometheus")]
+use std::fmt::Write;
+
+#[cfg(feature = "prometheus")]
+use super::Client;
+
+#[cfg(feature = "prometheus")]
+use std::io::{self, Read, Write as IoWrite};
+
+#[cfg(feature = "prometheus")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+
+#[cfg(feature = "prometheus")]
+use std::sync::Arc;
+
+#[cfg(feature = "prometheus")]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "prometheus")]
+use std::thread::{self, JoinHandle};
+
+/// The upper bounds (in seconds) of the buckets of the latency histograms.
+pub const LATENCY_BUCKETS: &[f64] = &[0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// The upper bounds (in bytes) of the buckets of the response size histograms.
+pub const SIZE_BUCKETS: &[f64] = &[
+    1024.0, 4096.0, 16384.0, 65536.0, 262_144.0, 1_048_576.0
+];
+
+/// For how long the metrics server waits for a connection to send its request, or to receive
+/// the response, before giving up on it (so that a stalled client can't block the server).
+#[cfg(feature = "prometheus")]
+pub const METRICS_CONNECTION_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Represents a histogram: the amount of observed values within each bucket, alongside their
+/// sum and count.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Histogram {
+    /// The upper bounds (inclusive) of the buckets, in ascending order.
+    pub bounds: Vec<f64>,
+
+    /// The amount of observed values in each bucket (i.e. greater than the previous bound, and
+    /// less than or equal to the respective bound). Has one more item than [`bounds`], for the
+    /// values greater than every bound.
+    ///
+    /// [`bounds`]: #structfield.bounds
+    pub counts: Vec<u64>,
+
+    /// The sum of all observed values.
+    pub sum: f64,
+
+    /// The amount of observed values.
+    pub count: u64,
+}
+
+impl Histogram {
+    /// Creates an empty histogram with the given bucket bounds (which must be in ascending
+    /// order).
+    pub fn new(bounds: &[f64]) -> Histogram {
+        Histogram {
+            bounds: bounds.to_vec(),
+            counts: vec![0; bounds.len() + 1],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Records a value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::http::metrics::Histogram;
+    ///
+    /// let mut histogram = Histogram::new(&[1.0, 5.0]);
+    /// histogram.observe(0.5);
+    /// histogram.observe(3.0);
+    /// histogram.observe(9.0);
+    ///
+    /// assert_eq!(histogram.counts, vec![1, 1, 1]);
+    /// assert_eq!(histogram.sum, 12.5);
+    /// assert_eq!(histogram.count, 3);
+    /// ```
+    pub fn observe(&mut self, value: f64) {
+        let bucket = self.bounds.iter()
+            .position(|bound| value <= *bound)
+            .unwrap_or(self.bounds.len());
+
+        self.counts[bucket] += 1;
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+/// Represents the amount of requests which resulted in each class of status code.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+pub struct StatusClasses {
+    /// Responses with a `1xx` status.
+    pub informational: u64,
+
+    /// Responses with a `2xx` status.
+    pub success: u64,
+
+    /// Responses with a `3xx` status.
+    pub redirection: u64,
+
+    /// Responses with a `4xx` status.
+    pub client_error: u64,
+
+    /// Responses with a `5xx` status.
+    pub server_error: u64,
+
+    /// Requests which got no response at all (e.g. due to connection errors).
+    pub no_response: u64,
+}
+
+impl StatusClasses {
+    /// Counts a response with the given status (or a request without response, if `None`).
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    fn count(&mut self, status: Option<StatusCode>) {
+        let class = match status {
+            Some(status) if status.is_informational() => &mut self.informational,
+            Some(status) if status.is_success() => &mut self.success,
+            Some(status) if status.is_redirection() => &mut self.redirection,
+            Some(status) if status.is_client_error() => &mut self.client_error,
+            Some(_) => &mut self.server_error,
+            None => &mut self.no_response,
+        };
+
+        *class += 1;
+    }
+
+    /// Returns each class with its label (as in `"2xx"`).
+    pub fn labeled(&self) -> [(&'static str, u64); 6] {
+        [
+            ("1xx", self.informational),
+            ("2xx", self.success),
+            ("3xx", self.redirection),
+            ("4xx", self.client_error),
+            ("5xx", self.server_error),
+            ("none", self.no_response),
+        ]
+    }
+}
+
+/// Represents the metrics of a single kind of route.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteMetrics {
+    /// The amount of requests sent.
+    pub requests: u64,
+
+    /// The amount of requests that resulted in each class of status code.
+    pub statuses: StatusClasses,
+
+    /// The latency (in seconds) of the requests.
+    pub latency: Histogram,
+
+    /// The size (in bytes) of the response bodies.
+    pub response_size: Histogram,
+
+    /// The total amount of bytes received (in response bodies).
+    pub bytes_received: u64,
+
+    /// The amount of requests that were ratelimited.
+    pub ratelimited: u64,
+}
+
+impl Default for RouteMetrics {
+    fn default() -> RouteMetrics {
+        RouteMetrics {
+            requests: 0,
+            statuses: StatusClasses::default(),
+            latency: Histogram::new(LATENCY_BUCKETS),
+            response_size: Histogram::new(SIZE_BUCKETS),
+            bytes_received: 0,
+            ratelimited: 0,
+        }
+    }
+}
+
+/// Represents the metrics of a [`Client`] (and all of its clones) at some point in time.
+/// Obtained through [`Client::metrics`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use brawl_api::{Client, Player, traits::*};
+/// use brawl_api::http::RouteKind;
+///
+/// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+/// let my_client = Client::new("my auth token");
+/// let player = Player::fetch(&my_client, "#PLAYERTAGHERE")?;
+///
+/// let metrics = my_client.metrics();
+/// let player_metrics = &metrics.routes[&RouteKind::Player];
+/// assert_eq!(player_metrics.requests, 1);
+/// assert_eq!(player_metrics.statuses.success, 1);
+///
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Client`]: ../client/struct.Client.html
+/// [`Client::metrics`]: ../client/struct.Client.html#method.metrics
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MetricsSnapshot {
+    /// The metrics of each kind of route which was requested at least once.
+    pub routes: BTreeMap<RouteKind, RouteMetrics>,
+
+    /// The amount of requests that were served by an identical request in-flight, through
+    /// request coalescing (see [`Client::with_coalescing`]). Responses are never cached, so
+    /// this (along with [`coalesce_misses`]) stands in for cache hits.
+    ///
+    /// [`Client::with_coalescing`]: ../client/struct.Client.html#method.with_coalescing
+    /// [`coalesce_misses`]: #structfield.coalesce_misses
+    pub coalesce_hits: u64,
+
+    /// The amount of coalesced requests that had to be sent, as no identical request was
+    /// in-flight.
+    pub coalesce_misses: u64,
+}
+
+impl MetricsSnapshot {
+    /// Returns the total amount of requests sent, across all routes.
+    pub fn total_requests(&self) -> u64 {
+        self.routes.values().map(|route| route.requests).sum()
+    }
+
+    /// Returns the total amount of ratelimited requests, across all routes.
+    pub fn total_ratelimited(&self) -> u64 {
+        self.routes.values().map(|route| route.ratelimited).sum()
+    }
+}
+
+/// The metrics of a client, shared between its clones.
+#[derive(Debug, Default)]
+pub(crate) struct Metrics {
+    inner: Mutex<MetricsSnapshot>,
+}
+
+impl Metrics {
+    /// Records a request, along with its response (or `None` if there was no response), the
+    /// error it resulted in (if any) and its latency.
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    pub(crate) fn record(
+        &self, kind: RouteKind, response: Option<&RawResponse>, error: Option<&Error>,
+        latency: Duration,
+    ) {
+        let mut inner = self.inner.lock().unwrap();
+        let route = inner.routes.entry(kind).or_default();
+
+        route.requests += 1;
+        route.statuses.count(response.map(|response| response.status));
+        route.latency.observe(latency.as_secs_f64());
+
+        if let Some(response) = response {
+            route.response_size.observe(response.body.len() as f64);
+            route.bytes_received += response.body.len() as u64;
+        }
+
+        let ratelimited = match error {
+            Some(Error::Ratelimited { .. }) => true,
+            Some(Error::Status(status, _, _)) => *status == StatusCode::TOO_MANY_REQUESTS,
+            _ => false,
+        };
+        if ratelimited {
+            route.ratelimited += 1;
+        }
+    }
+
+    /// Records a request that was (`true`) or was not (`false`) served by an identical request
+    /// in-flight.
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    pub(crate) fn record_coalesce(&self, hit: bool) {
+        let mut inner = self.inner.lock().unwrap();
+        if hit {
+            inner.coalesce_hits += 1;
+        } else {
+            inner.coalesce_misses += 1;
+        }
+    }
+
+    /// Returns a copy of the current metrics.
+    pub(crate) fn snapshot(&self) -> MetricsSnapshot {
+        self.inner.lock().unwrap().clone()
+    }
+}
+
+#[cfg(feature = "prometheus")]
+impl MetricsSnapshot {
+    /// Renders these metrics in the Prometheus text exposition format (version 0.0.4). All
+    /// metric names are prefixed with `brawl_api_`, and per-route metrics have a `route` label
+    /// (see [`RouteKind::as_str`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::Client;
+    ///
+    /// let my_client = Client::new("my auth key");
+    /// let text = my_client.metrics().to_prometheus();
+    ///
+    /// assert!(text.contains("# TYPE brawl_api_coalesce_hits_total counter"));
+    /// ```
+    ///
+    /// [`RouteKind::as_str`]: ../routes/enum.RouteKind.html#method.as_str
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        write_header(
+            &mut out, "brawl_api_requests_total", "counter",
+            "Requests sent, by route and status class."
+        );
+        for (kind, route) in &self.routes {
+            for (class, count) in route.statuses.labeled().iter() {
+                if *count > 0 {
+                    let _ = writeln!(
+                        out, "brawl_api_requests_total{{route=\"{}\",status=\"{}\"}} {}",
+                        kind, class, count
+                    );
+                }
+            }
+        }
+
+        write_histogram(
+            &mut out, &self.routes, "brawl_api_request_duration_seconds",
+            "Latency of requests, in seconds.", |route| &route.latency
+        );
+        write_histogram(
+            &mut out, &self.routes, "brawl_api_response_size_bytes",
+            "Size of response bodies, in bytes.", |route| &route.response_size
+        );
+
+        write_header(
+            &mut out, "brawl_api_received_bytes_total", "counter",
+            "Bytes received in response bodies, by route."
+        );
+        for (kind, route) in &self.routes {
+            let _ = writeln!(
+                out, "brawl_api_received_bytes_total{{route=\"{}\"}} {}", kind, route.bytes_received
+            );
+        }
+
+        write_header(
+            &mut out, "brawl_api_ratelimited_total", "counter",
+            "Requests that were ratelimited, by route."
+        );
+        for (kind, route) in &self.routes {
+            let _ = writeln!(
+                out, "brawl_api_ratelimited_total{{route=\"{}\"}} {}", kind, route.ratelimited
+            );
+        }
+
+        write_header(
+            &mut out, "brawl_api_coalesce_hits_total", "counter",
+            "Requests served by an identical request in-flight (coalescing)."
+        );
+        let _ = writeln!(out, "brawl_api_coalesce_hits_total {}", self.coalesce_hits);
+
+        write_header(
+            &mut out, "brawl_api_coalesce_misses_total", "counter",
+            "Coalesced requests that had to be sent."
+        );
+        let _ = writeln!(out, "brawl_api_coalesce_misses_total {}", self.coalesce_misses);
+
+        out
+    }
+}
+
+/// Writes the `HELP` and `TYPE` lines of a metric.
+#[cfg(feature = "prometheus")]
+fn write_header(out: &mut String, name: &str, metric_type: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, metric_type);
+}
+
+/// Writes a histogram metric, with one series per route.
+#[cfg(feature = "prometheus")]
+fn write_histogram<F>(
+    out: &mut String, routes: &BTreeMap<RouteKind, RouteMetrics>, name: &str, help: &str,
+    histogram_of: F,
+) where F: Fn(&RouteMetrics) -> &Histogram {
+    write_header(out, name, "histogram", help);
+
+    for (kind, route) in routes {
+        let histogram = histogram_of(route);
+        let mut cumulative = 0;
+
+        for (bound, count) in histogram.bounds.iter().zip(&histogram.counts) {
+            cumulative += count;
+            let _ = writeln!(
+                out, "{}_bucket{{route=\"{}\",le=\"{}\"}} {}", name, kind, bound, cumulative
+            );
+        }
+        let _ = writeln!(
+            out, "{}_bucket{{route=\"{}\",le=\"+Inf\"}} {}", name, kind, histogram.count
+        );
+        let _ = writeln!(out, "{}_sum{{route=\"{}\"}} {}", name, kind, histogram.sum);
+        let _ = writeln!(out, "{}_count{{route=\"{}\"}} {}", name, kind, histogram.count);
+    }
+}
+
+/// A tiny HTTP server, serving a client's metrics (in the Prometheus text format) at `/metrics`.
+/// Started through [`Client::serve_metrics`]; it runs in a background thread until
+/// [`shutdown`] is called or it is dropped.
+///
+/// [`Client::serve_metrics`]: ../client/struct.Client.html#method.serve_metrics
+/// [`shutdown`]: #method.shutdown
+#[cfg(feature = "prometheus")]
+#[derive(Debug)]
+pub struct MetricsServer {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[cfg(feature = "prometheus")]
+impl MetricsServer {
+    /// Returns the address the server is listening on (useful when binding to port `0`).
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Stops the server, waiting for its thread to finish.
+    pub fn shutdown(mut self) {
+        self.stop_thread();
+    }
+
+    /// Stops the server's thread, if still running.
+    fn stop_thread(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.stop.store(true, Ordering::SeqCst);
+
+            // wake up the listener (through loopback, as connecting to e.g. `0.0.0.0` doesn't
+            // work on every platform)
+            let mut wake_addr = self.addr;
+            if wake_addr.ip().is_unspecified() {
+                wake_addr.set_ip(match wake_addr.ip() {
+                    IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+                    IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+                });
+            }
+            let _ = TcpStream::connect_timeout(&wake_addr, METRICS_CONNECTION_TIMEOUT);
+
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(feature = "prometheus")]
+impl Drop for MetricsServer {
+    fn drop(&mut self) {
+        self.stop_thread();
+    }
+}
+
+/// Answers a single HTTP request to the metrics server (giving up on it after
+/// [`METRICS_CONNECTION_TIMEOUT`] without progress).
+///
+/// [`METRICS_CONNECTION_TIMEOUT`]: constant.METRICS_CONNECTION_TIMEOUT.html
+#[cfg(feature = "prometheus")]
+fn serve_connection(client: &Client, mut stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(METRICS_CONNECTION_TIMEOUT))?;
+    stream.set_write_timeout(Some(METRICS_CONNECTION_TIMEOUT))?;
+
+    // the request line may arrive over several reads; stop once it's complete (or the buffer
+    // is full, or the peer is done sending)
+    let mut buf = [0u8; 1024];
+    let mut read = 0;
+    while read < buf.len() && !buf[..read].windows(2).any(|bytes| bytes == b"\r\n") {
+        match stream.read(&mut buf[read..])? {
+            0 => break,
+            amount => read += amount,
+        }
+    }
+    let request_line = String::from_utf8_lossy(&buf[..read]);
+    let mut parts = request_line.split_whitespace();
+
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some(path)) if path == "/metrics" || path.starts_with("/metrics?") => {
+            ("200 OK", client.metrics().to_prometheus())
+        },
+        _ => ("404 Not Found", String::from("Not Found\n")),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n\
+        Connection: close\r\n\r\n{}",
+        status, body.len(), body
+    )?;
+    stream.flush()
+}
+
+#[cfg(feature = "prometheus")]
+impl Client {
+    /// Starts a tiny HTTP server, in a background thread, which serves this client's metrics
+    /// (see [`metrics`]) in the Prometheus text format at `/metrics`. The server keeps running
+    /// until the returned [`MetricsServer`] is shut down or dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `std::io::Error` if the address could not be bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use brawl_api::Client;
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth key");
+    /// let server = my_client.serve_metrics("127.0.0.1:9184")?;
+    ///
+    /// // ... use the client; metrics are available at http://127.0.0.1:9184/metrics
+    ///
+    /// server.shutdown();
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`metrics`]: #method.metrics
+    /// [`MetricsServer`]: ../metrics/struct.MetricsServer.html
+    pub fn serve_metrics<A: ToSocketAddrs>(&self, addr: A) -> io::Result<MetricsServer> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let client = self.clone();
+        let thread_stop = Arc::clone(&stop);
+        let handle = thread::Builder::new()
+            .name(String::from("brawl-api-metrics"))
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    if thread_stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        let _ = serve_connection(&client, stream);
+                    }
+                }
+            })?;
+
+        Ok(MetricsServer { addr, stop, handle: Some(handle) })
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(test, any(feature = "blocking", feature = "ureq", feature = "async")))]
+mod tests {
+    use std::time::Duration;
+    use http::StatusCode;
+    use super::Metrics;
+    use crate::error::Error;
+    use crate::http::middleware::RawResponse;
+    use crate::http::routes::RouteKind;
+
+    /// Tests recording requests into the metrics.
+    #[test]
+    fn metrics_record() {
+        let metrics = Metrics::default();
+        let ok = RawResponse::new(StatusCode::OK, vec![0; 2000]);
+        let limited = RawResponse::new(StatusCode::TOO_MANY_REQUESTS, "{}");
+        let limited_err = Error::Status(StatusCode::TOO_MANY_REQUESTS, None, None);
+
+        metrics.record(RouteKind::Player, Some(&ok), None, Duration::from_millis(30));
+        metrics.record(
+            RouteKind::Player, Some(&limited), Some(&limited_err), Duration::from_millis(300)
+        );
+        metrics.record(RouteKind::Club, None, Some(&Error::Cancelled), Duration::from_secs(20));
+        metrics.record_coalesce(true);
+
+        let snapshot = metrics.snapshot();
+        let player = &snapshot.routes[&RouteKind::Player];
+        assert_eq!(player.requests, 2);
+        assert_eq!(player.statuses.success, 1);
+        assert_eq!(player.statuses.client_error, 1);
+        assert_eq!(player.ratelimited, 1);
+        assert_eq!(player.bytes_received, 2002);
+        assert_eq!(player.latency.counts, vec![1, 0, 0, 1, 0, 0, 0, 0, 0]);
+        assert_eq!(player.response_size.counts[0], 1);
+        assert_eq!(player.response_size.counts[1], 1);
+
+        let club = &snapshot.routes[&RouteKind::Club];
+        assert_eq!(club.statuses.no_response, 1);
+        assert_eq!(club.latency.counts[8], 1);
+
+        assert_eq!(snapshot.total_requests(), 3);
+        assert_eq!(snapshot.coalesce_hits, 1);
+        assert_eq!(snapshot.coalesce_misses, 0);
+    }
+
+    /// Tests rendering metrics in the Prometheus text format, and serving them.
+    #[cfg(feature = "prometheus")]
+    #[test]
+    fn metrics_prometheus() -> Result<(), Box<dyn ::std::error::Error>> {
+        use std::io::{Read, Write};
+        use std::net::TcpStream;
+        use crate::http::Client;
+
+        let client = Client::new("my auth key");
+        let ok = RawResponse::new(StatusCode::OK, "{}");
+        client.metrics.record(RouteKind::Brawlers, Some(&ok), None, Duration::from_millis(70));
+
+        let text = client.metrics().to_prometheus();
+        assert!(text.contains("brawl_api_requests_total{route=\"brawlers\",status=\"2xx\"} 1\n"));
+        assert!(text.contains(
+            "brawl_api_request_duration_seconds_bucket{route=\"brawlers\",le=\"0.05\"} 0\n"
+        ));
+        assert!(text.contains(
+            "brawl_api_request_duration_seconds_bucket{route=\"brawlers\",le=\"0.1\"} 1\n"
+        ));
+        assert!(text.contains("brawl_api_received_bytes_total{route=\"brawlers\"} 2\n"));
+
+        let server = client.serve_metrics("127.0.0.1:0")?;
+        let mut stream = TcpStream::connect(server.local_addr())?;
+        stream.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        server.shutdown();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with(&text));
+
+        Ok(())
+    }
+
+    /// Tests that a request line split across several writes is still answered.
+    #[cfg(feature = "prometheus")]
+    #[test]
+    fn metrics_server_split_request() -> Result<(), Box<dyn ::std::error::Error>> {
+        use std::io::{Read, Write};
+        use std::net::TcpStream;
+        use std::thread;
+        use crate::http::Client;
+
+        let client = Client::new("my auth key");
+        let server = client.serve_metrics("127.0.0.1:0")?;
+
+        let mut stream = TcpStream::connect(server.local_addr())?;
+        stream.set_nodelay(true)?;
+        for part in &["GE", "T /met", "rics HTTP/1.1\r", "\nHost: localhost\r\n\r\n"] {
+            stream.write_all(part.as_bytes())?;
+            stream.flush()?;
+            thread::sleep(Duration::from_millis(20));
+        }
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        server.shutdown();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "got {:?}", response);
+
+        Ok(())
+    }
+
+    /// Tests that a client which connects and sends nothing only stalls the metrics server until
+    /// it times out, and that the server (bound to an unspecified address) still shuts down.
+    #[cfg(feature = "prometheus")]
+    #[test]
+    fn metrics_server_idle_connection() -> Result<(), Box<dyn ::std::error::Error>> {
+        use std::io::{Read, Write};
+        use std::net::TcpStream;
+        use std::time::Instant;
+        use crate::http::Client;
+        use super::METRICS_CONNECTION_TIMEOUT;
+
+        let client = Client::new("my auth key");
+        let server = client.serve_metrics("0.0.0.0:0")?;
+        let addr = ("127.0.0.1", server.local_addr().port());
+
+        let _idle = TcpStream::connect(addr)?;
+        let mut stream = TcpStream::connect(addr)?;
+        stream.set_read_timeout(Some(METRICS_CONNECTION_TIMEOUT * 3))?;
+        stream.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+
+        let _idle = TcpStream::connect(addr)?;
+        let start = Instant::now();
+        server.shutdown();
+        assert!(start.elapsed() < METRICS_CONNECTION_TIMEOUT * 3);
+
+        Ok(())
+    }
+}
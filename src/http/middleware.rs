@@ -0,0 +1,248 @@
+//! Contains the [`Middleware`] trait, used for attaching behavior (such as logging, header
+//! injection or fault injection) to every request sent by a [`Client`], and the [`RawResponse`]
+//! struct seen by middleware.
+//!
+//! [`Middleware`]: trait.Middleware.html
+//! [`Client`]: ../client/struct.Client.html
+//! [`RawResponse`]: struct.RawResponse.html
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use http::StatusCode;
+use http::header::HeaderMap;
+
+use crate::http::request::Request;
+
+/// Represents a complete (already received) response: its status, headers and body. Given to
+/// middleware, which may change it, and returned by middleware which short-circuits a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawResponse {
+    /// The status code of the response.
+    pub status: StatusCode,
+
+    /// The headers of the response.
+    pub headers: HeaderMap,
+
+    /// The (raw) body of the response - normally JSON.
+    pub body: Vec<u8>,
+}
+
+impl RawResponse {
+    /// Creates a new response with the given status and body, and no headers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::http::RawResponse;
+    /// use http::StatusCode;
+    ///
+    /// let response = RawResponse::new(StatusCode::OK, r#"{"items": []}"#);
+    ///
+    /// assert!(response.headers.is_empty());
+    /// assert_eq!(response.body, br#"{"items": []}"#.to_vec());
+    /// ```
+    pub fn new<B: Into<Vec<u8>>>(status: StatusCode, body: B) -> RawResponse {
+        RawResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+}
+
+/// Represents a middleware, which runs on every request sent by a [`Client`] (see
+/// [`Client::with_middleware`]) - be it sync or async, as the hooks themselves are not async.
+///
+/// For each request, the [`on_request`] hooks of the client's middleware run in the order the
+/// middleware was added; each of them may change the request (e.g. add headers), or
+/// short-circuit it by returning a response, in which case nothing is sent to the API and no
+/// further `on_request` hooks run. Then, the [`on_response`] hooks of the middleware that ran run
+/// in reverse order, each of them being able to change the response (status, headers or body)
+/// before it is turned into a model (or an error, if its status is not successful).
+///
+/// For requests made by fetching models, the request's `endpoint` is the full URL of the
+/// respective [`Route`].
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::Client;
+/// use brawl_api::http::{Middleware, RawResponse, request::Request};
+/// use http::StatusCode;
+///
+/// /// Refuses every request to the `/brawlers` endpoints, without requesting to the API.
+/// #[derive(Debug)]
+/// struct NoBrawlers;
+///
+/// impl Middleware for NoBrawlers {
+///     fn on_request(&self, request: &mut Request<'_>) -> Option<RawResponse> {
+///         if request.endpoint.contains("/brawlers") {
+///             Some(RawResponse::new(StatusCode::FORBIDDEN, "{}"))
+///         } else {
+///             None
+///         }
+///     }
+/// }
+///
+/// let my_client = Client::new("my auth key").with_middleware(NoBrawlers);
+/// ```
+///
+/// [`Client`]: ../client/struct.Client.html
+/// [`Client::with_middleware`]: ../client/struct.Client.html#method.with_middleware
+/// [`on_request`]: #method.on_request
+/// [`on_response`]: #method.on_response
+/// [`Route`]: ../routes/enum.Route.html
+pub trait Middleware: Send + Sync {
+    /// Runs before a request is sent. Returning `Some(response)` short-circuits the request:
+    /// it is not sent, and `response` is used as if it had been received.
+    ///
+    /// By default, does nothing.
+    fn on_request(&self, _request: &mut Request<'_>) -> Option<RawResponse> {
+        None
+    }
+
+    /// Runs after a response was received (or given by a middleware which short-circuited the
+    /// request), before it is processed. `request` is the request as sent.
+    ///
+    /// By default, does nothing.
+    fn on_response(&self, _request: &Request<'_>, _response: &mut RawResponse) {}
+}
+
+/// The middleware of a client, in the order it was added.
+#[derive(Clone, Default)]
+pub(crate) struct MiddlewareChain {
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl Debug for MiddlewareChain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        f.debug_struct("MiddlewareChain")
+            .field("len", &self.middleware.len())
+            .finish()
+    }
+}
+
+impl MiddlewareChain {
+    /// Adds a middleware to the end of the chain.
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middleware.push(middleware);
+    }
+
+    /// Runs the `on_request` hooks, in order, until one short-circuits. Returns the amount of
+    /// hooks that ran, and the short-circuiting response (if any).
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    pub(crate) fn run_request(&self, request: &mut Request<'_>) -> (usize, Option<RawResponse>) {
+        for (index, middleware) in self.middleware.iter().enumerate() {
+            if let Some(response) = middleware.on_request(request) {
+                return (index + 1, Some(response));
+            }
+        }
+
+        (self.middleware.len(), None)
+    }
+
+    /// Runs the `on_response` hooks of the first `ran` middleware, in reverse order.
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    pub(crate) fn run_response(
+        &self, ran: usize, request: &Request<'_>, response: &mut RawResponse
+    ) {
+        for middleware in self.middleware[..ran].iter().rev() {
+            middleware.on_response(request, response);
+        }
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(test, any(feature = "blocking", feature = "ureq", feature = "async")))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use http::StatusCode;
+    use super::{Middleware, MiddlewareChain, RawResponse};
+    use crate::http::request::Request;
+
+    /// Records the order in which its hooks run.
+    struct Recorder {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+        short_circuit: bool,
+    }
+
+    impl Middleware for Recorder {
+        fn on_request(&self, request: &mut Request<'_>) -> Option<RawResponse> {
+            self.log.lock().unwrap().push(format!("request {}", self.name));
+            request.endpoint.push_str(self.name);
+
+            if self.short_circuit {
+                Some(RawResponse::new(StatusCode::OK, "{}"))
+            } else {
+                None
+            }
+        }
+
+        fn on_response(&self, _request: &Request<'_>, response: &mut RawResponse) {
+            self.log.lock().unwrap().push(format!("response {}", self.name));
+            response.body.extend_from_slice(self.name.as_bytes());
+        }
+    }
+
+    /// Tests the order of the hooks, and short-circuiting.
+    #[test]
+    fn middleware_chain_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut chain = MiddlewareChain::default();
+        for &(name, short_circuit) in &[("a", false), ("b", true), ("c", false)] {
+            chain.push(Arc::new(Recorder { name, log: Arc::clone(&log), short_circuit }));
+        }
+
+        let mut request = Request { endpoint: String::from("/"), ..Request::default() };
+        let (ran, response) = chain.run_request(&mut request);
+        assert_eq!(ran, 2);
+        assert_eq!(request.endpoint, "/ab");
+
+        let mut response = response.unwrap();
+        chain.run_response(ran, &request, &mut response);
+        assert_eq!(response.body, b"{}ba".to_vec());
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["request a", "request b", "response b", "response a"]
+        );
+    }
+
+    /// Tests that a short-circuiting middleware replaces the API when fetching models.
+    #[cfg(all(feature = "brawlers", any(feature = "blocking", feature = "ureq")))]
+    #[test]
+    fn middleware_client_fetch() {
+        use crate::error::Error;
+        use crate::http::Client;
+        use crate::model::brawlers::BrawlerList;
+
+        struct Fake;
+
+        impl Middleware for Fake {
+            fn on_request(&self, request: &mut Request<'_>) -> Option<RawResponse> {
+                assert!(request.endpoint.ends_with("/brawlers/"));
+                Some(RawResponse::new(StatusCode::OK, r#"{"items": []}"#))
+            }
+        }
+
+        struct NotFound;
+
+        impl Middleware for NotFound {
+            fn on_response(&self, _request: &Request<'_>, response: &mut RawResponse) {
+                response.status = StatusCode::NOT_FOUND;
+            }
+        }
+
+        let client = Client::new("my auth key").with_middleware(Fake);
+        let brawlers = client.get::<BrawlerList>(&()).unwrap();
+        assert!(brawlers.items.is_empty());
+
+        let client = Client::new("my auth key").with_middleware(NotFound).with_middleware(Fake);
+        match client.get::<BrawlerList>(&()) {
+            Err(Error::Status(StatusCode::NOT_FOUND, _, _)) => {},
+            other => panic!("Expected a 404 Status error, got {:?}", other),
+        }
+    }
+}
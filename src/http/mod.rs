@@ -5,5 +5,46 @@ pub mod request;
 pub mod client;
 pub use client::Client;
 
+pub mod secret;
+pub use secret::AuthKey;
+
+pub mod middleware;
+pub use middleware::{Middleware, RawResponse};
+
+pub mod query;
+pub use query::Query;
+
 pub mod routes;
-pub use routes::Route;
+pub use routes::{Route, RouteKind};
+
+pub mod fetched;
+pub use fetched::{Fetched, RatelimitInfo};
+
+pub mod keys;
+pub use keys::{KeyPool, KeySelection, KeyStats};
+
+pub(crate) mod coalesce;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+pub(crate) mod trace;
+
+#[cfg(all(test, feature = "async"))]
+pub(crate) mod test_server;
+
+pub mod metrics;
+pub use metrics::MetricsSnapshot;
+
+#[cfg(feature = "prometheus")]
+pub use metrics::MetricsServer;
+
+pub mod health;
+pub use health::{Health, HealthStatus};
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+pub mod batch;
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+pub use batch::{BatchFetcher, CancelToken};
+
+pub mod key_info;
+pub use key_info::{KeyInfo, KeyLimit, ip_in_cidr};
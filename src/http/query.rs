@@ -0,0 +1,203 @@
+//! Contains the [`Query`] struct, a typed builder for the query parameters of requests to the API
+//! (`limit`, `before`, `after`...).
+//!
+//! [`Query`]: struct.Query.html
+
+use url::Url;
+
+use crate::error::{Result, Error};
+
+/// The maximum value of the `limit` query parameter accepted by the API.
+pub const MAX_LIMIT: u32 = 200;
+
+/// Represents the query parameters of a request to the API, validated (see [`validate`]) before
+/// being sent.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::http::{Query, Route};
+///
+/// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+/// let route = Route::ClubMembers(String::from("#CLUBTAG"));
+/// let url = route.to_url_with(&Query::new().limit(50).after("someMarker"))?;
+///
+/// assert_eq!(
+///     url.as_str(),
+///     "https://api.brawlstars.com/v1/clubs/%23CLUBTAG/members?limit=50&after=someMarker"
+/// );
+///
+/// assert!(route.to_url_with(&Query::new().limit(201)).is_err());
+///
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`validate`]: #method.validate
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+pub struct Query {
+    /// The maximum amount of items to return (between 1 and [`MAX_LIMIT`]).
+    ///
+    /// [`MAX_LIMIT`]: constant.MAX_LIMIT.html
+    pub limit: Option<u32>,
+
+    /// Return only items that occur before this marker (for paging).
+    pub before: Option<String>,
+
+    /// Return only items that occur after this marker (for paging).
+    pub after: Option<String>,
+
+    /// Any other parameters, in order, as (name, value) pairs.
+    pub extra: Vec<(String, String)>,
+}
+
+impl Query {
+    /// Creates a new, empty query.
+    pub fn new() -> Query {
+        Query::default()
+    }
+
+    /// Sets the `limit` parameter.
+    pub fn limit(mut self, limit: u32) -> Query {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `before` parameter.
+    pub fn before<S: Into<String>>(mut self, marker: S) -> Query {
+        self.before = Some(marker.into());
+        self
+    }
+
+    /// Sets the `after` parameter.
+    pub fn after<S: Into<String>>(mut self, marker: S) -> Query {
+        self.after = Some(marker.into());
+        self
+    }
+
+    /// Adds any other parameter (e.g. one added to the API after this library's release).
+    pub fn param<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Query {
+        self.extra.push((name.into(), value.into()));
+        self
+    }
+
+    /// Returns whether or not this query has no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.limit.is_none() && self.before.is_none() && self.after.is_none()
+            && self.extra.is_empty()
+    }
+
+    /// Returns a query with the parameters of this one, overridden by the ones set in `other`.
+    pub fn merged(&self, other: &Query) -> Query {
+        Query {
+            limit: other.limit.or(self.limit),
+            before: other.before.clone().or_else(|| self.before.clone()),
+            after: other.after.clone().or_else(|| self.after.clone()),
+            extra: self.extra.iter().chain(other.extra.iter()).cloned().collect(),
+        }
+    }
+
+    /// Checks whether the parameters are within the limits documented by the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::InvalidParameter`] if:
+    ///
+    /// - `limit` is not between 1 and [`MAX_LIMIT`];
+    /// - `before` or `after` is empty, or both are set;
+    /// - any extra parameter has an empty name.
+    ///
+    /// [`Error::InvalidParameter`]: ../../error/enum.Error.html#variant.InvalidParameter
+    /// [`MAX_LIMIT`]: constant.MAX_LIMIT.html
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |name: &str, reason: String| Err(Error::InvalidParameter {
+            name: String::from(name), reason
+        });
+
+        if let Some(limit) = self.limit {
+            if limit == 0 || limit > MAX_LIMIT {
+                return invalid(
+                    "limit", format!("must be between 1 and {} (got {})", MAX_LIMIT, limit)
+                );
+            }
+        }
+
+        for (name, marker) in &[("before", &self.before), ("after", &self.after)] {
+            if marker.as_deref() == Some("") {
+                return invalid(name, String::from("must not be empty"));
+            }
+        }
+
+        if self.before.is_some() && self.after.is_some() {
+            return invalid("before", String::from("cannot be used together with 'after'"));
+        }
+
+        if self.extra.iter().any(|(name, _)| name.is_empty()) {
+            return invalid("", String::from("parameter names must not be empty"));
+        }
+
+        Ok(())
+    }
+
+    /// Appends the parameters (percent-encoded) to the query string of a URL. Does nothing if
+    /// there are no parameters.
+    pub(crate) fn apply(&self, url: &mut Url) {
+        if self.is_empty() {
+            return;
+        }
+
+        let mut pairs = url.query_pairs_mut();
+
+        if let Some(limit) = self.limit {
+            pairs.append_pair("limit", &limit.to_string());
+        }
+        if let Some(ref before) = self.before {
+            pairs.append_pair("before", before);
+        }
+        if let Some(ref after) = self.after {
+            pairs.append_pair("after", after);
+        }
+        for (name, value) in &self.extra {
+            pairs.append_pair(name, value);
+        }
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Query;
+    use crate::error::Error;
+
+    /// Tests validation of the documented limits.
+    #[test]
+    fn query_validate() {
+        assert!(Query::new().validate().is_ok());
+        assert!(Query::new().limit(200).before("abc").validate().is_ok());
+
+        for query in &[
+            Query::new().limit(0),
+            Query::new().limit(201),
+            Query::new().after(""),
+            Query::new().before("a").after("b"),
+            Query::new().param("", "x"),
+        ] {
+            match query.validate() {
+                Err(Error::InvalidParameter { .. }) => {},
+                other => panic!("Expected an InvalidParameter error for {:?}, got {:?}", query, other),
+            }
+        }
+    }
+
+    /// Tests merging queries.
+    #[test]
+    fn query_merged() {
+        let base = Query::new().limit(10).param("a", "1");
+        let merged = base.merged(&Query::new().limit(20).after("x").param("b", "2"));
+
+        assert_eq!(merged.limit, Some(20));
+        assert_eq!(merged.after.as_deref(), Some("x"));
+        assert_eq!(merged.extra.len(), 2);
+    }
+}
@@ -1,5 +1,6 @@
 //! Contains the `Request` class, used for building requests to the API.
 
+#[cfg(feature = "blocking")]
 use reqwest::blocking::{
     RequestBuilder
 };
@@ -9,18 +10,28 @@ use reqwest::{
     RequestBuilder as ARequestBuilder
 };
 
-use reqwest::{
-    header::{
-        HeaderMap,
-        USER_AGENT, AUTHORIZATION, CONTENT_TYPE, CONTENT_LENGTH,
-        HeaderValue,
-    },
-    Url,
-    Method,
-};
+#[cfg(feature = "ureq")]
+use ureq::Request as UreqRequest;
+
+use http::{header::HeaderMap, Method};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use http::header::{USER_AGENT, AUTHORIZATION, CONTENT_TYPE, CONTENT_LENGTH, HeaderValue};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use url::{Url, ParseError as UrlError};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::error::{Result, Error};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::http::Client;
-use crate::constants::USER_AGENT as B_API_USER_AGENT;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::http::secret::AuthKey;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::constants::{USER_AGENT as B_API_USER_AGENT, API_URI};
 
 
 /// A struct representing a request to some endpoint.
@@ -33,7 +44,8 @@ pub struct Request<'a> {
     /// The headers of the request.
     pub headers: Option<HeaderMap>,
 
-    /// The endpoint (e.g. /players/%23sometag).
+    /// The endpoint, either as a full URL (e.g. `https://api.brawlstars.com/v1/players/%23sometag`)
+    /// or relative to the API's base URL (e.g. `players/%23sometag`).
     pub endpoint: String,
 
     /// The method (GET/POST/...). Defaults to GET
@@ -47,7 +59,7 @@ impl<'a> Default for Request<'a> {
     ///
     /// ```rust
     /// use brawl_api::http::request::Request;
-    /// use reqwest::Method;
+    /// use http::Method;
     ///
     /// assert_eq!(
     ///     Request::default(),
@@ -69,22 +81,68 @@ impl<'a> Default for Request<'a> {
     }
 }
 
+/// Parses an endpoint into a URL, considering it relative to the API's base URL if it is not a
+/// full URL.
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+fn endpoint_url(endpoint: &str) -> Result<Url> {
+    match Url::parse(endpoint) {
+        Err(UrlError::RelativeUrlWithoutBase) => Url::parse(API_URI)
+            .and_then(|base| base.join(endpoint.trim_start_matches('/')))
+            .map_err(Error::Url),
+
+        other => other.map_err(Error::Url),
+    }
+}
+
 // (Credits to Serenity lib for the useful HTTP bases)
 impl<'a> Request<'a> {
+    /// Returns the headers to send with this request, authenticating with the given key.
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    fn headers_with_key(&self, key: &AuthKey) -> Result<HeaderMap> {
+        let mut headers = HeaderMap::with_capacity(4);
+        headers.insert(USER_AGENT, HeaderValue::from_static(&B_API_USER_AGENT));
+        headers.insert(AUTHORIZATION, key.authorization_header()?);  // the only use of the key
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(&"application/json"));
+        headers.insert(CONTENT_LENGTH, HeaderValue::from(self.body.map_or(0, <[u8]>::len)));
+
+        if let Some(ref r_headers) = self.headers {
+            headers.extend(r_headers.clone());
+        }
+
+        Ok(headers)
+    }
+
     /// (For sync usage) Creates a (blocking) [`RequestBuilder`] (`reqwest` crate) instance.
     ///
     /// [`RequestBuilder`]: https://docs.rs/reqwest/*/reqwest/blocking/struct.RequestBuilder.html
+    #[cfg(feature = "blocking")]
     pub fn build(&'a self, client: &Client) -> Result<RequestBuilder> {
+        self.build_with_key(client, &client.auth_key)
+    }
+
+    /// (For sync usage) Like `build`, but authenticates with the given key instead of the client's
+    /// [`auth_key`] (used when the client has a key pool).
+    ///
+    /// [`auth_key`]: ../client/struct.Client.html#structfield.auth_key
+    #[cfg(feature = "blocking")]
+    pub(crate) fn build_with_key(
+        &'a self, client: &Client, key: &AuthKey
+    ) -> Result<RequestBuilder> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!(
+            "brawl_api.build", method = %self.method, endpoint = %self.endpoint
+        ).entered();
+
         let Request {
             body,
-            headers: ref r_headers,
             endpoint: ref r_endpoint,
             ref method,
+            ..
         } = *self;
 
         let mut builder = client.inner.request(
             method.clone(),
-            Url::parse(r_endpoint).map_err(Error::Url)?,
+            endpoint_url(r_endpoint)?,
         );
 
         if let Some(ref bytes) = body {  // body was provided
@@ -92,28 +150,32 @@ impl<'a> Request<'a> {
             builder = builder.body(b_vec);
         }
 
-        let key = &client.auth_key;
+        builder = builder.headers(self.headers_with_key(key)?);
+
+        Ok(builder)
+    }
 
-        let key = if key.starts_with("Bearer ") {
-            key.clone()
-        } else {
-            format!("Bearer {}", key)
-        };
+    /// (For sync usage, with the `ureq` feature) Creates a [`ureq::Request`] instance,
+    /// authenticating with the given key.
+    ///
+    /// [`ureq::Request`]: https://docs.rs/ureq/2/ureq/struct.Request.html
+    #[cfg(feature = "ureq")]
+    pub(crate) fn u_build_with_key(&self, client: &Client, key: &AuthKey) -> Result<UreqRequest> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!(
+            "brawl_api.build", method = %self.method, endpoint = %self.endpoint
+        ).entered();
 
-        let mut headers = HeaderMap::with_capacity(3);
-        headers.insert(USER_AGENT, HeaderValue::from_static(&B_API_USER_AGENT));
-        headers.insert(AUTHORIZATION,
-                       HeaderValue::from_str(&key).map_err(Error::Authorization)?);
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static(&"application/json"));
-        headers.insert(CONTENT_LENGTH, HeaderValue::from_static(&"0"));
+        let url = endpoint_url(&self.endpoint)?;
+        let mut request = client.u_inner.request(self.method.as_str(), url.as_str());
 
-        if let Some(ref r_headers) = r_headers {
-            headers.extend(r_headers.clone());
+        for (name, value) in &self.headers_with_key(key)? {
+            if let Ok(value) = value.to_str() {  // ureq only takes visible ASCII header values
+                request = request.set(name.as_str(), value);
+            }
         }
 
-        builder = builder.headers(headers);
-
-        Ok(builder)
+        Ok(request)
     }
 
     /// (For async usage) Creates a (non-blocking) [`RequestBuilder`] (`reqwest` crate) instance.
@@ -121,16 +183,32 @@ impl<'a> Request<'a> {
     /// [`RequestBuilder`]: https://docs.rs/reqwest/*/reqwest/struct.RequestBuilder.html
     #[cfg(feature = "async")]
     pub fn a_build(&'a self, client: &Client) -> Result<ARequestBuilder> {
+        self.a_build_with_key(client, &client.auth_key)
+    }
+
+    /// (For async usage) Like `a_build`, but authenticates with the given key instead of the
+    /// client's [`auth_key`] (used when the client has a key pool).
+    ///
+    /// [`auth_key`]: ../client/struct.Client.html#structfield.auth_key
+    #[cfg(feature = "async")]
+    pub(crate) fn a_build_with_key(
+        &'a self, client: &Client, key: &AuthKey
+    ) -> Result<ARequestBuilder> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!(
+            "brawl_api.build", method = %self.method, endpoint = %self.endpoint
+        ).entered();
+
         let Request {
             body,
-            headers: ref r_headers,
             endpoint: ref r_endpoint,
             ref method,
+            ..
         } = *self;
 
         let mut builder = client.a_inner.request(
             method.clone(),
-            Url::parse(r_endpoint).map_err(Error::Url)?,
+            endpoint_url(r_endpoint)?,
         );
 
         if let Some(ref bytes) = body {  // body was provided
@@ -138,27 +216,48 @@ impl<'a> Request<'a> {
             builder = builder.body(b_vec);
         }
 
-        let key = &client.auth_key;
+        builder = builder.headers(self.headers_with_key(key)?);
 
-        let key = if key.starts_with("Bearer ") {
-            key.clone()
-        } else {
-            format!("Bearer {}", key)  // add "Bearer " if missing.
-        };
+        Ok(builder)
+    }
+}
 
-        let mut headers = HeaderMap::with_capacity(3);
-        headers.insert(USER_AGENT, HeaderValue::from_static(&B_API_USER_AGENT));
-        headers.insert(AUTHORIZATION,
-                       HeaderValue::from_str(&key).map_err(Error::Authorization)?);
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static(&"application/json"));
-        headers.insert(CONTENT_LENGTH, HeaderValue::from_static(&"0"));
+///////////////////////////////////   tests   ///////////////////////////////////
 
-        if let Some(ref r_headers) = r_headers {
-            headers.extend(r_headers.clone());
-        }
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use http::header::CONTENT_LENGTH;
+    use super::Request;
+    use crate::http::Client;
 
-        builder = builder.headers(headers);
+    /// Tests that relative endpoints are resolved against the API's base URL.
+    #[test]
+    fn request_relative_endpoint() -> Result<(), Box<dyn ::std::error::Error>> {
+        let client = Client::new("my auth key");
 
-        Ok(builder)
+        let request = client.endpoint_request("/events/rotation").build(&client)?.build()?;
+        assert_eq!(request.url().as_str(), "https://api.brawlstars.com/v1/events/rotation");
+
+        let full = client.endpoint_request("https://api.brawlstars.com/v1/brawlers/")
+            .build(&client)?.build()?;
+        assert_eq!(full.url().as_str(), "https://api.brawlstars.com/v1/brawlers/");
+
+        Ok(())
+    }
+
+    /// Tests that the Content-Length header matches the body.
+    #[test]
+    fn request_content_length() -> Result<(), Box<dyn ::std::error::Error>> {
+        let client = Client::new("my auth key");
+        let body = br#"{"some": "data"}"#;
+
+        let empty = client.endpoint_request("brawlers/").build(&client)?.build()?;
+        assert_eq!(empty.headers()[CONTENT_LENGTH], "0");
+
+        let with_body = Request { body: Some(body), ..client.endpoint_request("brawlers/") }
+            .build(&client)?.build()?;
+        assert_eq!(with_body.headers()[CONTENT_LENGTH], body.len().to_string().as_str());
+
+        Ok(())
     }
 }
@@ -1,33 +1,49 @@
 //! Contains the `Route` enum, responsible for listing the available API endpoints and parsing
 //! the given values into a valid URL.
 
-use crate::b_api_concat;
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use url::Url;
+use crate::error::Result;
+use crate::ids::BrawlerId;
+use crate::constants::API_URI;
+use super::query::Query;
+
+/// Returns a tag as a path segment (to be percent-encoded), accepting tags whose hashtag was
+/// already percent-encoded (`%23`).
+fn tag_segment(tag: &str) -> Cow<'_, str> {
+    match tag.strip_prefix("%23") {
+        Some(rest) => Cow::Owned(format!("#{}", rest)),
+        None => Cow::Borrowed(tag),
+    }
+}
 
 
 /// An enum representing the possible Brawl API routes.
 #[non_exhaustive]
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Route {
-    /// Route for the `/players/:tag` endpoint. (`tag` must begin with a `#` (`%23`) for correct
-    /// results.)
+    /// Route for the `/players/:tag` endpoint. (`tag` must begin with a `#`, which is
+    /// percent-encoded when building the URL; `%23` is also accepted.)
     ///
     /// This fetches a player's data.
     Player(String),
 
-    /// Route for the `/players/:tag/battlelog` endpoint. (`tag` must begin with a `#` (`%23`) for
-    /// correct results.)
+    /// Route for the `/players/:tag/battlelog` endpoint. (`tag` must begin with a `#`,
+    /// which is percent-encoded when building the URL; `%23` is also accepted.)
     ///
     /// This fetches the player's recently-played battles.
     PlayerBattlelogs(String),
 
-    /// Route for the `/clubs/:tag` endpoint. (`tag` must begin with a `#` (`%23`) for correct
-    /// results.)
+    /// Route for the `/clubs/:tag` endpoint. (`tag` must begin with a `#`, which is
+    /// percent-encoded when building the URL; `%23` is also accepted.)
     ///
     /// This fetches a club's data.
     Club(String),
 
     /// Route for the `/clubs/:tag/members` endpoint.
-    /// (`tag` must begin with a `#` (`%23`) for correct results.)
+    /// (`tag` must begin with a `#`, which is percent-encoded when building the URL; `%23` is
+    /// also accepted.)
     ///
     /// This fetches a club's members.
     ClubMembers(String),
@@ -35,8 +51,11 @@ pub enum Route {
     /// Route for the `/rankings/:country_code/players?limit=x` endpoint (shows the top `x` players
     /// with most trophies in said country code).
     ///
-    /// The limit can be up to 200. Specifying higher than that simply works the same way as
-    /// specifying 200, thus returning up to 200 entries.
+    /// The limit must be between 1 and 200 (see [`MAX_LIMIT`]); other values are refused with an
+    /// [`Error::InvalidParameter`] before sending the request.
+    ///
+    /// [`MAX_LIMIT`]: ../query/constant.MAX_LIMIT.html
+    /// [`Error::InvalidParameter`]: ../../error/enum.Error.html#variant.InvalidParameter
     PlayerRankings {
         /// The two-letter country code whose leaderboard should be fetched (e.g. BR for Brazil,
         /// ZW for Zimbabwe...), or `"global"` for the global leaderboard.
@@ -48,8 +67,11 @@ pub enum Route {
 
     /// Route for the `/rankings/:country_code/clubs?limit=x` endpoint.
     ///
-    /// The limit can be up to 200. Specifying higher than that simply works the same way as
-    /// specifying 200, thus returning up to 200 entries.
+    /// The limit must be between 1 and 200 (see [`MAX_LIMIT`]); other values are refused with an
+    /// [`Error::InvalidParameter`] before sending the request.
+    ///
+    /// [`MAX_LIMIT`]: ../query/constant.MAX_LIMIT.html
+    /// [`Error::InvalidParameter`]: ../../error/enum.Error.html#variant.InvalidParameter
     ClubRankings {
         /// The two-letter country code whose leaderboard should be fetched (e.g. BR for Brazil,
         /// ZW for Zimbabwe...), or `"global"` for the global leaderboard.
@@ -61,16 +83,21 @@ pub enum Route {
 
     /// Route for the `/rankings/:country_code/brawlers/:brawler_id?limit=x` endpoint.
     ///
-    /// The limit can be up to 200. Specifying higher than that simply works the same way as
-    /// specifying 200, thus returning up to 200 entries.
+    /// The limit must be between 1 and 200 (see [`MAX_LIMIT`]); other values are refused with an
+    /// [`Error::InvalidParameter`] before sending the request.
+    ///
+    /// [`MAX_LIMIT`]: ../query/constant.MAX_LIMIT.html
+    /// [`Error::InvalidParameter`]: ../../error/enum.Error.html#variant.InvalidParameter
     BrawlerRankings {
         /// The two-letter country code whose leaderboard should be fetched (e.g. BR for Brazil,
         /// ZW for Zimbabwe...), or `"global"` for the global leaderboard.
         country_code: String,
 
         /// The ID of the brawler whose rankings should be fetched. To obtain this,
-        /// use the `/brawlers/` endpoint.
-        brawler_id: usize,
+        /// use the `/brawlers/` endpoint (or the [`Brawlers`] enum).
+        ///
+        /// [`Brawlers`]: ../../constants/enum.Brawlers.html
+        brawler_id: BrawlerId,
 
         /// The limit of rankings to get (i.e., to get the top `limit` players, sorted by trophies
         /// on this specific brawler).
@@ -82,67 +109,319 @@ pub enum Route {
 
     /// Route for the `/brawlers/:id` endpoint, which returns data for a specific brawler, given
     /// that brawler's ID.
-    Brawler(usize),
+    Brawler(BrawlerId),
 }
 
 impl Route {
 
-    /// Evaluates the `Route` instance into a full URL path string.
+    /// Evaluates the `Route` instance into a full URL string, with percent-encoded path segments
+    /// and the route's own query parameters (see [`query`]). Unlike [`to_url`], this does not
+    /// validate the parameters.
     ///
     /// # Examples
-    /// ```rs
-    /// use brawl_api::Route;
-    /// assert_eq!(Route::Player("tag"), "https://api.brawlstars.com/v1/players/tag")
+    ///
+    /// ```rust
+    /// use brawl_api::http::Route;
+    ///
+    /// assert_eq!(
+    ///     Route::Player(String::from("#TAG")).to_url_str(),
+    ///     "https://api.brawlstars.com/v1/players/%23TAG"
+    /// );
+    /// assert_eq!(
+    ///     Route::PlayerBattlelogs(String::from("#TAG")).to_url_str(),
+    ///     "https://api.brawlstars.com/v1/players/%23TAG/battlelog"
+    /// );
     /// assert_eq!(
-    ///     Route::PlayerBattlelogs("tag"), "https://api.brawlstars.com/v1/players/tag/battlelogs"
-    /// )
-    /// assert_eq!(Route::Club("tag"), "https://api.brawlstars.com/v1/clubs/tag")
-    /// assert_eq!(Route::ClubMembers("tag"), "https://api.brawlstars.com/v1/clubs/tag/members")
+    ///     Route::ClubRankings { country_code: String::from("global"), limit: 5 }.to_url_str(),
+    ///     "https://api.brawlstars.com/v1/rankings/global/clubs?limit=5"
+    /// );
     /// ```
+    ///
+    /// [`query`]: #method.query
+    /// [`to_url`]: #method.to_url
     pub fn to_url_str(&self) -> String {
+        self.build_url(&self.query()).into_string()
+    }
+
+    /// Evaluates the `Route` instance into a full URL, with percent-encoded path segments and the
+    /// route's own query parameters (see [`query`]), after validating them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::InvalidParameter`] if a parameter is outside of the limits documented
+    /// by the API (e.g. a rankings `limit` above 200). See [`Query::validate`].
+    ///
+    /// [`query`]: #method.query
+    /// [`Error::InvalidParameter`]: ../../error/enum.Error.html#variant.InvalidParameter
+    /// [`Query::validate`]: ../query/struct.Query.html#method.validate
+    pub fn to_url(&self) -> Result<Url> {
+        self.to_url_with(&Query::new())
+    }
+
+    /// Like [`to_url`], but adding the given query parameters (which override the route's own
+    /// ones, such as the rankings `limit`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::InvalidParameter`] if a parameter is outside of the limits documented
+    /// by the API. See [`Query::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::http::{Query, Route};
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let route = Route::PlayerRankings { country_code: String::from("BR"), limit: 10 };
+    /// let url = route.to_url_with(&Query::new().before("someMarker"))?;
+    ///
+    /// assert_eq!(
+    ///     url.as_str(),
+    ///     "https://api.brawlstars.com/v1/rankings/BR/players?limit=10&before=someMarker"
+    /// );
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_url`]: #method.to_url
+    /// [`Error::InvalidParameter`]: ../../error/enum.Error.html#variant.InvalidParameter
+    /// [`Query::validate`]: ../query/struct.Query.html#method.validate
+    pub fn to_url_with(&self, query: &Query) -> Result<Url> {
+        let query = self.query().merged(query);
+        query.validate()?;
+        Ok(self.build_url(&query))
+    }
+
+    /// Returns the route's own query parameters (the `limit` of rankings routes).
+    pub fn query(&self) -> Query {
+        match *self {
+            Route::PlayerRankings { limit, .. }
+                | Route::ClubRankings { limit, .. }
+                | Route::BrawlerRankings { limit, .. } => Query::new().limit(u32::from(limit)),
+
+            _ => Query::new(),
+        }
+    }
+
+    /// Builds the URL of this route with the given query parameters (without validating them).
+    fn build_url(&self, query: &Query) -> Url {
+        let mut url = Url::parse(API_URI).expect("The API's base URL is valid.");
+
+        {
+            let mut segments = url.path_segments_mut()
+                .expect("The API's base URL can have path segments.");
+            segments.pop_if_empty();
+
+            match *self {
+                Route::Player(ref tag) => segments.extend(&["players", &*tag_segment(tag)]),
+
+                Route::PlayerBattlelogs(ref tag) => segments.extend(
+                    &["players", &*tag_segment(tag), "battlelog"]
+                ),
+
+                Route::Club(ref tag) => segments.extend(&["clubs", &*tag_segment(tag)]),
+
+                Route::ClubMembers(ref tag) => segments.extend(
+                    &["clubs", &*tag_segment(tag), "members"]
+                ),
+
+                Route::PlayerRankings { ref country_code, .. } => segments.extend(
+                    &["rankings", country_code.as_str(), "players"]
+                ),
+
+                Route::ClubRankings { ref country_code, .. } => segments.extend(
+                    &["rankings", country_code.as_str(), "clubs"]
+                ),
+
+                Route::BrawlerRankings { ref country_code, brawler_id, .. } => segments.extend(
+                    &["rankings", country_code.as_str(), "brawlers", &*brawler_id.to_string()]
+                ),
+
+                Route::Brawlers => segments.extend(&["brawlers", ""]),
+
+                Route::Brawler(id) => segments.extend(&["brawlers", &*id.to_string()]),
+            };
+        }
+
+        query.apply(&mut url);
+        url
+    }
+
+    /// Returns the kind of this route (i.e., which endpoint it is for, regardless of its
+    /// parameters).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::http::routes::{Route, RouteKind};
+    ///
+    /// assert_eq!(Route::Player(String::from("%23ABC")).kind(), RouteKind::Player);
+    /// assert_eq!(Route::Brawlers.kind(), RouteKind::Brawlers);
+    /// ```
+    pub fn kind(&self) -> RouteKind {
+        match *self {
+            Route::Player(_) => RouteKind::Player,
+            Route::PlayerBattlelogs(_) => RouteKind::PlayerBattlelogs,
+            Route::Club(_) => RouteKind::Club,
+            Route::ClubMembers(_) => RouteKind::ClubMembers,
+            Route::PlayerRankings { .. } => RouteKind::PlayerRankings,
+            Route::ClubRankings { .. } => RouteKind::ClubRankings,
+            Route::BrawlerRankings { .. } => RouteKind::BrawlerRankings,
+            Route::Brawlers => RouteKind::Brawlers,
+            Route::Brawler(_) => RouteKind::Brawler,
+        }
+    }
+}
+
+/// An enum representing the kinds of [`Route`] (one for each endpoint), plus `Other` for
+/// endpoints without a `Route` (requested through [`Client::request_json`]).
+///
+/// [`Route`]: enum.Route.html
+/// [`Client::request_json`]: ../client/struct.Client.html#method.request_json
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RouteKind {
+    /// The `/players/:tag` endpoint.
+    Player,
+
+    /// The `/players/:tag/battlelog` endpoint.
+    PlayerBattlelogs,
+
+    /// The `/clubs/:tag` endpoint.
+    Club,
+
+    /// The `/clubs/:tag/members` endpoint.
+    ClubMembers,
+
+    /// The `/rankings/:country_code/players` endpoint.
+    PlayerRankings,
+
+    /// The `/rankings/:country_code/clubs` endpoint.
+    ClubRankings,
+
+    /// The `/rankings/:country_code/brawlers/:brawler_id` endpoint.
+    BrawlerRankings,
+
+    /// The `/brawlers/` endpoint.
+    Brawlers,
+
+    /// The `/brawlers/:id` endpoint.
+    Brawler,
+
+    /// Any other endpoint.
+    Other,
+}
+
+impl RouteKind {
+    /// Returns a short, snake_case name for this kind of route (e.g. `"player_battlelogs"`).
+    pub fn as_str(self) -> &'static str {
         match self {
-            Route::Player(ref s) => format!("{}{}", b_api_concat!("players/"), s),
-
-            Route::PlayerBattlelogs(ref s) => format!(
-                "{}{}/battlelog", b_api_concat!("players/"), s
-            ),
-
-            Route::Club(ref s) => format!("{}{}", b_api_concat!("clubs/"), s),
-
-            Route::ClubMembers(ref s) => format!(
-                "{}{}/members", b_api_concat!("clubs/"), s
-            ),
-
-            Route::PlayerRankings {
-                ref country_code,
-                limit
-            } => format!(
-                "{}{}/players?limit={}", b_api_concat!("rankings/"), country_code, limit
-            ),
-
-            Route::ClubRankings {
-                ref country_code,
-                limit
-            } => format!(
-                "{}{}/clubs?limit={}", b_api_concat!("rankings/"), country_code, limit
-            ),
-
-            Route::BrawlerRankings {
-                ref country_code,
-                brawler_id,
-                limit
-            } => format!(
-                "{}{}/brawlers/{}?limit={}",
-                b_api_concat!("rankings/"), country_code, brawler_id, limit
-            ),
-
-            Route::Brawlers => String::from(b_api_concat!("brawlers/")),
-
-            Route::Brawler(id) => format!(
-                "{}/{}",
-                b_api_concat!("brawlers"),
-                id,
-            )
+            RouteKind::Player => "player",
+            RouteKind::PlayerBattlelogs => "player_battlelogs",
+            RouteKind::Club => "club",
+            RouteKind::ClubMembers => "club_members",
+            RouteKind::PlayerRankings => "player_rankings",
+            RouteKind::ClubRankings => "club_rankings",
+            RouteKind::BrawlerRankings => "brawler_rankings",
+            RouteKind::Brawlers => "brawlers",
+            RouteKind::Brawler => "brawler",
+            RouteKind::Other => "other",
+        }
+    }
+
+    /// Determines the kind of route of an endpoint (either a full URL or relative to the API's
+    /// base URL, as in [`Request`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::http::routes::RouteKind;
+    ///
+    /// assert_eq!(
+    ///     RouteKind::from_endpoint("https://api.brawlstars.com/v1/players/%23ABC/battlelog"),
+    ///     RouteKind::PlayerBattlelogs,
+    /// );
+    /// assert_eq!(RouteKind::from_endpoint("rankings/global/clubs?limit=5"), RouteKind::ClubRankings);
+    /// assert_eq!(RouteKind::from_endpoint("events/rotation"), RouteKind::Other);
+    /// ```
+    ///
+    /// [`Request`]: ../request/struct.Request.html
+    pub fn from_endpoint(endpoint: &str) -> RouteKind {
+        let path = endpoint.strip_prefix(API_URI).unwrap_or(endpoint);
+        let path = path.split(['?', '#']).next().unwrap_or("");
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        match segments.as_slice() {
+            ["players", _] => RouteKind::Player,
+            ["players", _, "battlelog"] => RouteKind::PlayerBattlelogs,
+            ["clubs", _] => RouteKind::Club,
+            ["clubs", _, "members"] => RouteKind::ClubMembers,
+            ["rankings", _, "players"] => RouteKind::PlayerRankings,
+            ["rankings", _, "clubs"] => RouteKind::ClubRankings,
+            ["rankings", _, "brawlers", _] => RouteKind::BrawlerRankings,
+            ["brawlers"] => RouteKind::Brawlers,
+            ["brawlers", _] => RouteKind::Brawler,
+            _ => RouteKind::Other,
         }
     }
-}
\ No newline at end of file
+}
+
+impl Display for RouteKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Route;
+    use crate::constants::Brawlers;
+    use crate::error::Error;
+
+    /// Tests that tags (with `#` or an already-encoded `%23`) are percent-encoded.
+    #[test]
+    fn route_tag_encoding() {
+        let expected = "https://api.brawlstars.com/v1/players/%23ABC/battlelog";
+
+        assert_eq!(Route::PlayerBattlelogs(String::from("#ABC")).to_url_str(), expected);
+        assert_eq!(Route::PlayerBattlelogs(String::from("%23ABC")).to_url_str(), expected);
+        assert_eq!(
+            Route::Club(String::from("#A/B?C")).to_url_str(),
+            "https://api.brawlstars.com/v1/clubs/%23A%2FB%3FC"
+        );
+    }
+
+    /// Tests URLs of routes with other kinds of parameters.
+    #[test]
+    fn route_url_params() -> Result<(), Box<dyn ::std::error::Error>> {
+        let rankings = Route::BrawlerRankings {
+            country_code: String::from("a b"),
+            brawler_id: Brawlers::Shelly.into(),
+            limit: 3,
+        };
+        assert_eq!(
+            rankings.to_url()?.as_str(),
+            "https://api.brawlstars.com/v1/rankings/a%20b/brawlers/16000000?limit=3"
+        );
+
+        assert_eq!(Route::Brawlers.to_url()?.as_str(), "https://api.brawlstars.com/v1/brawlers/");
+
+        Ok(())
+    }
+
+    /// Tests that out-of-range rankings limits are refused.
+    #[test]
+    fn route_limit_validation() {
+        for &limit in &[0, 201, 255] {
+            let route = Route::PlayerRankings { country_code: String::from("global"), limit };
+            assert!(matches!(route.to_url(), Err(Error::InvalidParameter { .. })));
+        }
+
+        let route = Route::ClubRankings { country_code: String::from("global"), limit: 200 };
+        assert!(route.to_url().is_ok());
+    }
+}
@@ -0,0 +1,224 @@
+//! Contains the [`AuthKey`] struct, which holds an API key while keeping it out of logs and
+//! clearing it from memory once it is no longer used.
+//!
+//! [`AuthKey`]: struct.AuthKey.html
+
+use std::env;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::fs;
+use std::path::Path;
+use zeroize::Zeroize;
+
+use crate::error::{Result, Error};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use http::header::HeaderValue;
+
+/// The text shown in place of the key when formatting an [`AuthKey`].
+///
+/// [`AuthKey`]: struct.AuthKey.html
+const REDACTED: &str = "[redacted]";
+
+/// Represents an API auth key, held by a [`Client`] (see its [`auth_key`] field).
+///
+/// Its `Debug` and `Display` implementations never show the key (so it doesn't leak into logs or
+/// panic messages), and its memory is cleared (zeroed) once it is dropped. The key itself is only
+/// read when writing the `Authorization` header of a request, or through [`expose_secret`].
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::http::AuthKey;
+///
+/// let key = AuthKey::new("my auth key");
+///
+/// assert_eq!(format!("{:?}", key), "AuthKey([redacted])");
+/// assert_eq!(key.to_string(), "[redacted]");
+/// assert_eq!(key.expose_secret(), "my auth key");
+/// ```
+///
+/// [`Client`]: ../client/struct.Client.html
+/// [`auth_key`]: ../client/struct.Client.html#structfield.auth_key
+/// [`expose_secret`]: #method.expose_secret
+#[derive(Clone, Default)]
+pub struct AuthKey(String);
+
+impl AuthKey {
+    /// Creates a new `AuthKey` holding (a copy of) the given key.
+    pub fn new(key: &str) -> AuthKey {
+        AuthKey(String::from(key))
+    }
+
+    /// Loads the key from the given environment variable, ignoring surrounding whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::LoadKey`] if the variable is not set, is not valid unicode, or is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, http::AuthKey};
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::from_key(AuthKey::from_env("BRAWL_API_KEY")?);
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error::LoadKey`]: ../../error/enum.Error.html#variant.LoadKey
+    pub fn from_env(var: &str) -> Result<AuthKey> {
+        let value = env::var(var)
+            .map_err(|err| Error::LoadKey(format!("environment variable {}: {}", var, err)))?;
+
+        AuthKey::from_untrimmed(value, || format!("environment variable {} is empty", var))
+    }
+
+    /// Loads the key from the file at the given path, ignoring surrounding whitespace (such as a
+    /// trailing newline).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::LoadKey`] if the file could not be read, or if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, http::AuthKey};
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::from_key(AuthKey::from_file("/run/secrets/brawl_api_key")?);
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error::LoadKey`]: ../../error/enum.Error.html#variant.LoadKey
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<AuthKey> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|err| Error::LoadKey(format!("file {}: {}", path.display(), err)))?;
+
+        AuthKey::from_untrimmed(contents, || format!("file {} is empty", path.display()))
+    }
+
+    /// Creates an `AuthKey` from a value with surrounding whitespace, clearing the original value.
+    fn from_untrimmed<F>(mut value: String, empty_msg: F) -> Result<AuthKey>
+        where F: FnOnce() -> String {
+        let key = AuthKey::new(value.trim());
+        value.zeroize();
+
+        if key.0.is_empty() {
+            Err(Error::LoadKey(empty_msg()))
+        } else {
+            Ok(key)
+        }
+    }
+
+    /// Returns the key itself. Avoid storing or logging the returned value.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// Creates the value of the `Authorization` header for this key (adding `"Bearer "` if
+    /// missing), marked as sensitive.
+    #[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+    pub(crate) fn authorization_header(&self) -> Result<HeaderValue> {
+        let mut header = if self.0.starts_with("Bearer ") {
+            self.0.clone()
+        } else {
+            format!("Bearer {}", self.0)  // add "Bearer " if missing.
+        };
+
+        let value = HeaderValue::from_str(&header).map_err(Error::Authorization);
+        header.zeroize();
+
+        let mut value = value?;
+        value.set_sensitive(true);
+        Ok(value)
+    }
+}
+
+impl Drop for AuthKey {
+    /// Clears the key's memory.
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Debug for AuthKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AuthKey").field(&format_args!("{}", REDACTED)).finish()
+    }
+}
+
+impl Display for AuthKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl From<&str> for AuthKey {
+    fn from(key: &str) -> AuthKey {
+        AuthKey::new(key)
+    }
+}
+
+impl From<String> for AuthKey {
+    fn from(key: String) -> AuthKey {
+        AuthKey(key)
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(test, any(feature = "blocking", feature = "ureq", feature = "async")))]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::process;
+    use super::AuthKey;
+    use crate::error::Error;
+    use crate::http::Client;
+
+    /// Tests that the key does not appear when formatting a key or a client.
+    #[test]
+    fn auth_key_redacted() {
+        let client = Client::new("super secret key");
+
+        assert!(!format!("{:?}", client).contains("super secret key"));
+        assert!(!format!("{} {:?}", client.auth_key, client.auth_key).contains("secret"));
+        assert_eq!(client.auth_key.expose_secret(), "super secret key");
+
+        let header = client.auth_key.authorization_header().unwrap();
+        assert!(header.is_sensitive());
+        assert_eq!(header.to_str().unwrap(), "Bearer super secret key");
+    }
+
+    /// Tests loading keys from environment variables and files. (The variable and file names are
+    /// unique to this test and process, as tests run in parallel, and so may several test runs.)
+    #[test]
+    fn auth_key_sources() -> Result<(), Box<dyn ::std::error::Error>> {
+        let var = format!("BRAWL_API_AUTH_KEY_SOURCES_{}", process::id());
+        env::set_var(&var, "  env key\n");
+        let key = AuthKey::from_env(&var);
+        env::remove_var(&var);
+        assert_eq!(key?.expose_secret(), "env key");
+
+        match AuthKey::from_env(&var) {
+            Err(Error::LoadKey(_)) => {},
+            other => panic!("Expected a LoadKey error, got {:?}", other),
+        }
+
+        let file_name = format!("brawl_api_auth_key_sources_{}.txt", process::id());
+        let path = env::temp_dir().join(file_name);
+        fs::write(&path, "file key\n")?;
+        let key = AuthKey::from_file(&path);
+        fs::remove_file(&path)?;
+        assert_eq!(key?.expose_secret(), "file key");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+//! A local HTTP server standing in for the API in tests, so that requests are actually in-flight
+//! (and can be counted) at the same time, which short-circuiting middleware can't do for async
+//! requests.
+
+use std::future::Future;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use crate::constants::API_URI;
+use super::middleware::{Middleware, RawResponse};
+use super::request::Request;
+
+/// Runs a future to completion on a new runtime. (Clients must be created and dropped outside of
+/// it, as they hold a blocking client whose own runtime can't be dropped in an async context.)
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new()
+        .basic_scheduler()
+        .enable_all()
+        .build()
+        .expect("Could not build the test runtime.")
+        .block_on(future)
+}
+
+/// Gives the status code and (JSON) body to respond with, given the requested path
+/// (e.g. `/players/%23TAG`).
+type Respond = dyn Fn(&str) -> (u16, String) + Send + Sync;
+
+/// A local server which takes `delay` to answer each request. Used as a middleware, it redirects
+/// all of a client's requests to itself.
+#[derive(Clone)]
+pub(crate) struct TestServer {
+    /// The server's base URL (e.g. `http://127.0.0.1:1234/`).
+    base: String,
+
+    /// The amount of requests being answered right now.
+    active: Arc<AtomicUsize>,
+
+    /// The most requests ever answered at once.
+    peak: Arc<AtomicUsize>,
+
+    /// The total amount of requests received.
+    total: Arc<AtomicUsize>,
+}
+
+impl TestServer {
+    /// Starts a server in the background, which answers each request with `respond`'s result
+    /// after sleeping for `delay`.
+    pub(crate) fn start<F>(delay: Duration, respond: F) -> TestServer
+        where F: Fn(&str) -> (u16, String) + Send + Sync + 'static
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind the test server.");
+        let server = TestServer {
+            base: format!("http://{}/", listener.local_addr().unwrap()),
+            active: Arc::new(AtomicUsize::new(0)),
+            peak: Arc::new(AtomicUsize::new(0)),
+            total: Arc::new(AtomicUsize::new(0)),
+        };
+
+        let respond: Arc<Respond> = Arc::new(respond);
+        let handle = server.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let server = handle.clone();
+                let respond = Arc::clone(&respond);
+                thread::spawn(move || server.answer(stream, delay, &*respond));
+            }
+        });
+
+        server
+    }
+
+    /// The most requests answered at once so far.
+    pub(crate) fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+
+    /// The amount of requests received so far.
+    pub(crate) fn total(&self) -> usize {
+        self.total.load(Ordering::SeqCst)
+    }
+
+    /// Reads a request (which is assumed to have no body) and answers it.
+    fn answer(&self, mut stream: TcpStream, delay: Duration, respond: &Respond) {
+        let mut head = Vec::new();
+        let mut buf = [0; 1024];
+        while !head.ends_with(b"\r\n\r\n") {
+            match stream.read(&mut buf) {
+                Ok(0) | Err(_) => return,
+                Ok(read) => head.extend_from_slice(&buf[..read]),
+            }
+        }
+
+        let head = String::from_utf8_lossy(&head);
+        let path = head.split(' ').nth(1).unwrap_or("/");
+
+        self.total.fetch_add(1, Ordering::SeqCst);
+        let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(active, Ordering::SeqCst);
+
+        thread::sleep(delay);
+        let (status, body) = respond(path);
+
+        self.active.fetch_sub(1, Ordering::SeqCst);
+
+        let _ = write!(
+            stream,
+            "HTTP/1.1 {} Test\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            status, body.len(), body,
+        );
+    }
+}
+
+impl Middleware for TestServer {
+    fn on_request(&self, request: &mut Request<'_>) -> Option<RawResponse> {
+        let path = request.endpoint.trim_start_matches(API_URI).trim_start_matches('/');
+        request.endpoint = format!("{}{}", self.base, path);
+        None
+    }
+}
@@ -0,0 +1,226 @@
+//! Contains the internals of the (optional) `tracing` instrumentation of requests. With the
+//! `tracing` feature disabled, everything here is a no-op.
+//!
+//! Each request sent by a [`Client`] gets a `brawl_api.request` span (at the `DEBUG` level),
+//! recording its method, route, status, latency (in milliseconds), response size (in bytes) and
+//! ratelimit headers. Request headers are never recorded, so the `Authorization` value (the API
+//! key) never shows up.
+//!
+//! There is no retry count, as the client never retries a request by itself; requests sent again
+//! (e.g. by [`Client::wait_until_available`]) get a span each.
+//!
+//! [`Client`]: ../client/struct.Client.html
+//! [`Client::wait_until_available`]: ../client/struct.Client.html#method.wait_until_available
+
+use crate::error::Result;
+use crate::http::request::Request;
+use crate::http::middleware::RawResponse;
+
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+#[cfg(feature = "tracing")]
+use tracing::{Span, field::Empty};
+
+#[cfg(feature = "tracing")]
+use crate::http::fetched::RatelimitInfo;
+
+#[cfg(all(feature = "tracing", feature = "async"))]
+use tracing::instrument::{Instrument, Instrumented};
+
+#[cfg(feature = "async")]
+use std::future::Future;
+
+/// The instrumentation of a single request (including the sending of its response through the
+/// client's middleware).
+#[derive(Debug)]
+pub(crate) struct RequestTrace {
+    #[cfg(feature = "tracing")]
+    span: Span,
+
+    #[cfg(feature = "tracing")]
+    start: Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl RequestTrace {
+    /// Starts the instrumentation of a request, as it will be sent (after any changes by
+    /// middleware).
+    pub(crate) fn new(request: &Request<'_>, short_circuited: bool) -> RequestTrace {
+        let span = tracing::debug_span!(
+            "brawl_api.request",
+            method = %request.method,
+            route = %request.endpoint,
+            short_circuited,
+            status = Empty,
+            latency_ms = Empty,
+            size = Empty,
+            ratelimit_limit = Empty,
+            ratelimit_remaining = Empty,
+            ratelimit_reset = Empty,
+        );
+
+        RequestTrace { span, start: Instant::now() }
+    }
+
+    /// Runs the given function inside the request's span.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    pub(crate) fn in_scope<T, F: FnOnce() -> T>(&self, f: F) -> T {
+        self.span.in_scope(f)
+    }
+
+    /// Makes the given future run inside the request's span.
+    #[cfg(feature = "async")]
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> Instrumented<F> {
+        future.instrument(self.span.clone())
+    }
+
+    /// Records the status, size and ratelimit headers of the received response.
+    pub(crate) fn response(&self, response: &RawResponse) {
+        let ratelimit = RatelimitInfo::from_headers(&response.headers);
+
+        self.span.record("status", response.status.as_u16());
+        self.span.record("size", response.body.len());
+        if let Some(limit) = ratelimit.limit {
+            self.span.record("ratelimit_limit", limit);
+        }
+        if let Some(remaining) = ratelimit.remaining {
+            self.span.record("ratelimit_remaining", remaining);
+        }
+        if let Some(ref reset) = ratelimit.reset {
+            self.span.record("ratelimit_reset", reset.as_str());
+        }
+    }
+
+    /// Records the latency of the request, and emits an event with its outcome.
+    pub(crate) fn finish(&self, result: &Result<RawResponse>) {
+        let latency_ms = self.start.elapsed().as_millis() as u64;
+        self.span.record("latency_ms", latency_ms);
+
+        self.span.in_scope(|| match *result {
+            Ok(_) => tracing::debug!(latency_ms, "request completed"),
+            Err(ref err) => tracing::warn!(latency_ms, error = %err, "request failed"),
+        });
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+impl RequestTrace {
+    /// Starts the instrumentation of a request. (No-op without the `tracing` feature.)
+    pub(crate) fn new(_request: &Request<'_>, _short_circuited: bool) -> RequestTrace {
+        RequestTrace {}
+    }
+
+    /// Runs the given function. (No span without the `tracing` feature.)
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    pub(crate) fn in_scope<T, F: FnOnce() -> T>(&self, f: F) -> T {
+        f()
+    }
+
+    /// Returns the given future. (No span without the `tracing` feature.)
+    #[cfg(feature = "async")]
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> F {
+        future
+    }
+
+    /// Records the received response. (No-op without the `tracing` feature.)
+    pub(crate) fn response(&self, _response: &RawResponse) {}
+
+    /// Records the outcome of the request. (No-op without the `tracing` feature.)
+    pub(crate) fn finish(&self, _result: &Result<RawResponse>) {}
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(
+    test, feature = "tracing", feature = "brawlers", any(feature = "blocking", feature = "ureq")
+))]
+mod tests {
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use http::StatusCode;
+    use tracing::{Event, Metadata, Subscriber};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use crate::http::{Client, Middleware, RawResponse, request::Request};
+    use crate::model::brawlers::BrawlerList;
+
+    /// Writes every recorded field into a shared log.
+    struct LogVisitor<'a>(&'a mut String);
+
+    impl<'a> Visit for LogVisitor<'a> {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.push_str(&format!("{}={:?} ", field.name(), value));
+        }
+    }
+
+    /// A subscriber which logs every span, field and event.
+    #[derive(Default)]
+    struct LogSubscriber {
+        log: Arc<Mutex<String>>,
+        next_id: AtomicU64,
+    }
+
+    impl Subscriber for LogSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool { true }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut log = self.log.lock().unwrap();
+            log.push_str(&format!("\nspan {} ", span.metadata().name()));
+            span.record(&mut LogVisitor(&mut log));
+            Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+        }
+
+        fn record(&self, _span: &Id, values: &Record<'_>) {
+            values.record(&mut LogVisitor(&mut self.log.lock().unwrap()));
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut log = self.log.lock().unwrap();
+            log.push_str("\nevent ");
+            event.record(&mut LogVisitor(&mut log));
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    /// Answers every request with an empty brawler list.
+    struct Fake;
+
+    impl Middleware for Fake {
+        fn on_request(&self, _request: &mut Request<'_>) -> Option<RawResponse> {
+            Some(RawResponse::new(StatusCode::OK, r#"{"items": []}"#))
+        }
+    }
+
+    /// Tests that requests are traced, and that the key never is.
+    #[test]
+    fn trace_request_redacted() {
+        let subscriber = LogSubscriber::default();
+        let log = Arc::clone(&subscriber.log);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let client = Client::new("super secret key");
+
+            #[cfg(feature = "blocking")]
+            let _builder = client.endpoint_request("brawlers").build(&client).unwrap();
+
+            let client = client.with_middleware(Fake);
+            client.get::<BrawlerList>(&()).unwrap();
+        });
+
+        let log = log.lock().unwrap();
+        #[cfg(feature = "blocking")]
+        assert!(log.contains("span brawl_api.build"));
+        assert!(log.contains("span brawl_api.request"));
+        assert!(log.contains("status=200"));
+        assert!(log.contains("size=13"));
+        assert!(log.contains("span brawl_api.deserialize"));
+        assert!(!log.contains("secret"));
+    }
+}
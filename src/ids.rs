@@ -0,0 +1,207 @@
+//! Contains typed ID structs ([`BrawlerId`], [`StarPowerId`], [`GadgetId`] and [`EventId`]),
+//! which wrap the numeric IDs given by the API in order to avoid mixing up IDs of different
+//! kinds (e.g. passing a star power's ID to [`Brawler::fetch`]).
+//!
+//! Each kind of ID belongs to a "family" of IDs in the game, which starts at a multiple of one
+//! million:
+//!
+//! - Brawlers: `16000000..17000000`
+//! - Accessories (star powers and gadgets): `23000000..24000000`
+//! - Events: `15000000..16000000`
+//!
+//! Creating an ID through `new` (or `TryFrom<usize>`) checks whether the given number is in the
+//! respective family, returning an [`Error::InvalidId`] otherwise. Note, however, that IDs are
+//! (de)serialized transparently (as plain numbers) and **not** checked when deserializing, as
+//! the API is trusted to provide correct values (and some fields may be absent, in which case
+//! the ID is `0`).
+//!
+//! [`BrawlerId`]: struct.BrawlerId.html
+//! [`StarPowerId`]: struct.StarPowerId.html
+//! [`GadgetId`]: struct.GadgetId.html
+//! [`EventId`]: struct.EventId.html
+//! [`Brawler::fetch`]: ../model/brawlers/struct.Brawler.html#method.fetch
+//! [`Error::InvalidId`]: ../error/enum.Error.html#variant.InvalidId
+
+use serde::{self, Serialize, Deserialize};
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use crate::error::{Result, Error};
+use crate::constants::Brawlers;
+
+/// Defines a typed ID struct, given its name and the start of its ID family.
+macro_rules! typed_id {
+    ($(#[$meta:meta])* $name:ident, $family:expr, $example:expr) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default,
+            Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub(crate) usize);
+
+        impl $name {
+            /// The first ID of this ID family.
+            pub const FAMILY_START: usize = $family;
+
+            /// The first ID after the end of this ID family.
+            pub const FAMILY_END: usize = $family + 1_000_000;
+
+            #[doc = concat!(
+                "Creates a new `", stringify!($name), "`, checking whether the given number ",
+                "belongs to its ID family.\n\n",
+                "# Errors\n\n",
+                "If `id` is not in the ID family, an [`Error::InvalidId`] is returned.\n\n",
+                "# Examples\n\n",
+                "```rust\n",
+                "use brawl_api::", stringify!($name), ";\n\n",
+                "assert!(", stringify!($name), "::new(", stringify!($example), ").is_ok());\n",
+                "assert!(", stringify!($name), "::new(1).is_err());\n",
+                "```\n\n",
+                "[`Error::InvalidId`]: ../error/enum.Error.html#variant.InvalidId",
+            )]
+            pub fn new(id: usize) -> Result<$name> {
+                if $name::is_in_family(id) {
+                    Ok($name(id))
+                } else {
+                    Err(Error::InvalidId { id_type: stringify!($name), id })
+                }
+            }
+
+            /// Returns whether or not the given number belongs to this ID family.
+            pub fn is_in_family(id: usize) -> bool {
+                ($name::FAMILY_START..$name::FAMILY_END).contains(&id)
+            }
+
+            /// Returns whether or not this ID belongs to its ID family. This is always `true`
+            /// for IDs created through `new`, but may be `false` for deserialized (or default)
+            /// ones.
+            pub fn is_valid(&self) -> bool {
+                $name::is_in_family(self.0)
+            }
+
+            /// Returns the inner number.
+            pub fn inner(&self) -> usize {
+                self.0
+            }
+        }
+
+        impl TryFrom<usize> for $name {
+            type Error = Error;
+
+            /// Attempts to create this ID from a number. See `new`.
+            fn try_from(id: usize) -> Result<$name> {
+                $name::new(id)
+            }
+        }
+
+        impl From<$name> for usize {
+            /// Returns the inner number of the ID.
+            fn from(id: $name) -> usize {
+                id.0
+            }
+        }
+
+        impl PartialEq<usize> for $name {
+            fn eq(&self, other: &usize) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl Display for $name {
+            /// Displays the inner number of the ID.
+            fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    }
+}
+
+typed_id!(
+    /// Represents the ID of a brawler (`16000000..17000000`). See the [module docs] for more
+    /// info.
+    ///
+    /// One can be obtained from a [`Brawlers`] variant with `BrawlerId::from`.
+    ///
+    /// [module docs]: index.html
+    /// [`Brawlers`]: ../constants/enum.Brawlers.html
+    BrawlerId, 16_000_000, 16000000
+);
+
+typed_id!(
+    /// Represents the ID of a star power (`23000000..24000000`, shared with gadgets). See the
+    /// [module docs] for more info.
+    ///
+    /// [module docs]: index.html
+    StarPowerId, 23_000_000, 23000076
+);
+
+typed_id!(
+    /// Represents the ID of a gadget (`23000000..24000000`, shared with star powers). See the
+    /// [module docs] for more info.
+    ///
+    /// [module docs]: index.html
+    GadgetId, 23_000_000, 23000255
+);
+
+typed_id!(
+    /// Represents the ID of an event (`15000000..16000000`). See the [module docs] for more
+    /// info.
+    ///
+    /// [module docs]: index.html
+    EventId, 15_000_000, 15000163
+);
+
+impl From<Brawlers> for BrawlerId {
+    /// Obtains the ID of a [`Brawlers`] variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::{Brawlers, BrawlerId};
+    ///
+    /// assert_eq!(BrawlerId::from(Brawlers::Shelly), 16000000);
+    /// ```
+    ///
+    /// [`Brawlers`]: ../constants/enum.Brawlers.html
+    fn from(brawler: Brawlers) -> BrawlerId {
+        BrawlerId(brawler as usize)
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::{BrawlerId, StarPowerId, EventId};
+    use crate::error::Error;
+
+    /// Tests ID family range checking.
+    #[test]
+    fn ids_range_check() {
+        assert!(BrawlerId::new(16000000).is_ok());
+        assert!(BrawlerId::new(16999999).is_ok());
+        assert!(BrawlerId::new(17000000).is_err());
+        assert!(BrawlerId::new(23000076).is_err());
+        assert!(StarPowerId::new(16000000).is_err());
+
+        match EventId::new(0) {
+            Err(Error::InvalidId { id_type, id }) => {
+                assert_eq!(id_type, "EventId");
+                assert_eq!(id, 0);
+            },
+            other => panic!("Expected an InvalidId error, got {:?}", other),
+        }
+    }
+
+    /// Tests that IDs are (de)serialized as plain numbers.
+    #[test]
+    fn ids_serde_transparent() -> Result<(), Box<dyn ::std::error::Error>> {
+        let id = BrawlerId::new(16000001)?;
+
+        assert_eq!(serde_json::to_string(&id)?, "16000001");
+        assert_eq!(serde_json::from_str::<BrawlerId>("16000001")?, id);
+
+        Ok(())
+    }
+}
@@ -5,9 +5,10 @@
 //!
 //! # Requesting
 //!
-//! All the requesting work is done by the [`reqwest` crate].
-//! The library supports both sync (blocking) and, with the `async` feature (enabled by default),
-//! async function styles - all fetching methods with that duality have a sync variant (with its
+//! All the requesting work is done by the [`reqwest` crate] (or, for sync requests with the
+//! `ureq` feature, by the [`ureq` crate]).
+//! The library supports both sync (blocking), with the `blocking` feature, and async, with the
+//! `async` feature (both enabled by default), function styles - all fetching methods with that duality have a sync variant (with its
 //! normal name) and an async one (named by prefixing the sync name with `a_` - e.g.: `fetch` (sync)
 //! and `a_fetch` (async)).
 //!
@@ -29,6 +30,9 @@
 //!
 //! For more info on models, see the [`model`] module.
 //!
+//! To share recorded API data (e.g. in bug reports) without exposing players' identities, see the
+//! [`anonymize`] module (or the `brawl-api anonymize` command).
+//!
 //! # Recommended Usage
 //!
 //! It is recommended to import the library using its [`prelude`] module:
@@ -48,8 +52,20 @@
 //!
 //! # Feature Flags
 //!
-//! The crate has a few feature flags available (all enabled by default):
-//!
+//! The crate has a few feature flags available (all enabled by default, except for `ureq`, `tracing`, `prometheus` and `fake`):
+//!
+//! - `blocking` flag:
+//!     - Enables the usage of sync (blocking) fetch functions - `fetch`, `fetch_from`,
+//! `fetch_into`, `refetch` - where applicable, as well as the [`BatchFetcher`].
+//!     - Enables `reqwest`'s blocking client. Disabling it (e.g. in async-only services) reduces
+//! compile times and binary size.
+//! - `ureq` flag:
+//!     - Makes sync (blocking) fetch functions available, like `blocking`, but sends their
+//! requests through the lightweight [`ureq` crate] instead of `reqwest` (even if `blocking` is
+//! enabled as well). With `blocking` and `async` disabled, neither `reqwest` nor `tokio` are
+//! compiled, which suits small CLI tools.
+//!     - Transport errors are still given as [`Error::Request`], whose [`TransportError`] then
+//! holds a `ureq` error instead of a `reqwest` one.
 //! - `async` flag:
 //!     - Enables the usage of async (non-blocking) fetch functions - `a_fetch`, `a_fetch_from`,
 //! `a_fetch_into`, `a_refetch` - where applicable.
@@ -67,16 +83,35 @@
 //! - `clubs` flag: Enables the usage of the [`model::clubs`] module (for the `/clubs` endpoint).
 //! - `rankings` flag: Enables the usage of the [`model::rankings`] module (for the `/rankings` endpoint).
 //! - `brawlers` flag: Enables the usage of the [`model::brawlers`] module (for the `/brawlers` endpoint).
+//! - `tracing` flag (disabled by default): Emits [`tracing`] spans and events for each request
+//! (building, sending and deserialization), recording its route, status, latency, response size
+//! and ratelimit headers.
+//!     - Adds `tracing` as a dependency. The `Authorization` header (API key) is never recorded.
+//! - `prometheus` flag (disabled by default): Enables rendering the client's metrics (see
+//! [`Client::metrics`]) in the Prometheus text format, and serving them at `/metrics` through a
+//! tiny HTTP listener (see [`Client::serve_metrics`]). Adds no dependencies.
+//! - `fake` flag (disabled by default): Enables the [`fake`] module, whose [`Faker`] generates
+//! realistic, internally consistent models from a seed (e.g. for tests). Adds no dependencies.
 //!
 //! [`reqwest` crate]: https://crates.io/crate/reqwest
+//! [`ureq` crate]: https://crates.io/crates/ureq
+//! [`Error::Request`]: error/enum.Error.html#variant.Request
+//! [`TransportError`]: error/struct.TransportError.html
+//! [`tracing`]: https://crates.io/crates/tracing
 //! [`serde`]: https://crates.io/crate/serde
 //! [`serde-json`]: https://crates.io/crate/serde-json
 //! [`Serialize`]: https://docs.rs/serde/*/ser/trait.Serialize.html
 //! [`Deserialize`]: https://docs.rs/serde/*/de/trait.Deserialize.html
 //! [`Player::fetch`]: model/players/player/struct.Player.html#method.fetch
+//! [`BatchFetcher`]: http/batch/struct.BatchFetcher.html
+//! [`Client::metrics`]: http/client/struct.Client.html#method.metrics
+//! [`Client::serve_metrics`]: http/client/struct.Client.html#method.serve_metrics
+//! [`fake`]: fake/index.html
+//! [`Faker`]: fake/struct.Faker.html
 //! [`TimeLike.parse`]: time/struct.TimeLike.html#method.parse
 //! [`chrono::DateTime<chrono::Utc>`]: https://docs.rs/chrono/*/chrono/struct.DateTime.html
 //! [`model`]: model/index.html
+//! [`anonymize`]: anonymize/index.html
 //! [`prelude`]: prelude/index.html
 //! [`Brawlers`]: constants/enum.Brawlers.html
 //! [`model::players`]: model/players/index.html
@@ -101,12 +136,24 @@ pub mod model;
 pub mod time;
 pub use time::TimeLike;
 
+pub mod ids;
+pub use ids::{BrawlerId, StarPowerId, GadgetId, EventId};
+
 #[cfg(any(feature = "players", feature = "brawlers"))]
-pub use model::common::StarPower;
+pub use model::common::{StarPower, Gadget};
+
+#[cfg(any(feature = "players", feature = "clubs", feature = "rankings"))]
+pub use model::common::PlayerIcon;
+
+#[cfg(any(feature = "clubs", feature = "rankings"))]
+pub use model::common::{Paging, PagingCursors};
+
+#[cfg(all(feature = "players", feature = "brawlers"))]
+pub use model::players::MissingAccessories;
 
 #[cfg(feature = "players")]
 pub use model::players::{
-    Player, PlayerClub, PlayerBrawlerStat,
+    Player, PlayerClub, PlayerBrawlerStat, Gear, HyperCharge, BrawlerSkin,
     battlelog::{
         BattleLog,
         Battle, BattleEvent, BattleResultInfo,
@@ -132,6 +179,14 @@ pub use model::brawlers::{BrawlerList, Brawler};
 pub mod error;
 pub use error::{Error, Result};
 
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+pub mod api;
+
+#[cfg(feature = "fake")]
+pub mod fake;
+
+pub mod anonymize;
+
 pub mod prelude;
 
 //#[cfg(test)]
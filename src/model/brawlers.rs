@@ -3,17 +3,20 @@
 //! module.
 
 use std::ops::{Deref, DerefMut};
-use crate::traits::{FetchFrom, Refetchable};
+use crate::traits::{FetchFrom, Refetchable, Endpoint};
 use crate::http::routes::Route;
-use crate::util::{fetch_route, a_fetch_route};
 use serde::{self, Serialize, Deserialize};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::error::Result;
 
 #[cfg(feature = "async")]
 use async_trait::async_trait;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::http::Client;
 
-use super::common::StarPower;
+use super::common::{StarPower, Gadget};
 
 #[cfg(feature = "players")]
 use super::players::{
@@ -21,6 +24,7 @@ use super::players::{
     battlelog::BattleBrawler,
 };
 use crate::Brawlers;
+use crate::ids::BrawlerId;
 
 // region:BrawlerList
 
@@ -146,9 +150,9 @@ impl BrawlerList {
     /// [`Error::Json`]: error/enum.Error.html#variant.Json
     /// [`Brawler`]: struct.Brawler.html
     /// [`Brawler::fetch`]: struct.Brawler.html#method.fetch
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     pub fn fetch(client: &Client) -> Result<BrawlerList> {
-        let route = BrawlerList::get_route();
-        fetch_route::<BrawlerList>(client, &route)
+        client.get::<BrawlerList>(&())
     }
 
     /// (Sync) Fetches data for all brawlers in the game (see [`Brawler`]). To fetch for a specific
@@ -186,8 +190,28 @@ impl BrawlerList {
     /// [`Brawler::fetch`]: struct.Brawler.html#method.fetch
     #[cfg(feature = "async")]
     pub async fn a_fetch(client: &Client) -> Result<BrawlerList> {
-        let route = BrawlerList::get_route();
-        a_fetch_route::<BrawlerList>(client, &route).await
+        client.a_get::<BrawlerList>(&()).await
+    }
+}
+
+impl Endpoint for BrawlerList {
+    type Params = ();
+
+    fn route(_: &()) -> Route { BrawlerList::get_route() }
+}
+
+#[cfg_attr(feature = "async", async_trait)]
+impl Refetchable for BrawlerList {
+    /// (Sync) Fetches the list of all brawlers again.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    fn refetch(&self, client: &Client) -> Result<BrawlerList> {
+        BrawlerList::fetch(client)
+    }
+
+    /// (Async) Fetches the list of all brawlers again.
+    #[cfg(feature = "async")]
+    async fn a_refetch(&self, client: &Client) -> Result<BrawlerList> {
+        BrawlerList::a_fetch(client).await
     }
 }
 
@@ -204,14 +228,22 @@ pub struct Brawler {
     #[serde(default)]
     pub name: String,
 
-    /// The brawler's ID (an arbitrary number representing it).
+    /// The brawler's ID (see [`BrawlerId`]).
+    ///
+    /// [`BrawlerId`]: ../../ids/struct.BrawlerId.html
     #[serde(default)]
-    pub id: usize,
+    pub id: BrawlerId,
 
     /// The brawler's star powers, as a vector (note that this does **not** have a fixed size:
     /// new brawlers start with 1 star power, while older ones have at least 2.)
     #[serde(default)]
     pub star_powers: Vec<StarPower>,
+
+    /// The brawler's gadgets, as a vector (just like star powers, this does **not** have a
+    /// fixed size.) The API omits this for brawlers without gadgets.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub gadgets: Vec<Gadget>,
 }
 
 impl Default for Brawler {
@@ -222,22 +254,24 @@ impl Default for Brawler {
     /// # Examples
     ///
     /// ```rust
-    /// use brawl_api::Brawler;
+    /// use brawl_api::{Brawler, BrawlerId};
     ///
     /// assert_eq!(
     ///     Brawler::default(),
     ///     Brawler {
     ///         name: String::from(""),
-    ///         id: 0,
-    ///         star_powers: vec![]
+    ///         id: BrawlerId::default(),
+    ///         star_powers: vec![],
+    ///         gadgets: vec![],
     ///     }
     /// );
     /// ```
     fn default() -> Brawler {
         Brawler {
             name: String::from(""),
-            id: 0,
-            star_powers: vec![]
+            id: BrawlerId::default(),
+            star_powers: vec![],
+            gadgets: vec![],
         }
     }
 }
@@ -256,23 +290,25 @@ impl Brawler {
     ///
     /// assert_eq!(brawler.get_fetch_prop(), brawler.id);
     /// ```
-    pub fn get_fetch_prop(&self) -> usize { self.id }
+    pub fn get_fetch_prop(&self) -> BrawlerId { self.id }
 
     /// Returns the [`Route`] object required for fetching a `Brawler` instance.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use brawl_api::{BrawlerList, http::Route};
+    /// use brawl_api::{Brawler, Brawlers, BrawlerId, http::Route};
+    ///
+    /// let shelly_id = BrawlerId::from(Brawlers::Shelly);
     ///
     /// assert_eq!(
-    ///     BrawlerList::get_route(),
-    ///     Route::Brawlers
+    ///     Brawler::get_route(shelly_id),
+    ///     Route::Brawler(shelly_id)
     /// );
     /// ```
     ///
     /// [`Route`]: http/routes/struct.Route.html
-    pub fn get_route(id: usize) -> Route { Route::Brawler(id) }
+    pub fn get_route(id: BrawlerId) -> Route { Route::Brawler(id) }
 
     /// (Sync) Fetches data for a brawler with a specific ID (see the [`Brawlers`] enum for a
     /// humanized list with IDs).
@@ -294,7 +330,7 @@ impl Brawler {
     ///
     /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
     /// let my_client = Client::new("my auth token");
-    /// let shelly = Brawler::fetch(&my_client, Brawlers::Shelly as usize)?;
+    /// let shelly = Brawler::fetch(&my_client, Brawlers::Shelly.into())?;
     /// // now the data for Shelly is available.
     ///
     /// # Ok(())
@@ -305,9 +341,9 @@ impl Brawler {
     /// [`Error::Status`]: error/enum.Error.html#variant.Status
     /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
     /// [`Error::Json`]: error/enum.Error.html#variant.Json
-    pub fn fetch(client: &Client, id: usize) -> Result<Brawler> {
-        let route = Brawler::get_route(id);
-        fetch_route::<Brawler>(client, &route)
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    pub fn fetch(client: &Client, id: BrawlerId) -> Result<Brawler> {
+        client.get::<Brawler>(&id)
     }
 
     /// (Async) Fetches data for a brawler with a specific ID (see the [`Brawlers`] enum for a
@@ -330,7 +366,7 @@ impl Brawler {
     ///
     /// # async fn main() -> Result<(), Box<dyn ::std::error::Error>> {
     /// let my_client = Client::new("my auth token");
-    /// let shelly = Brawler::a_fetch(&my_client, Brawlers::Shelly as usize).await?;
+    /// let shelly = Brawler::a_fetch(&my_client, Brawlers::Shelly.into()).await?;
     /// // now the data for Shelly is available.
     ///
     /// # Ok(())
@@ -342,15 +378,21 @@ impl Brawler {
     /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
     /// [`Error::Json`]: error/enum.Error.html#variant.Json
     #[cfg(feature="async")]
-    pub async fn a_fetch(client: &Client, id: usize) -> Result<Brawler> {
-        let route = Brawler::get_route(id);
-        a_fetch_route::<Brawler>(client, &route).await
+    pub async fn a_fetch(client: &Client, id: BrawlerId) -> Result<Brawler> {
+        client.a_get::<Brawler>(&id).await
     }
 }
 
+impl Endpoint for Brawler {
+    type Params = BrawlerId;
+
+    fn route(id: &BrawlerId) -> Route { Brawler::get_route(*id) }
+}
+
 #[cfg_attr(feature = "async", async_trait)]
 impl Refetchable for Brawler {
     /// (Sync) Fetches data for this brawler again.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn refetch(&self, client: &Client) -> Result<Brawler> {
         Brawler::fetch(client, self.id)
     }
@@ -368,6 +410,7 @@ impl FetchFrom<PlayerBrawlerStat> for Brawler {
     /// (Sync) Attempts to fetch a `Brawler` from an existing [`PlayerBrawlerStat`] instance.
     ///
     /// [`PlayerBrawlerStat`]: ../players/player/struct.PlayerBrawlerStat.html
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(client: &Client, p_brawler: &PlayerBrawlerStat) -> Result<Brawler> {
         Brawler::fetch(client, p_brawler.id)
     }
@@ -387,6 +430,7 @@ impl FetchFrom<BattleBrawler> for Brawler {
     /// (Sync) Attempts to fetch a `Brawler` from an existing [`BattleBrawler`] instance.
     ///
     /// [`BattleBrawler`]: ../players/battlelog/struct.BattleBrawler.html
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(client: &Client, b_brawler: &BattleBrawler) -> Result<Brawler> {
         Brawler::fetch(client, b_brawler.id)
     }
@@ -405,8 +449,9 @@ impl FetchFrom<Brawlers> for Brawler {
     /// (Sync) Attempts to fetch a `Brawler` from an existing [`Brawlers`] variant.
     ///
     /// [`Brawlers`]: ../constants/enum.Brawlers.html
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(client: &Client, b_brawler: &Brawlers) -> Result<Brawler> {
-        Brawler::fetch(client, b_brawler.to_owned() as usize)
+        Brawler::fetch(client, BrawlerId::from(*b_brawler))
     }
 
     /// (Async) Attempts to fetch a `Brawler` from an existing [`Brawlers`] variant.
@@ -414,7 +459,7 @@ impl FetchFrom<Brawlers> for Brawler {
     /// [`Brawlers`]: ../constants/enum.Brawlers.html
     #[cfg(feature = "async")]
     async fn a_fetch_from(client: &Client, b_brawler: &Brawlers) -> Result<Brawler> {
-        Brawler::a_fetch(client, b_brawler.to_owned() as usize).await
+        Brawler::a_fetch(client, BrawlerId::from(*b_brawler)).await
     }
 }
 
@@ -424,8 +469,9 @@ impl FetchFrom<Brawlers> for Brawler {
 mod tests {
     use serde_json;
     use super::{BrawlerList, Brawler};
-    use super::super::common::StarPower;
+    use super::super::common::{StarPower, Gadget};
     use crate::error::Error;
+    use crate::ids::{BrawlerId, StarPowerId, GadgetId};
 
     /// Tests for Brawlers deserialization from API-provided JSON.
     #[test]
@@ -445,6 +491,16 @@ mod tests {
           "id": 23000135,
           "name": "Band-Aid"
         }
+      ],
+      "gadgets": [
+        {
+          "id": 23000255,
+          "name": "Fast Forward"
+        },
+        {
+          "id": 23000288,
+          "name": "Clay Pigeons"
+        }
       ]
     },
     {
@@ -501,65 +557,84 @@ mod tests {
             BrawlerList {
               items: vec![
                 Brawler {
-                  id: 16000000,
+                  id: BrawlerId::new(16000000)?,
                   name: String::from("SHELLY"),
                   star_powers: vec![
                     StarPower {
-                      id: 23000076,
+                      id: StarPowerId::new(23000076)?,
                       name: String::from("Shell Shock")
                     },
                     StarPower {
-                      id: 23000135,
+                      id: StarPowerId::new(23000135)?,
                       name: String::from("Band-Aid")
                     }
+                  ],
+                  gadgets: vec![
+                    Gadget {
+                      id: GadgetId::new(23000255)?,
+                      name: String::from("Fast Forward")
+                    },
+                    Gadget {
+                      id: GadgetId::new(23000288)?,
+                      name: String::from("Clay Pigeons")
+                    }
                   ]
                 },
                 Brawler {
-                  id: 16000001,
+                  id: BrawlerId::new(16000001)?,
                   name: String::from("COLT"),
                   star_powers: vec![
                     StarPower {
-                      id: 23000077,
+                      id: StarPowerId::new(23000077)?,
                       name: String::from("Slick Boots")
                     },
                     StarPower {
-                      id: 23000138,
+                      id: StarPowerId::new(23000138)?,
                       name: String::from("Magnum Special")
                     }
-                  ]
+                  ],
+                  gadgets: vec![]
                 },
                 Brawler {
-                  id: 16000002,
+                  id: BrawlerId::new(16000002)?,
                   name: String::from("BULL"),
                   star_powers: vec![
                     StarPower {
-                      id: 23000078,
+                      id: StarPowerId::new(23000078)?,
                       name: String::from("Berserker")
                     },
                     StarPower {
-                      id: 23000137,
+                      id: StarPowerId::new(23000137)?,
                       name: String::from("Tough Guy")
                     }
-                  ]
+                  ],
+                  gadgets: vec![]
                 },
                 Brawler {
-                  id: 16000003,
+                  id: BrawlerId::new(16000003)?,
                   name: String::from("BROCK"),
                   star_powers: vec![
                     StarPower {
-                      id: 23000079,
+                      id: StarPowerId::new(23000079)?,
                       name: String::from("Incendiary")
                     },
                     StarPower {
-                      id: 23000150,
+                      id: StarPowerId::new(23000150)?,
                       name: String::from("Rocket No. Four")
                     }
-                  ]
+                  ],
+                  gadgets: vec![]
                 }
               ]
             }
         );
 
+        // serializing gives back the API's JSON
+        assert_eq!(
+            serde_json::to_value(&brawlers)?,
+            serde_json::from_str::<serde_json::Value>(brawlers_json_s)?
+        );
+
         Ok(())
     }
 
@@ -579,6 +654,16 @@ mod tests {
       "id": 23000135,
       "name": "Band-Aid"
     }
+  ],
+  "gadgets": [
+    {
+      "id": 23000255,
+      "name": "Fast Forward"
+    },
+    {
+      "id": 23000288,
+      "name": "Clay Pigeons"
+    }
   ]
 }"##;
 
@@ -589,21 +674,37 @@ mod tests {
             brawler,
 
             Brawler {
-                id: 16000000,
+                id: BrawlerId::new(16000000)?,
                 name: String::from("SHELLY"),
                 star_powers: vec![
                     StarPower {
-                        id: 23000076,
+                        id: StarPowerId::new(23000076)?,
                         name: String::from("Shell Shock")
                     },
                     StarPower {
-                        id: 23000135,
+                        id: StarPowerId::new(23000135)?,
                         name: String::from("Band-Aid")
                     }
+                ],
+                gadgets: vec![
+                    Gadget {
+                        id: GadgetId::new(23000255)?,
+                        name: String::from("Fast Forward")
+                    },
+                    Gadget {
+                        id: GadgetId::new(23000288)?,
+                        name: String::from("Clay Pigeons")
+                    }
                 ]
             }
         );
 
+        // serializing gives back the API's JSON
+        assert_eq!(
+            serde_json::to_value(&brawler)?,
+            serde_json::from_str::<serde_json::Value>(brawler_json_s)?
+        );
+
         Ok(())
     }
 }
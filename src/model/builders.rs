@@ -0,0 +1,852 @@
+//! Contains builders for constructing the main models in code (e.g. in tests), without writing
+//! full struct literals: [`PlayerBuilder`], [`ClubBuilder`], [`BattleBuilder`],
+//! [`BattleLogBuilder`], [`PlayerLeaderboardBuilder`], [`ClubLeaderboardBuilder`] and
+//! [`BrawlerLeaderboardBuilder`].
+//!
+//! Every builder starts from the model's `Default` values (e.g. a name color of `0xffffff`), and
+//! only changes the fields given through its `with_*` methods. Leaderboards, which have no
+//! `Default`, start with the `"global"` country code and no rankings.
+//!
+//! [`PlayerBuilder`]: struct.PlayerBuilder.html
+//! [`ClubBuilder`]: struct.ClubBuilder.html
+//! [`BattleBuilder`]: struct.BattleBuilder.html
+//! [`BattleLogBuilder`]: struct.BattleLogBuilder.html
+//! [`PlayerLeaderboardBuilder`]: struct.PlayerLeaderboardBuilder.html
+//! [`ClubLeaderboardBuilder`]: struct.ClubLeaderboardBuilder.html
+//! [`BrawlerLeaderboardBuilder`]: struct.BrawlerLeaderboardBuilder.html
+
+#[cfg(feature = "players")]
+use super::common::PlayerIcon;
+
+#[cfg(feature = "players")]
+use super::players::{
+    Player, PlayerClub, PlayerBrawlerStat,
+    battlelog::{BattleLog, Battle, BattlePlayer, BattleOutcome},
+};
+
+#[cfg(feature = "players")]
+use crate::ids::EventId;
+
+#[cfg(feature = "players")]
+use crate::time::TimeLike;
+
+#[cfg(feature = "clubs")]
+use super::clubs::{Club, ClubMember, ClubMemberRole, ClubType};
+
+#[cfg(feature = "rankings")]
+use super::rankings::{
+    players::{PlayerLeaderboard, PlayerRanking},
+    clubs::{ClubLeaderboard, ClubRanking},
+    brawlers::BrawlerLeaderboard,
+};
+
+#[cfg(feature = "rankings")]
+use crate::ids::BrawlerId;
+
+#[cfg(feature = "rankings")]
+use crate::http::query::MAX_LIMIT;
+
+/// A builder for [`Player`] instances, starting from [`Player::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::{PlayerBrawlerStat, Brawlers};
+/// use brawl_api::model::PlayerBuilder;
+///
+/// let player = PlayerBuilder::new()
+///     .with_tag("#ABC")
+///     .with_name("Someone")
+///     .with_trophies(500)
+///     .with_club("#CLUB", "Some Club")
+///     .with_brawler(PlayerBrawlerStat { id: Brawlers::Shelly.into(), ..PlayerBrawlerStat::default() })
+///     .build();
+///
+/// assert_eq!(player.trophies, 500);
+/// assert_eq!(player.club.unwrap().tag, "#CLUB");
+/// assert_eq!(player.name_color, 0xffffff);  // default
+/// ```
+///
+/// [`Player`]: ../players/player/struct.Player.html
+/// [`Player::default`]: ../players/player/struct.Player.html#method.default
+#[cfg(feature = "players")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerBuilder {
+    player: Player,
+}
+
+#[cfg(feature = "players")]
+impl PlayerBuilder {
+    /// Creates a new `PlayerBuilder`, with the default values of [`Player`].
+    ///
+    /// [`Player`]: ../players/player/struct.Player.html
+    pub fn new() -> PlayerBuilder {
+        PlayerBuilder::default()
+    }
+
+    /// Sets the player's tag.
+    pub fn with_tag(mut self, tag: &str) -> PlayerBuilder {
+        self.player.tag = String::from(tag);
+        self
+    }
+
+    /// Sets the player's name.
+    pub fn with_name(mut self, name: &str) -> PlayerBuilder {
+        self.player.name = String::from(name);
+        self
+    }
+
+    /// Sets the player's name color (e.g. `0xff1ba5f5`).
+    pub fn with_name_color(mut self, name_color: u64) -> PlayerBuilder {
+        self.player.name_color = name_color;
+        self
+    }
+
+    /// Sets the ID of the player's profile icon.
+    pub fn with_icon(mut self, icon_id: usize) -> PlayerBuilder {
+        self.player.icon = PlayerIcon { id: icon_id };
+        self
+    }
+
+    /// Sets the player's current trophies. Their highest trophies are raised to match, if they
+    /// were lower.
+    pub fn with_trophies(mut self, trophies: usize) -> PlayerBuilder {
+        self.player.trophies = trophies;
+        self.player.highest_trophies = self.player.highest_trophies.max(trophies);
+        self
+    }
+
+    /// Sets the player's highest trophies.
+    pub fn with_highest_trophies(mut self, highest_trophies: usize) -> PlayerBuilder {
+        self.player.highest_trophies = highest_trophies;
+        self
+    }
+
+    /// Sets the player's experience level and points.
+    pub fn with_exp(mut self, level: usize, points: usize) -> PlayerBuilder {
+        self.player.exp_level = level;
+        self.player.exp_points = points;
+        self
+    }
+
+    /// Sets the player's current and highest power play points.
+    pub fn with_power_play_points(mut self, points: usize, highest: usize) -> PlayerBuilder {
+        self.player.power_play_points = points;
+        self.player.highest_power_play_points = highest;
+        self
+    }
+
+    /// Sets the player's victories in 3v3 modes, Solo Showdown and Duo Showdown, respectively.
+    pub fn with_victories(mut self, tvt: usize, solo: usize, duo: usize) -> PlayerBuilder {
+        self.player.tvt_victories = tvt;
+        self.player.solo_victories = solo;
+        self.player.duo_victories = duo;
+        self
+    }
+
+    /// Sets the club the player is in.
+    pub fn with_club(mut self, tag: &str, name: &str) -> PlayerBuilder {
+        self.player.club = Some(PlayerClub { tag: String::from(tag), name: String::from(name) });
+        self
+    }
+
+    /// Adds a brawler to the player's brawlers.
+    pub fn with_brawler(mut self, brawler: PlayerBrawlerStat) -> PlayerBuilder {
+        self.player.brawlers.push(brawler);
+        self
+    }
+
+    /// Adds brawlers to the player's brawlers.
+    pub fn with_brawlers<I>(mut self, brawlers: I) -> PlayerBuilder
+        where I: IntoIterator<Item = PlayerBrawlerStat> {
+        self.player.brawlers.extend(brawlers);
+        self
+    }
+
+    /// Returns the built [`Player`].
+    ///
+    /// [`Player`]: ../players/player/struct.Player.html
+    pub fn build(self) -> Player {
+        self.player
+    }
+}
+
+/// A builder for [`Battle`] instances, starting from [`Battle::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::{BattlePlayer, BattleOutcome};
+/// use brawl_api::model::BattleBuilder;
+///
+/// let me = BattlePlayer { tag: String::from("#ABC"), ..BattlePlayer::default() };
+/// let teams = vec![
+///     vec![me.clone(), BattlePlayer::default(), BattlePlayer::default()],
+///     vec![BattlePlayer::default(); 3],
+/// ];
+///
+/// let battle = BattleBuilder::new()
+///     .with_time("20200131T003432.000Z")
+///     .with_map("Backyard Bowl")
+///     .with_team_battle("brawlBall", teams, BattleOutcome::Victory)
+///     .with_star_player(me)
+///     .with_trophy_change(8)
+///     .build();
+///
+/// assert_eq!(battle.event.mode, "brawlBall");
+/// assert_eq!(battle.result.mode, "brawlBall");
+/// assert_eq!(battle.result.result, Some(BattleOutcome::Victory));
+/// ```
+///
+/// [`Battle`]: ../players/battlelog/struct.Battle.html
+/// [`Battle::default`]: ../players/battlelog/struct.Battle.html#method.default
+#[cfg(feature = "players")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BattleBuilder {
+    battle: Battle,
+}
+
+#[cfg(feature = "players")]
+impl BattleBuilder {
+    /// Creates a new `BattleBuilder`, with the default values of [`Battle`].
+    ///
+    /// [`Battle`]: ../players/battlelog/struct.Battle.html
+    pub fn new() -> BattleBuilder {
+        BattleBuilder::default()
+    }
+
+    /// Sets the time of the battle, in the API's format (e.g. `"20200131T003432.000Z"`).
+    pub fn with_time(mut self, time: &str) -> BattleBuilder {
+        self.battle.battle_time = TimeLike(String::from(time));
+        self
+    }
+
+    /// Sets the event mode (e.g. `"brawlBall"`, `"soloShowdown"`...), both in the event and in
+    /// the battle's result.
+    pub fn with_mode(mut self, mode: &str) -> BattleBuilder {
+        self.battle.event.mode = String::from(mode);
+        self.battle.result.mode = String::from(mode);
+        self
+    }
+
+    /// Sets the name of the event's map.
+    pub fn with_map(mut self, map: &str) -> BattleBuilder {
+        self.battle.event.map = String::from(map);
+        self
+    }
+
+    /// Sets the ID of the event.
+    pub fn with_event_id(mut self, id: EventId) -> BattleBuilder {
+        self.battle.event.id = id;
+        self
+    }
+
+    /// Sets the battle type (e.g. `Some("ranked")`).
+    pub fn with_type(mut self, battle_type: Option<&str>) -> BattleBuilder {
+        self.battle.result.battle_type = battle_type.map(String::from);
+        self
+    }
+
+    /// Sets the duration of the battle, in seconds.
+    pub fn with_duration(mut self, duration: usize) -> BattleBuilder {
+        self.battle.result.duration = duration;
+        self
+    }
+
+    /// Sets the amount of trophies won (or lost, if negative) in the battle.
+    pub fn with_trophy_change(mut self, trophy_change: isize) -> BattleBuilder {
+        self.battle.result.trophy_change = trophy_change;
+        self
+    }
+
+    /// Sets the battle as a team battle (e.g. 3v3 or Duo Showdown) of the given mode, between
+    /// the given teams, with the given outcome (for the player whose battle log this is).
+    pub fn with_team_battle(
+        self, mode: &str, teams: Vec<Vec<BattlePlayer>>, outcome: BattleOutcome
+    ) -> BattleBuilder {
+        let mut builder = self.with_mode(mode).with_teams(teams);
+        builder.battle.result.result = Some(outcome);
+        builder
+    }
+
+    /// Sets the teams in the battle.
+    pub fn with_teams(mut self, teams: Vec<Vec<BattlePlayer>>) -> BattleBuilder {
+        self.battle.result.teams = Some(teams);
+        self
+    }
+
+    /// Sets the players in the battle, for modes without teams (e.g. Solo Showdown).
+    pub fn with_players(mut self, players: Vec<BattlePlayer>) -> BattleBuilder {
+        self.battle.result.players = Some(players);
+        self
+    }
+
+    /// Sets the rank of the player whose battle log this is (e.g. in Showdown).
+    pub fn with_rank(mut self, rank: u8) -> BattleBuilder {
+        self.battle.result.rank = Some(rank);
+        self
+    }
+
+    /// Sets the outcome of the battle (for the player whose battle log this is).
+    pub fn with_outcome(mut self, outcome: BattleOutcome) -> BattleBuilder {
+        self.battle.result.result = Some(outcome);
+        self
+    }
+
+    /// Sets the star player of the battle.
+    pub fn with_star_player(mut self, star_player: BattlePlayer) -> BattleBuilder {
+        self.battle.result.star_player = Some(star_player);
+        self
+    }
+
+    /// Returns the built [`Battle`].
+    ///
+    /// [`Battle`]: ../players/battlelog/struct.Battle.html
+    pub fn build(self) -> Battle {
+        self.battle
+    }
+}
+
+/// A builder for [`BattleLog`] instances, which starts with an empty tag and no battles.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::model::{BattleLogBuilder, BattleBuilder};
+///
+/// let battlelog = BattleLogBuilder::new("#ABC")
+///     .with_battle(BattleBuilder::new().with_mode("soloShowdown").with_rank(1).build())
+///     .build();
+///
+/// assert_eq!(battlelog.tag, "#ABC");
+/// assert_eq!(battlelog.len(), 1);
+/// ```
+///
+/// [`BattleLog`]: ../players/battlelog/struct.BattleLog.html
+#[cfg(feature = "players")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BattleLogBuilder {
+    tag: String,
+    items: Vec<Battle>,
+}
+
+#[cfg(feature = "players")]
+impl BattleLogBuilder {
+    /// Creates a new `BattleLogBuilder`, for the battle log of the player with the given tag.
+    pub fn new(tag: &str) -> BattleLogBuilder {
+        BattleLogBuilder { tag: String::from(tag), items: Vec::new() }
+    }
+
+    /// Adds a battle to the battle log (battle logs are sorted from the most recent battle).
+    pub fn with_battle(mut self, battle: Battle) -> BattleLogBuilder {
+        self.items.push(battle);
+        self
+    }
+
+    /// Adds battles to the battle log (battle logs are sorted from the most recent battle).
+    pub fn with_battles<I>(mut self, battles: I) -> BattleLogBuilder
+        where I: IntoIterator<Item = Battle> {
+        self.items.extend(battles);
+        self
+    }
+
+    /// Returns the built [`BattleLog`].
+    ///
+    /// [`BattleLog`]: ../players/battlelog/struct.BattleLog.html
+    pub fn build(self) -> BattleLog {
+        BattleLog { tag: self.tag, items: self.items }
+    }
+}
+
+/// A builder for [`Club`] instances, starting from [`Club::default`]. The tag of the built club's
+/// [`ClubMembers`] is always the club's tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::ClubMemberRole;
+/// use brawl_api::model::ClubBuilder;
+///
+/// let club = ClubBuilder::new()
+///     .with_tag("#CLUB")
+///     .with_name("Some Club")
+///     .with_member("#ABC", ClubMemberRole::President, 1000)
+///     .with_member("#DEF", ClubMemberRole::Member, 500)
+///     .build();
+///
+/// assert_eq!(club.member_count(), 2);
+/// assert_eq!(club.members.tag, "#CLUB");
+/// assert_eq!(club.members[0].role, ClubMemberRole::President);
+/// ```
+///
+/// [`Club`]: ../clubs/struct.Club.html
+/// [`Club::default`]: ../clubs/struct.Club.html#method.default
+/// [`ClubMembers`]: ../clubs/members/struct.ClubMembers.html
+#[cfg(feature = "clubs")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClubBuilder {
+    club: Club,
+}
+
+#[cfg(feature = "clubs")]
+impl ClubBuilder {
+    /// Creates a new `ClubBuilder`, with the default values of [`Club`].
+    ///
+    /// [`Club`]: ../clubs/struct.Club.html
+    pub fn new() -> ClubBuilder {
+        ClubBuilder::default()
+    }
+
+    /// Sets the club's tag.
+    pub fn with_tag(mut self, tag: &str) -> ClubBuilder {
+        self.club.tag = String::from(tag);
+        self
+    }
+
+    /// Sets the club's name.
+    pub fn with_name(mut self, name: &str) -> ClubBuilder {
+        self.club.name = String::from(name);
+        self
+    }
+
+    /// Sets the club's description.
+    pub fn with_description(mut self, description: &str) -> ClubBuilder {
+        self.club.description = Some(String::from(description));
+        self
+    }
+
+    /// Sets the club's trophies.
+    pub fn with_trophies(mut self, trophies: usize) -> ClubBuilder {
+        self.club.trophies = trophies;
+        self
+    }
+
+    /// Sets the trophies required to join the club.
+    pub fn with_required_trophies(mut self, required_trophies: usize) -> ClubBuilder {
+        self.club.required_trophies = required_trophies;
+        self
+    }
+
+    /// Sets the club's type (whether it's open, invite-only or closed).
+    pub fn with_type(mut self, club_type: ClubType) -> ClubBuilder {
+        self.club.club_type = club_type;
+        self
+    }
+
+    /// Sets the ID of the club's badge.
+    pub fn with_badge_id(mut self, badge_id: usize) -> ClubBuilder {
+        self.club.badge_id = badge_id;
+        self
+    }
+
+    /// Sets whether the club is marked as family-friendly.
+    pub fn with_family_friendly(mut self, is_family_friendly: bool) -> ClubBuilder {
+        self.club.is_family_friendly = is_family_friendly;
+        self
+    }
+
+    /// Adds a member with the given tag, role and trophies (and default values otherwise) to the
+    /// club's members.
+    pub fn with_member(self, tag: &str, role: ClubMemberRole, trophies: usize) -> ClubBuilder {
+        self.with_club_member(ClubMember {
+            tag: String::from(tag),
+            role,
+            trophies,
+            ..ClubMember::default()
+        })
+    }
+
+    /// Adds a member to the club's members.
+    pub fn with_club_member(mut self, member: ClubMember) -> ClubBuilder {
+        self.club.members.push(member);
+        self
+    }
+
+    /// Returns the built [`Club`].
+    ///
+    /// [`Club`]: ../clubs/struct.Club.html
+    pub fn build(mut self) -> Club {
+        self.club.members.tag = self.club.tag.clone();
+        self.club
+    }
+}
+
+/// Asserts that a leaderboard with `amount` rankings can have another one.
+///
+/// # Panics
+///
+/// Panics if the leaderboard already has [`MAX_LIMIT`] rankings, the most the API returns.
+///
+/// [`MAX_LIMIT`]: ../../http/query/constant.MAX_LIMIT.html
+#[cfg(feature = "rankings")]
+fn assert_room(amount: usize) {
+    assert!(
+        amount < MAX_LIMIT as usize,
+        "a leaderboard can't have more than {} rankings", MAX_LIMIT
+    );
+}
+
+/// Creates a player ranking at the given rank, with default values otherwise.
+#[cfg(feature = "rankings")]
+fn player_ranking(rank: u8, tag: &str, name: &str, trophies: usize) -> PlayerRanking {
+    PlayerRanking {
+        tag: String::from(tag),
+        name: String::from(name),
+        trophies,
+        rank,
+        ..PlayerRanking::default()
+    }
+}
+
+/// A builder for [`PlayerLeaderboard`] instances, which starts with the `"global"` country code
+/// and no rankings. Unless set through [`with_limit`], the built leaderboard's `limit` is its
+/// amount of rankings. Like the API's, it can have at most 200 rankings.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::model::PlayerLeaderboardBuilder;
+///
+/// let leaderboard = PlayerLeaderboardBuilder::new()
+///     .with_country_code("BR")
+///     .with_player("#ABC", "First", 50000)
+///     .with_player("#DEF", "Second", 49000)
+///     .build();
+///
+/// assert_eq!(leaderboard.limit, 2);
+/// assert_eq!(leaderboard[1].rank, 2);
+/// ```
+///
+/// [`PlayerLeaderboard`]: ../rankings/players/struct.PlayerLeaderboard.html
+/// [`with_limit`]: #method.with_limit
+#[cfg(feature = "rankings")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerLeaderboardBuilder {
+    country_code: String,
+    limit: Option<u8>,
+    items: Vec<PlayerRanking>,
+}
+
+#[cfg(feature = "rankings")]
+impl Default for PlayerLeaderboardBuilder {
+    /// Returns a builder for the global leaderboard, without rankings.
+    fn default() -> PlayerLeaderboardBuilder {
+        PlayerLeaderboardBuilder {
+            country_code: String::from("global"), limit: None, items: Vec::new()
+        }
+    }
+}
+
+#[cfg(feature = "rankings")]
+impl PlayerLeaderboardBuilder {
+    /// Creates a new `PlayerLeaderboardBuilder`, for the global leaderboard.
+    pub fn new() -> PlayerLeaderboardBuilder {
+        PlayerLeaderboardBuilder::default()
+    }
+
+    /// Sets the leaderboard's country code (or `"global"`).
+    pub fn with_country_code(mut self, country_code: &str) -> PlayerLeaderboardBuilder {
+        self.country_code = String::from(country_code);
+        self
+    }
+
+    /// Sets the leaderboard's limit of rankings (clamped to 200, the greatest the API accepts).
+    pub fn with_limit(mut self, limit: u8) -> PlayerLeaderboardBuilder {
+        self.limit = Some(limit.min(MAX_LIMIT as u8));
+        self
+    }
+
+    /// Adds a player with the given tag, name and trophies (and default values otherwise) at the
+    /// next rank.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the leaderboard already has 200 rankings.
+    pub fn with_player(
+        mut self, tag: &str, name: &str, trophies: usize
+    ) -> PlayerLeaderboardBuilder {
+        assert_room(self.items.len());
+        let rank = self.items.len() as u8 + 1;
+        self.items.push(player_ranking(rank, tag, name, trophies));
+        self
+    }
+
+    /// Adds a ranking to the leaderboard, as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the leaderboard already has 200 rankings.
+    pub fn with_ranking(mut self, ranking: PlayerRanking) -> PlayerLeaderboardBuilder {
+        assert_room(self.items.len());
+        self.items.push(ranking);
+        self
+    }
+
+    /// Returns the built [`PlayerLeaderboard`].
+    ///
+    /// [`PlayerLeaderboard`]: ../rankings/players/struct.PlayerLeaderboard.html
+    pub fn build(self) -> PlayerLeaderboard {
+        PlayerLeaderboard {
+            country_code: self.country_code,
+            limit: self.limit.unwrap_or(self.items.len() as u8),
+            items: self.items,
+            paging: None,
+        }
+    }
+}
+
+/// A builder for [`ClubLeaderboard`] instances, which starts with the `"global"` country code
+/// and no rankings. Unless set through [`with_limit`], the built leaderboard's `limit` is its
+/// amount of rankings. Like the API's, it can have at most 200 rankings.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::model::ClubLeaderboardBuilder;
+///
+/// let leaderboard = ClubLeaderboardBuilder::new()
+///     .with_club("#CLUB", "First", 1000000)
+///     .build();
+///
+/// assert_eq!(leaderboard.country_code, "global");
+/// assert_eq!(leaderboard[0].rank, 1);
+/// ```
+///
+/// [`ClubLeaderboard`]: ../rankings/clubs/struct.ClubLeaderboard.html
+/// [`with_limit`]: #method.with_limit
+#[cfg(feature = "rankings")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClubLeaderboardBuilder {
+    country_code: String,
+    limit: Option<u8>,
+    items: Vec<ClubRanking>,
+}
+
+#[cfg(feature = "rankings")]
+impl Default for ClubLeaderboardBuilder {
+    /// Returns a builder for the global leaderboard, without rankings.
+    fn default() -> ClubLeaderboardBuilder {
+        ClubLeaderboardBuilder {
+            country_code: String::from("global"), limit: None, items: Vec::new()
+        }
+    }
+}
+
+#[cfg(feature = "rankings")]
+impl ClubLeaderboardBuilder {
+    /// Creates a new `ClubLeaderboardBuilder`, for the global leaderboard.
+    pub fn new() -> ClubLeaderboardBuilder {
+        ClubLeaderboardBuilder::default()
+    }
+
+    /// Sets the leaderboard's country code (or `"global"`).
+    pub fn with_country_code(mut self, country_code: &str) -> ClubLeaderboardBuilder {
+        self.country_code = String::from(country_code);
+        self
+    }
+
+    /// Sets the leaderboard's limit of rankings (clamped to 200, the greatest the API accepts).
+    pub fn with_limit(mut self, limit: u8) -> ClubLeaderboardBuilder {
+        self.limit = Some(limit.min(MAX_LIMIT as u8));
+        self
+    }
+
+    /// Adds a club with the given tag, name and trophies (and default values otherwise) at the
+    /// next rank.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the leaderboard already has 200 rankings.
+    pub fn with_club(mut self, tag: &str, name: &str, trophies: usize) -> ClubLeaderboardBuilder {
+        assert_room(self.items.len());
+        let rank = self.items.len() as u8 + 1;
+        self.items.push(ClubRanking {
+            tag: String::from(tag),
+            name: String::from(name),
+            trophies,
+            rank,
+            ..ClubRanking::default()
+        });
+        self
+    }
+
+    /// Adds a ranking to the leaderboard, as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the leaderboard already has 200 rankings.
+    pub fn with_ranking(mut self, ranking: ClubRanking) -> ClubLeaderboardBuilder {
+        assert_room(self.items.len());
+        self.items.push(ranking);
+        self
+    }
+
+    /// Returns the built [`ClubLeaderboard`].
+    ///
+    /// [`ClubLeaderboard`]: ../rankings/clubs/struct.ClubLeaderboard.html
+    pub fn build(self) -> ClubLeaderboard {
+        ClubLeaderboard {
+            country_code: self.country_code,
+            limit: self.limit.unwrap_or(self.items.len() as u8),
+            items: self.items,
+            paging: None,
+        }
+    }
+}
+
+/// A builder for [`BrawlerLeaderboard`] instances of a brawler, which starts with the `"global"`
+/// country code and no rankings. Unless set through [`with_limit`], the built leaderboard's
+/// `limit` is its amount of rankings. Like the API's, it can have at most 200 rankings.
+///
+/// # Examples
+///
+/// ```rust
+/// use brawl_api::{Brawlers, BrawlerId};
+/// use brawl_api::model::BrawlerLeaderboardBuilder;
+///
+/// let leaderboard = BrawlerLeaderboardBuilder::new(BrawlerId::from(Brawlers::Shelly))
+///     .with_player("#ABC", "First", 1500)
+///     .build();
+///
+/// assert_eq!(leaderboard.brawler_id, BrawlerId::from(Brawlers::Shelly));
+/// assert_eq!(leaderboard.limit, 1);
+/// ```
+///
+/// [`BrawlerLeaderboard`]: ../rankings/brawlers/struct.BrawlerLeaderboard.html
+/// [`with_limit`]: #method.with_limit
+#[cfg(feature = "rankings")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrawlerLeaderboardBuilder {
+    country_code: String,
+    brawler_id: BrawlerId,
+    limit: Option<u8>,
+    items: Vec<PlayerRanking>,
+}
+
+#[cfg(feature = "rankings")]
+impl BrawlerLeaderboardBuilder {
+    /// Creates a new `BrawlerLeaderboardBuilder`, for the global leaderboard of the given
+    /// brawler.
+    pub fn new(brawler_id: BrawlerId) -> BrawlerLeaderboardBuilder {
+        BrawlerLeaderboardBuilder {
+            country_code: String::from("global"), brawler_id, limit: None, items: Vec::new()
+        }
+    }
+
+    /// Sets the leaderboard's country code (or `"global"`).
+    pub fn with_country_code(mut self, country_code: &str) -> BrawlerLeaderboardBuilder {
+        self.country_code = String::from(country_code);
+        self
+    }
+
+    /// Sets the leaderboard's limit of rankings (clamped to 200, the greatest the API accepts).
+    pub fn with_limit(mut self, limit: u8) -> BrawlerLeaderboardBuilder {
+        self.limit = Some(limit.min(MAX_LIMIT as u8));
+        self
+    }
+
+    /// Adds a player with the given tag, name and trophies (with the brawler) at the next rank,
+    /// with default values otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the leaderboard already has 200 rankings.
+    pub fn with_player(
+        mut self, tag: &str, name: &str, trophies: usize
+    ) -> BrawlerLeaderboardBuilder {
+        assert_room(self.items.len());
+        let rank = self.items.len() as u8 + 1;
+        self.items.push(player_ranking(rank, tag, name, trophies));
+        self
+    }
+
+    /// Adds a ranking to the leaderboard, as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the leaderboard already has 200 rankings.
+    pub fn with_ranking(mut self, ranking: PlayerRanking) -> BrawlerLeaderboardBuilder {
+        assert_room(self.items.len());
+        self.items.push(ranking);
+        self
+    }
+
+    /// Returns the built [`BrawlerLeaderboard`].
+    ///
+    /// [`BrawlerLeaderboard`]: ../rankings/brawlers/struct.BrawlerLeaderboard.html
+    pub fn build(self) -> BrawlerLeaderboard {
+        BrawlerLeaderboard {
+            country_code: self.country_code,
+            brawler_id: self.brawler_id,
+            limit: self.limit.unwrap_or(self.items.len() as u8),
+            items: self.items,
+            paging: None,
+        }
+    }
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(test, feature = "players", feature = "clubs"))]
+mod tests {
+    use super::{PlayerBuilder, ClubBuilder, BattleBuilder};
+    use crate::model::players::{Player, battlelog::Battle};
+    use crate::model::clubs::{Club, ClubMemberRole};
+
+    /// Tests that builders without any changes give the models' default values.
+    #[test]
+    fn builders_match_defaults() {
+        assert_eq!(PlayerBuilder::new().build(), Player::default());
+        assert_eq!(ClubBuilder::new().build(), Club::default());
+        assert_eq!(BattleBuilder::new().build(), Battle::default());
+    }
+
+    /// Tests that club members keep the club's tag and the order they were added in.
+    #[test]
+    fn club_builder_members() {
+        let club = ClubBuilder::new()
+            .with_member("#ABC", ClubMemberRole::Senior, 10)
+            .with_tag("#CLUB")
+            .with_member("#DEF", ClubMemberRole::President, 20)
+            .build();
+
+        assert_eq!(club.members.tag, "#CLUB");
+        let tags: Vec<&str> = club.members.iter().map(|m| m.tag.as_str()).collect();
+        assert_eq!(tags, ["#ABC", "#DEF"]);
+        assert_eq!(club.members[1].name_color, 0xffffff);
+    }
+
+    /// Tests that leaderboards can be built up to 200 rankings, and that limits are clamped to 200.
+    #[cfg(feature = "rankings")]
+    #[test]
+    fn leaderboard_builders_max_rankings() {
+        use super::{PlayerLeaderboardBuilder, ClubLeaderboardBuilder};
+        use crate::model::rankings::PlayerRanking;
+
+        let (players, clubs) = (0..200).fold(
+            (PlayerLeaderboardBuilder::new(), ClubLeaderboardBuilder::new()),
+            |(players, clubs), _| (
+                players.with_player("#ABC", "Player", 1000),
+                clubs.with_club("#CLUB", "Club", 1000),
+            ),
+        );
+        let (players, clubs) = (players.build(), clubs.build());
+
+        assert_eq!((players.limit, clubs.limit), (200, 200));
+        assert_eq!((players[0].rank, players[199].rank), (1, 200));
+        assert_eq!((clubs[0].rank, clubs[199].rank), (1, 200));
+        assert_eq!(players[0].name_color, PlayerRanking::default().name_color);
+
+        assert_eq!(PlayerLeaderboardBuilder::new().with_limit(255).build().limit, 200);
+    }
+
+    /// Tests that a leaderboard can't have more than 200 rankings.
+    #[cfg(feature = "rankings")]
+    #[test]
+    #[should_panic(expected = "more than 200 rankings")]
+    fn leaderboard_builders_past_max_rankings() {
+        use super::ClubLeaderboardBuilder;
+
+        (0..=200).fold(ClubLeaderboardBuilder::new(), |clubs, _| clubs.with_club("#C", "C", 0));
+    }
+}
@@ -4,25 +4,46 @@
 #[cfg(feature = "async")]
 use async_trait::async_trait;
 
+#[cfg(feature = "async")]
+use std::borrow::Borrow;
+
+#[cfg(feature = "async")]
+use futures_util::stream::Stream;
+
 use serde::{self, Serialize, Deserialize};
 
-use crate::traits::{PropFetchable, FetchFrom, GetFetchProp};
+use crate::traits::{PropFetchable, GetFetchProp, Endpoint};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::error::Result;
 
-#[cfg(feature = "async")]
-use crate::util::a_fetch_route;
+#[cfg(any(feature = "players", feature = "rankings"))]
+use crate::traits::FetchFrom;
+
+#[cfg(all(feature = "players", any(feature = "blocking", feature = "ureq", feature = "async")))]
+use crate::error::Error;
 
 #[cfg(feature = "players")]
-use super::players::PlayerClub;
+use super::players::{Player, PlayerClub};
+
+#[cfg(all(feature = "async", feature = "players"))]
+use super::players::BattleLog;
+
+use super::common::PlayerIcon;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::http::Client;
+
 use crate::serde::{
     serialize_smt_pointer, deserialize_number_from_string, deserialize_default_smt_pointer,
-    oxffffff_default,
+    serialize_hex_string, oxffffff_default,
 };
 use crate::http::routes::Route;
-use crate::util::{auto_hashtag, fetch_route};
+use crate::util::auto_hashtag;
 
 use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "rankings")]
 use crate::model::rankings::ClubRanking;
 use std::cmp::Ordering;
 
@@ -84,6 +105,7 @@ pub struct Club {
 
     /// The club's description.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
     /// The club's trophies.
@@ -109,7 +131,15 @@ pub struct Club {
     /// [`ClubType`]: ./enum.ClubType.html
     #[serde(rename = "type")]
     #[serde(default)]
-    pub club_type: ClubType
+    pub club_type: ClubType,
+
+    /// The id of the club's badge (an arbitrary number).
+    #[serde(default)]
+    pub badge_id: usize,
+
+    /// Whether or not the club is marked as family-friendly.
+    #[serde(default)]
+    pub is_family_friendly: bool,
 }
 
 impl Default for Club {
@@ -132,6 +162,8 @@ impl Default for Club {
     ///         required_trophies: 0,
     ///         members: ClubMembers::default(),
     ///         club_type: ClubType::Open,
+    ///         badge_id: 0,
+    ///         is_family_friendly: false,
     ///     }
     /// );
     /// ```
@@ -144,10 +176,30 @@ impl Default for Club {
             required_trophies: 0,
             members: ClubMembers::default(),
             club_type: ClubType::Open,
+            badge_id: 0,
+            is_family_friendly: false,
         }
     }
 }
 
+impl Club {
+    /// Returns the amount of members in this club.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::{Club, ClubMember};
+    ///
+    /// let mut club = Club::default();
+    /// club.members.push(ClubMember::default());
+    ///
+    /// assert_eq!(club.member_count(), 1);
+    /// ```
+    pub fn member_count(&self) -> usize {
+        self.members.len()
+    }
+}
+
 impl GetFetchProp for Club {
     type Property = str;
 
@@ -156,6 +208,19 @@ impl GetFetchProp for Club {
     fn get_route(tag: &str) -> Route { Route::Club(auto_hashtag(tag)) }
 }
 
+impl Endpoint for Club {
+    type Params = str;
+
+    fn route(tag: &str) -> Route { <Club as GetFetchProp>::get_route(tag) }
+
+    /// Sets the artificial [`ClubMembers.tag`] field of the club's members to the club's tag.
+    ///
+    /// [`ClubMembers.tag`]: members/struct.ClubMembers.html#structfield.tag
+    fn on_fetched(&mut self, _tag: &str) {
+        self.members.tag = self.tag.clone();
+    }
+}
+
 #[cfg_attr(feature = "async", async_trait)]
 impl PropFetchable for Club {
     type Property = str;
@@ -190,11 +255,9 @@ impl PropFetchable for Club {
     /// [`Error::Status`]: error/enum.Error.html#variant.Status
     /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
     /// [`Error::Json`]: error/enum.Error.html#variant.Json
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch(client: &Client, tag: &str) -> Result<Club> {
-        let route = Club::get_route(tag);
-        let mut club = fetch_route::<Club>(client, &route)?;
-        club.members.tag = club.tag.clone();
-        Ok(club)
+        client.get::<Club>(tag)
     }
 
     /// (Async) Fetches a club from its tag.
@@ -232,10 +295,46 @@ impl PropFetchable for Club {
         where Self: 'async_trait,
               Self::Property: 'async_trait,
     {
-        let route = Club::get_route(tag);
-        let mut club = a_fetch_route::<Club>(client, &route).await?;
-        club.members.tag = club.tag.clone();
-        Ok(club)
+        client.a_get::<Club>(tag).await
+    }
+}
+
+#[cfg(feature = "async")]
+impl Club {
+    /// (Async) Fetches many clubs concurrently, given their tags, keeping at most
+    /// `concurrency` requests in-flight at once.
+    ///
+    /// Returns a `Stream` of `(tag, result)` pairs, yielded in completion order. Each fetch
+    /// may fail independently (see [`Club::a_fetch`] for possible errors) without stopping
+    /// the others. This is equivalent to [`Client::a_get_many`] with `Club`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, Club, traits::*};
+    /// use futures::stream::StreamExt;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let tags = vec!["#CLUB1", "#CLUB2", "#CLUB3"];
+    ///
+    /// let results: Vec<_> = Club::a_fetch_many(&my_client, tags, 5).collect().await;
+    /// // each element is a (tag, Result<Club>) pair
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Club::a_fetch`]: #method.a_fetch
+    /// [`Client::a_get_many`]: ../../http/client/struct.Client.html#method.a_get_many
+    pub fn a_fetch_many<'a, I>(
+        client: &'a Client, tags: I, concurrency: usize
+    ) -> impl Stream<Item = (I::Item, Result<Club>)> + 'a
+        where I: IntoIterator,
+              I::IntoIter: 'a,
+              I::Item: Borrow<str> + 'a,
+    {
+        client.a_get_many::<Club, I>(tags, concurrency)
     }
 }
 
@@ -245,6 +344,7 @@ impl FetchFrom<PlayerClub> for Club {
     /// (Sync) Fetches a `Club` using data from a [`PlayerClub`] object.
     ///
     /// [`PlayerClub`]: ../../players/player/struct.PlayerClub.html
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(client: &Client, p_club: &PlayerClub) -> Result<Club> {
         Club::fetch(client, &p_club.tag)
     }
@@ -258,6 +358,52 @@ impl FetchFrom<PlayerClub> for Club {
     }
 }
 
+#[cfg_attr(feature = "async", async_trait)]
+#[cfg(feature = "players")]
+impl FetchFrom<Player> for Club {
+    /// (Sync) Fetches the club a given [`Player`] is in, using its [`club`] field.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in a club, an [`Error::FetchFrom`] is returned. Otherwise, see
+    /// [`Club::fetch`].
+    ///
+    /// [`Player`]: ../players/player/struct.Player.html
+    /// [`club`]: ../players/player/struct.Player.html#structfield.club
+    /// [`Error::FetchFrom`]: ../../error/enum.Error.html#variant.FetchFrom
+    /// [`Club::fetch`]: #method.fetch
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    fn fetch_from(client: &Client, player: &Player) -> Result<Club> {
+        match player.club {
+            Some(ref p_club) => Club::fetch_from(client, p_club),
+            None => Err(Error::FetchFrom(
+                format!("Player with tag {} is not in a club.", player.tag)
+            )),
+        }
+    }
+
+    /// (Async) Fetches the club a given [`Player`] is in, using its [`club`] field.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in a club, an [`Error::FetchFrom`] is returned. Otherwise, see
+    /// [`Club::a_fetch`].
+    ///
+    /// [`Player`]: ../players/player/struct.Player.html
+    /// [`club`]: ../players/player/struct.Player.html#structfield.club
+    /// [`Error::FetchFrom`]: ../../error/enum.Error.html#variant.FetchFrom
+    /// [`Club::a_fetch`]: #method.a_fetch
+    #[cfg(feature = "async")]
+    async fn a_fetch_from(client: &Client, player: &Player) -> Result<Club> {
+        match player.club {
+            Some(ref p_club) => Club::a_fetch_from(client, p_club).await,
+            None => Err(Error::FetchFrom(
+                format!("Player with tag {} is not in a club.", player.tag)
+            )),
+        }
+    }
+}
+
 #[cfg_attr(feature = "async", async_trait)]
 #[cfg(feature = "rankings")]
 impl FetchFrom<ClubRanking> for Club {
@@ -265,6 +411,7 @@ impl FetchFrom<ClubRanking> for Club {
     /// (Sync) Fetches a `Club` using data from a [`ClubRanking`] object.
     ///
     /// [`ClubRanking`]: ../../rankings/clubs/struct.ClubRanking.html
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(client: &Client, c_ranking: &ClubRanking) -> Result<Club> {
         Club::fetch(client, &c_ranking.tag)
     }
@@ -389,6 +536,12 @@ pub struct ClubMember {
     #[serde(default)]
     pub name: String,
 
+    /// The member's profile icon (see [`PlayerIcon`]).
+    ///
+    /// [`PlayerIcon`]: ../common/struct.PlayerIcon.html
+    #[serde(default)]
+    pub icon: PlayerIcon,
+
     /// The member's trophies.
     #[serde(default)]
     pub trophies: usize,
@@ -403,6 +556,7 @@ pub struct ClubMember {
     /// when the data is not available).
     #[serde(default = "oxffffff_default")]
     #[serde(deserialize_with = "deserialize_number_from_string")]  // parse num
+    #[serde(serialize_with = "serialize_hex_string")]  // "0x..." str, like the API
     pub name_color: u64
 }
 
@@ -459,13 +613,14 @@ impl Default for ClubMember {
     /// # Examples
     ///
     /// ```rust
-    /// use brawl_api::model::{ClubMember, ClubMemberRole};
+    /// use brawl_api::model::{ClubMember, ClubMemberRole, PlayerIcon};
     ///
     /// assert_eq!(
     ///     ClubMember::default(),
     ///     ClubMember {
     ///         tag: String::from(""),
     ///         name: String::from(""),
+    ///         icon: PlayerIcon::default(),
     ///         trophies: 0,
     ///         role: ClubMemberRole::default(),
     ///         name_color: 0xff_ff_ff
@@ -476,6 +631,7 @@ impl Default for ClubMember {
         ClubMember {
             tag: String::from(""),
             name: String::from(""),
+            icon: PlayerIcon::default(),
             trophies: 0,
             role: ClubMemberRole::default(),
             name_color: 0xff_ff_ff
@@ -488,6 +644,7 @@ impl Default for ClubMember {
 pub mod members {
     use super::*;
     use std::ops::{Deref, DerefMut};
+    use super::super::common::Paging;
 
     /// Represents a list of Club members, without relating to a previous [`Club`] object.
     /// This is only used if one does not want to fetch full club data, but only its members.
@@ -504,7 +661,15 @@ pub mod members {
 
         /// The fetched members of the specified club.
         #[serde(default)]
-        pub items: Vec<ClubMember>
+        pub items: Vec<ClubMember>,
+
+        /// The paging information given by the API along with the members, if any (see
+        /// [`Paging`]).
+        ///
+        /// [`Paging`]: ../../common/struct.Paging.html
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub paging: Option<Paging>,
     }
 
     impl Deref for ClubMembers {
@@ -577,6 +742,21 @@ pub mod members {
         }
     }
 
+    impl Endpoint for ClubMembers {
+        type Params = str;
+
+        fn route(tag: &str) -> Route {
+            <ClubMembers as GetFetchProp>::get_route(tag)
+        }
+
+        /// Sets the artificial [`tag`] field to the tag used for fetching.
+        ///
+        /// [`tag`]: #structfield.tag
+        fn on_fetched(&mut self, tag: &str) {
+            self.tag = tag.to_owned();
+        }
+    }
+
     impl From<Club> for ClubMembers {
         /// Simply returns a given [`Club`]'s [`members`][Club.members] field.
         ///
@@ -645,11 +825,9 @@ pub mod members {
         /// [`Error::Status`]: error/enum.Error.html#variant.Status
         /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
         /// [`Error::Json`]: error/enum.Error.html#variant.Json
+        #[cfg(any(feature = "blocking", feature = "ureq"))]
         fn fetch(client: &Client, tag: &str) -> Result<ClubMembers> {
-            let route = Self::get_route(tag);
-            let mut members = fetch_route::<ClubMembers>(client, &route)?;
-            members.tag = tag.to_owned();
-            Ok(members)
+            client.get::<ClubMembers>(tag)
         }
 
         /// (Async) Fetches a club's members, given its tag, without fetching the rest of the data.
@@ -690,10 +868,62 @@ pub mod members {
             where Self: 'async_trait,
                   Self::Property: 'async_trait,
         {
-            let route = ClubMembers::get_route(tag);
-            let mut members = a_fetch_route::<ClubMembers>(client, &route).await?;
-            members.tag = tag.to_owned();
-            Ok(members)
+            client.a_get::<ClubMembers>(tag).await
+        }
+    }
+
+    #[cfg(all(feature = "async", feature = "players"))]
+    impl ClubMembers {
+        /// (Async) Fetches the full [`Player`] data of every member concurrently, keeping at
+        /// most `concurrency` requests in-flight at once.
+        ///
+        /// Returns a `Stream` of `(tag, result)` pairs, yielded in completion order. Each fetch
+        /// may fail independently without stopping the others (see [`Player::a_fetch_many`]).
+        ///
+        /// # Examples
+        ///
+        /// ```rust,ignore
+        /// use brawl_api::{Client, ClubMembers, traits::*};
+        /// use futures::stream::StreamExt;
+        ///
+        /// # async fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+        /// let my_client = Client::new("my auth token");
+        /// let members = ClubMembers::a_fetch(&my_client, "#CLUBTAGHERE").await?;
+        ///
+        /// let mut players = members.a_fetch_players(&my_client, 5);
+        /// while let Some((tag, result)) = players.next().await {
+        ///     // ...
+        /// }
+        ///
+        /// #     Ok(())
+        /// # }
+        /// ```
+        ///
+        /// [`Player`]: ../../players/player/struct.Player.html
+        /// [`Player::a_fetch_many`]: ../../players/player/struct.Player.html#method.a_fetch_many
+        pub fn a_fetch_players<'a>(
+            &self, client: &'a Client, concurrency: usize
+        ) -> impl Stream<Item = (String, Result<Player>)> + 'a {
+            Player::a_fetch_many(client, self.member_tags(), concurrency)
+        }
+
+        /// (Async) Fetches the [`BattleLog`] of every member concurrently, keeping at most
+        /// `concurrency` requests in-flight at once.
+        ///
+        /// Returns a `Stream` of `(tag, result)` pairs, yielded in completion order. Each fetch
+        /// may fail independently without stopping the others (see [`BattleLog::a_fetch_many`]).
+        ///
+        /// [`BattleLog`]: ../../players/battlelog/struct.BattleLog.html
+        /// [`BattleLog::a_fetch_many`]: ../../players/battlelog/struct.BattleLog.html#method.a_fetch_many
+        pub fn a_fetch_battlelogs<'a>(
+            &self, client: &'a Client, concurrency: usize
+        ) -> impl Stream<Item = (String, Result<BattleLog>)> + 'a {
+            BattleLog::a_fetch_many(client, self.member_tags(), concurrency)
+        }
+
+        /// Collects the tags of all members, in order.
+        fn member_tags(&self) -> Vec<String> {
+            self.items.iter().map(|member| member.tag.clone()).collect()
         }
     }
 
@@ -710,11 +940,12 @@ pub mod members {
         ///     ClubMembers {
         ///         tag: String::from(""),
         ///         items: vec![],
+        ///         paging: None,
         ///     }
         /// );
         /// ```
         fn default() -> ClubMembers {
-            ClubMembers { tag: String::from(""), items: vec![] }
+            ClubMembers { tag: String::from(""), items: vec![], paging: None }
         }
     }
 }
@@ -726,6 +957,7 @@ mod tests {
     use std::result::Result as StdResult;
     use super::*;
     use crate::error::Error as BrawlError;
+    use crate::model::common::Paging;
     use serde_json;
 
     /// Tests for club deserialization from API-provided JSON.
@@ -738,11 +970,16 @@ mod tests {
   "type": "open",
   "requiredTrophies": 1000,
   "trophies": 60000,
+  "badgeId": 8000012,
+  "isFamilyFriendly": true,
   "members": [
     {
       "tag": "#PPP200JJJ",
       "name": "Member #1",
       "nameColor": "0xffff8afb",
+      "icon": {
+        "id": 28000010
+      },
       "role": "vicePresident",
       "trophies": 500
     },
@@ -750,6 +987,9 @@ mod tests {
       "tag": "#CCCCCCCCCC",
       "name": "Member #2",
       "nameColor": "0xff1ba5f5",
+      "icon": {
+        "id": 28000000
+      },
       "role": "president",
       "trophies": 200
     },
@@ -757,6 +997,9 @@ mod tests {
       "tag": "#VVVVVVVVV",
       "name": "Member #3",
       "nameColor": "0xffffff",
+      "icon": {
+        "id": 28000001
+      },
       "role": "member",
       "trophies": 8500
     },
@@ -764,6 +1007,9 @@ mod tests {
       "tag": "#9999999999",
       "name": "Member #4",
       "nameColor": "0xff4ddba2",
+      "icon": {
+        "id": 28000042
+      },
       "role": "member",
       "trophies": 20000
     },
@@ -771,6 +1017,9 @@ mod tests {
       "tag": "#UUUUUU888",
       "name": "Member #5",
       "nameColor": "0xff1ba5f5",
+      "icon": {
+        "id": 28000003
+      },
       "role": "senior",
       "trophies": 4500
     },
@@ -778,6 +1027,9 @@ mod tests {
       "tag": "#JJJJJJJJJ",
       "name": "Member ██▬█",
       "nameColor": "0xff1ba5f5",
+      "icon": {
+        "id": 28000000
+      },
       "role": "member",
       "trophies": 26300
     }
@@ -796,12 +1048,15 @@ mod tests {
                 club_type: ClubType::Open,
                 required_trophies: 1000,
                 trophies: 60000,
+                badge_id: 8000012,
+                is_family_friendly: true,
                 members: ClubMembers {
                     items: vec![
                         ClubMember {
                             tag: String::from("#PPP200JJJ"),
                             name: String::from("Member #1"),
                             name_color: 0xffff8afb,
+                            icon: PlayerIcon { id: 28000010 },
                             role: ClubMemberRole::VicePresident,
                             trophies: 500
                         },
@@ -809,6 +1064,7 @@ mod tests {
                             tag: String::from("#CCCCCCCCCC"),
                             name: String::from("Member #2"),
                             name_color: 0xff1ba5f5,
+                            icon: PlayerIcon { id: 28000000 },
                             role: ClubMemberRole::President,
                             trophies: 200
                         },
@@ -816,6 +1072,7 @@ mod tests {
                             tag: String::from("#VVVVVVVVV"),
                             name: String::from("Member #3"),
                             name_color: 0xffffff,
+                            icon: PlayerIcon { id: 28000001 },
                             role: ClubMemberRole::Member,
                             trophies: 8500
                         },
@@ -823,6 +1080,7 @@ mod tests {
                             tag: String::from("#9999999999"),
                             name: String::from("Member #4"),
                             name_color: 0xff4ddba2,
+                            icon: PlayerIcon { id: 28000042 },
                             role: ClubMemberRole::Member,
                             trophies: 20000
                         },
@@ -830,6 +1088,7 @@ mod tests {
                             tag: String::from("#UUUUUU888"),
                             name: String::from("Member #5"),
                             name_color: 0xff1ba5f5,
+                            icon: PlayerIcon { id: 28000003 },
                             role: ClubMemberRole::Senior,
                             trophies: 4500
                         },
@@ -837,6 +1096,7 @@ mod tests {
                             tag: String::from("#JJJJJJJJJ"),
                             name: String::from("Member ██▬█"),
                             name_color: 0xff1ba5f5,
+                            icon: PlayerIcon { id: 28000000 },
                             role: ClubMemberRole::Member,
                             trophies: 26300
                         }
@@ -846,6 +1106,67 @@ mod tests {
             }
         );
 
+        // serializing gives back the API's JSON
+        assert_eq!(
+            serde_json::to_value(&club)?,
+            serde_json::from_str::<serde_json::Value>(club_json_s)?
+        );
+
+        Ok(())
+    }
+
+    /// Tests that a club whose JSON lacks the badge, family-friendly flag and member icons falls
+    /// back to the documented defaults for them.
+    #[test]
+    fn club_deser_defaults() -> StdResult<(), Box<dyn ::std::error::Error>> {
+        let club_json_s = r##"{
+  "tag": "#GGGGGGG",
+  "name": "Club",
+  "type": "inviteOnly",
+  "requiredTrophies": 0,
+  "trophies": 500,
+  "members": [
+    {
+      "tag": "#PPP200JJJ",
+      "name": "Member #1",
+      "nameColor": "0xffff8afb",
+      "role": "president",
+      "trophies": 500
+    }
+  ]
+}"##;
+
+        let club: Club = serde_json::from_str::<Club>(club_json_s)
+            .map_err(BrawlError::Json)?;
+
+        assert_eq!(club.badge_id, 0);
+        assert!(!club.is_family_friendly);
+        assert_eq!(club.members[0].icon, PlayerIcon::default());
+
+        assert_eq!(
+            club,
+            Club {
+                tag: String::from("#GGGGGGG"),
+                name: String::from("Club"),
+                club_type: ClubType::InviteOnly,
+                trophies: 500,
+                members: ClubMembers {
+                    items: vec![
+                        ClubMember {
+                            tag: String::from("#PPP200JJJ"),
+                            name: String::from("Member #1"),
+                            name_color: 0xffff8afb,
+                            role: ClubMemberRole::President,
+                            trophies: 500,
+                            ..ClubMember::default()
+                        }
+                    ],
+                    ..ClubMembers::default()
+                },
+                ..Club::default()
+            }
+        );
+
         Ok(())
     }
 
@@ -858,6 +1179,9 @@ mod tests {
       "tag": "#PPP200JJJ",
       "name": "Member #1",
       "nameColor": "0xffff8afb",
+      "icon": {
+        "id": 28000010
+      },
       "role": "vicePresident",
       "trophies": 500
     },
@@ -865,6 +1189,9 @@ mod tests {
       "tag": "#CCCCCCCCCC",
       "name": "Member #2",
       "nameColor": "0xff1ba5f5",
+      "icon": {
+        "id": 28000000
+      },
       "role": "president",
       "trophies": 200
     },
@@ -872,6 +1199,9 @@ mod tests {
       "tag": "#VVVVVVVVV",
       "name": "Member #3",
       "nameColor": "0xffffff",
+      "icon": {
+        "id": 28000001
+      },
       "role": "member",
       "trophies": 8500
     },
@@ -879,6 +1209,9 @@ mod tests {
       "tag": "#9999999999",
       "name": "Member #4",
       "nameColor": "0xff4ddba2",
+      "icon": {
+        "id": 28000042
+      },
       "role": "member",
       "trophies": 20000
     },
@@ -886,6 +1219,9 @@ mod tests {
       "tag": "#UUUUUU888",
       "name": "Member #5",
       "nameColor": "0xff1ba5f5",
+      "icon": {
+        "id": 28000003
+      },
       "role": "senior",
       "trophies": 4500
     },
@@ -893,6 +1229,9 @@ mod tests {
       "tag": "#JJJJJJJJJ",
       "name": "Member ██▬█",
       "nameColor": "0xff1ba5f5",
+      "icon": {
+        "id": 28000000
+      },
       "role": "member",
       "trophies": 26300
     }
@@ -912,6 +1251,7 @@ mod tests {
                         tag: String::from("#PPP200JJJ"),
                         name: String::from("Member #1"),
                         name_color: 0xffff8afb,
+                        icon: PlayerIcon { id: 28000010 },
                         role: ClubMemberRole::VicePresident,
                         trophies: 500
                     },
@@ -919,6 +1259,7 @@ mod tests {
                         tag: String::from("#CCCCCCCCCC"),
                         name: String::from("Member #2"),
                         name_color: 0xff1ba5f5,
+                        icon: PlayerIcon { id: 28000000 },
                         role: ClubMemberRole::President,
                         trophies: 200
                     },
@@ -926,6 +1267,7 @@ mod tests {
                         tag: String::from("#VVVVVVVVV"),
                         name: String::from("Member #3"),
                         name_color: 0xffffff,
+                        icon: PlayerIcon { id: 28000001 },
                         role: ClubMemberRole::Member,
                         trophies: 8500
                     },
@@ -933,6 +1275,7 @@ mod tests {
                         tag: String::from("#9999999999"),
                         name: String::from("Member #4"),
                         name_color: 0xff4ddba2,
+                        icon: PlayerIcon { id: 28000042 },
                         role: ClubMemberRole::Member,
                         trophies: 20000
                     },
@@ -940,6 +1283,7 @@ mod tests {
                         tag: String::from("#UUUUUU888"),
                         name: String::from("Member #5"),
                         name_color: 0xff1ba5f5,
+                        icon: PlayerIcon { id: 28000003 },
                         role: ClubMemberRole::Senior,
                         trophies: 4500
                     },
@@ -947,15 +1291,87 @@ mod tests {
                         tag: String::from("#JJJJJJJJJ"),
                         name: String::from("Member ██▬█"),
                         name_color: 0xff1ba5f5,
+                        icon: PlayerIcon { id: 28000000 },
                         role: ClubMemberRole::Member,
                         trophies: 26300
                     }
                 ],
+                paging: Some(Paging::default()),
                 ..ClubMembers::default()
             }
         );
 
+        // serializing gives back the API's JSON
+        assert_eq!(
+            serde_json::to_value(&club_members)?,
+            serde_json::from_str::<serde_json::Value>(cm_json_s)?
+        );
+
         Ok(())
     }
-}
 
+    /// Tests that fetching many clubs, and the players and battle logs of club members, keeps
+    /// the given concurrency, and that a tag which fails to be fetched doesn't stop the others.
+    #[cfg(all(feature = "async", feature = "players"))]
+    #[test]
+    fn club_members_a_fetch_many() {
+        use std::time::Duration;
+        use futures_util::stream::StreamExt;
+        use crate::http::test_server::{TestServer, block_on};
+        use crate::model::players::Player;
+
+        let server = TestServer::start(Duration::from_millis(30), |path| {
+            let mut segments = path.trim_start_matches('/').split('/');
+            let (kind, tag) = (segments.next().unwrap(), segments.next().unwrap());
+            let tag = tag.replace("%23", "#");
+
+            let body = match (kind, segments.next()) {
+                _ if tag == "#BAD" => return (404, String::from(r#"{"reason": "notFound"}"#)),
+                ("players", Some("battlelog")) => Ok(String::from(r#"{"items": []}"#)),
+                ("players", _) => serde_json::to_string(&Player { tag, ..Player::default() }),
+                _ => serde_json::to_string(&Club { tag, ..Club::default() }),
+            };
+            (200, body.unwrap())
+        });
+        let client = Client::new("my auth key").with_middleware(server.clone());
+
+        let tags = ["#AAA", "#BAD", "#CCC", "#GGG", "#JJJ"];
+        let members = ClubMembers {
+            items: tags.iter()
+                .map(|&tag| ClubMember { tag: String::from(tag), ..ClubMember::default() })
+                .collect(),
+            ..ClubMembers::default()
+        };
+
+        let players: Vec<_> = block_on(members.a_fetch_players(&client, 2).collect());
+        let battlelogs: Vec<_> = block_on(members.a_fetch_battlelogs(&client, 2).collect());
+        let clubs: Vec<_> = block_on(Club::a_fetch_many(&client, tags.to_vec(), 2).collect());
+
+        assert_eq!(server.total(), 3 * tags.len());
+        assert!(server.peak() <= 2, "{} requests were in-flight at once", server.peak());
+
+        let check = |tag: &str, fetched: StdResult<String, BrawlError>| match fetched {
+            Ok(fetched_tag) => assert_eq!(fetched_tag, tag),
+            Err(BrawlError::Status(status, ..)) => {
+                assert_eq!(tag, "#BAD");
+                assert_eq!(status.as_u16(), 404);
+            },
+            Err(err) => panic!("Unexpected error for {}: {:?}", tag, err),
+        };
+
+        assert_eq!(players.len(), tags.len());
+        for (tag, result) in players {
+            check(&tag, result.map(|player| player.tag));
+        }
+
+        assert_eq!(battlelogs.len(), tags.len());
+        for (tag, result) in battlelogs {
+            check(&tag, result.map(|_| tag.clone()));
+        }
+
+        assert_eq!(clubs.len(), tags.len());
+        for (tag, result) in clubs {
+            check(tag, result.map(|club| club.tag));
+        }
+    }
+}
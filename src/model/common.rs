@@ -3,6 +3,9 @@
 
 use serde::{self, Serialize, Deserialize};
 
+#[cfg(any(feature = "players", feature = "brawlers"))]
+use crate::ids::{StarPowerId, GadgetId};
+
 /// A struct representing a brawler's star power. Note that, if **both** `players` and `brawlers`
 /// features are turned off, then this struct is also removed (it is required by both, so if neither
 /// are enabled anymore, this isn't either).
@@ -14,11 +17,14 @@ pub struct StarPower {
     #[serde(default)]
     pub name: String,
 
-    /// The star power's id (an arbitrary number).
+    /// The star power's id (see [`StarPowerId`]).
+    ///
+    /// [`StarPowerId`]: ../../ids/struct.StarPowerId.html
     #[serde(default)]
-    pub id: usize
+    pub id: StarPowerId
 }
 
+#[cfg(any(feature = "players", feature = "brawlers"))]
 impl Default for StarPower {
 
     /// Returns an instance of `StarPower` with initial values.
@@ -26,20 +32,189 @@ impl Default for StarPower {
     /// # Examples
     ///
     /// ```rust
-    /// use brawl_api::StarPower;
+    /// use brawl_api::{StarPower, StarPowerId};
     ///
     /// assert_eq!(
     ///     StarPower::default(),
     ///     StarPower {
     ///         name: String::from(""),
-    ///         id: 0,
+    ///         id: StarPowerId::default(),
     ///     }
     /// );
     /// ```
     fn default() -> StarPower {
         StarPower {
             name: String::from(""),
+            id: StarPowerId::default()
+        }
+    }
+}
+
+/// A struct representing a brawler's gadget. Note that, just like [`StarPower`], if **both**
+/// `players` and `brawlers` features are turned off, then this struct is also removed.
+///
+/// [`StarPower`]: ./struct.StarPower.html
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg(any(feature = "players", feature = "brawlers"))]
+pub struct Gadget {
+
+    /// The gadget name.
+    #[serde(default)]
+    pub name: String,
+
+    /// The gadget's id (see [`GadgetId`]).
+    ///
+    /// [`GadgetId`]: ../../ids/struct.GadgetId.html
+    #[serde(default)]
+    pub id: GadgetId
+}
+
+#[cfg(any(feature = "players", feature = "brawlers"))]
+impl Default for Gadget {
+
+    /// Returns an instance of `Gadget` with initial values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::{Gadget, GadgetId};
+    ///
+    /// assert_eq!(
+    ///     Gadget::default(),
+    ///     Gadget {
+    ///         name: String::from(""),
+    ///         id: GadgetId::default(),
+    ///     }
+    /// );
+    /// ```
+    fn default() -> Gadget {
+        Gadget {
+            name: String::from(""),
+            id: GadgetId::default()
+        }
+    }
+}
+
+/// A struct representing a player's profile icon (avatar), as seen in [`Player.icon`] and
+/// [`ClubMember.icon`]. Note that, if the `players`, `clubs` and `rankings` features are all
+/// turned off, then this struct is also removed.
+///
+/// [`Player.icon`]: ../players/player/struct.Player.html#structfield.icon
+/// [`ClubMember.icon`]: ../clubs/struct.ClubMember.html#structfield.icon
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg(any(feature = "players", feature = "clubs", feature = "rankings"))]
+pub struct PlayerIcon {
+
+    /// The icon's id (an arbitrary number).
+    #[serde(default)]
+    pub id: usize
+}
+
+#[cfg(any(feature = "players", feature = "clubs", feature = "rankings"))]
+impl Default for PlayerIcon {
+
+    /// Returns an instance of `PlayerIcon` with initial values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::PlayerIcon;
+    ///
+    /// assert_eq!(
+    ///     PlayerIcon::default(),
+    ///     PlayerIcon {
+    ///         id: 0,
+    ///     }
+    /// );
+    /// ```
+    fn default() -> PlayerIcon {
+        PlayerIcon {
             id: 0
         }
     }
 }
+
+/// A struct representing the paging information the API gives along with lists of items, as seen
+/// in leaderboards and in [`ClubMembers`]. Note that, if the `clubs` and `rankings` features are
+/// both turned off, then this struct is also removed.
+///
+/// [`ClubMembers`]: ../clubs/members/struct.ClubMembers.html
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg(any(feature = "clubs", feature = "rankings"))]
+pub struct Paging {
+
+    /// The markers of the previous and next pages, if there are any.
+    #[serde(default)]
+    pub cursors: PagingCursors
+}
+
+#[cfg(any(feature = "clubs", feature = "rankings"))]
+impl Default for Paging {
+
+    /// Returns an instance of `Paging` with initial values (no cursors).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::{Paging, PagingCursors};
+    ///
+    /// assert_eq!(
+    ///     Paging::default(),
+    ///     Paging {
+    ///         cursors: PagingCursors::default(),
+    ///     }
+    /// );
+    /// ```
+    fn default() -> Paging {
+        Paging {
+            cursors: PagingCursors::default()
+        }
+    }
+}
+
+/// A struct representing the markers of the previous and next pages of a list of items (see
+/// [`Paging`]), which can be given to [`Query::before`] and [`Query::after`].
+///
+/// [`Paging`]: ./struct.Paging.html
+/// [`Query::before`]: ../../http/query/struct.Query.html#method.before
+/// [`Query::after`]: ../../http/query/struct.Query.html#method.after
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg(any(feature = "clubs", feature = "rankings"))]
+pub struct PagingCursors {
+
+    /// The marker of the previous page, or `None` if this is the first page.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+
+    /// The marker of the next page, or `None` if this is the last page.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>
+}
+
+#[cfg(any(feature = "clubs", feature = "rankings"))]
+impl Default for PagingCursors {
+
+    /// Returns an instance of `PagingCursors` with initial values (neither cursor).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::PagingCursors;
+    ///
+    /// assert_eq!(
+    ///     PagingCursors::default(),
+    ///     PagingCursors {
+    ///         before: None,
+    ///         after: None,
+    ///     }
+    /// );
+    /// ```
+    fn default() -> PagingCursors {
+        PagingCursors {
+            before: None,
+            after: None
+        }
+    }
+}
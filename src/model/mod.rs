@@ -14,6 +14,15 @@
 //! A few may also implement `PartialOrd` and `Ord`, such as all `-Ranking` structs and the pair
 //! [`ClubMember`] and [`ClubMemberRole`].
 //!
+//! Serializing a model gives JSON in the API's shape: the same keys, name colors as `"0x..."`
+//! strings, and `Option` fields which are `None` (such as a player's club, when they're in none)
+//! omitted instead of `null`. Deserializing that JSON gives back an equal model. If the API's
+//! response had every field of the model, serializing gives back that exact JSON; however, models
+//! don't keep track of which fields were missing from the response, so those are serialized with
+//! their default values (e.g. a name color of `"0xffffff"` or an icon with id `0`). Data the
+//! API response doesn't have is not serialized, namely the tag in [`BattleLog`] and
+//! [`ClubMembers`], and the country code and limit of leaderboards.
+//!
 //! In addition, each endpoint has a different way of fetching, and is associated with a submodule:
 //!
 //! - `/players/:tag` -> [`Player::fetch`] (through the [`PropFetchable`] trait),
@@ -33,11 +42,17 @@
 //! - `/brawlers/` -> [`BrawlerList::fetch`] (direct implementation), [`model::brawlers`] module;
 //! - `/brawlers/:id` -> [`Brawler::fetch`] (direct implementation), [`model::brawlers`] module.
 //!
+//! Additionally, every model above implements the [`Endpoint`] trait, which allows fetching any of
+//! them through the generic [`Client::get`] method (e.g. `client.get::<Player>("#TAG")`) or through
+//! one of the `Client` convenience methods (e.g. `client.player("#TAG")`,
+//! `client.club_members("#TAG")`). All of the `fetch` functions listed are equivalent to those.
+//!
 //! [`serde::ser::Serialize`]: https://docs.rs/serde/*/ser/trait.Serialize.html
 //! [`serde::de::Deserialize`]: https://docs.rs/serde/*/de/trait.Deserialize.html
 //! [`BattleLog`]: ./players/battlelog/struct.BattleLog.html
 //! [`ClubMember`]: ./clubs/struct.ClubMember.html
 //! [`ClubMemberRole`]: ./clubs/enum.ClubMemberRole.html
+//! [`ClubMembers`]: ./clubs/members/struct.ClubMembers.html
 //! [`Player::fetch`]: ./players/player/struct.Player.html#method.fetch
 //! [`BattleLog::fetch`]: ./players/battlelog/struct.BattleLog.html#method.fetch
 //! [`Club::fetch`]: ./clubs/struct.Club.html#method.fetch
@@ -48,6 +63,8 @@
 //! [`BrawlerList::fetch`]: ./brawlers/struct.BrawlerList.html#method.fetch
 //! [`Brawler::fetch`]: ./brawlers/struct.Brawler.html#method.fetch
 //! [`PropFetchable`]: traits/propfetch/trait.PropFetchable.html
+//! [`Endpoint`]: traits/endpoint/trait.Endpoint.html
+//! [`Client::get`]: http/client/struct.Client.html#method.get
 //! [`PropLimFetchable`]: traits/proplimfetch/trait.PropLimFetchable.html
 //! [`model::players::player`]: ./players/player/index.html
 //! [`model::players::battlelog`]: ./players/battlelog/index.html
@@ -80,3 +97,8 @@ pub use rankings::*;
 pub mod brawlers;
 #[cfg(feature = "brawlers")]
 pub use brawlers::*;
+
+#[cfg(any(feature = "players", feature = "clubs", feature = "rankings"))]
+pub mod builders;
+#[cfg(any(feature = "players", feature = "clubs", feature = "rankings"))]
+pub use builders::*;
@@ -2,19 +2,31 @@
 //! Included by the feature `"players"`; removing that feature will disable the usage of this module.
 
 use std::ops::{Deref, DerefMut};
-use crate::traits::{GetFetchProp, PropFetchable, FetchFrom};
+use crate::traits::{GetFetchProp, PropFetchable, FetchFrom, Endpoint};
 use crate::http::routes::Route;
-use crate::util::{fetch_route, a_fetch_route, auto_hashtag};
+use crate::util::auto_hashtag;
 use serde::{self, Serialize, Deserialize};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::error::Result;
+
 use crate::serde::one_default;
 
 #[cfg(feature = "async")]
 use async_trait::async_trait;
+
+#[cfg(feature = "async")]
+use std::borrow::Borrow;
+
+#[cfg(feature = "async")]
+use futures_util::stream::Stream;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::http::Client;
 
 use super::player::Player;
 use crate::TimeLike;
+use crate::ids::{BrawlerId, EventId};
 
 // region:BattleLog
 
@@ -109,6 +121,21 @@ impl GetFetchProp for BattleLog {
     }
 }
 
+impl Endpoint for BattleLog {
+    type Params = str;
+
+    fn route(tag: &str) -> Route {
+        <BattleLog as GetFetchProp>::get_route(tag)
+    }
+
+    /// Sets the artificial [`tag`] field to the tag used for fetching.
+    ///
+    /// [`tag`]: #structfield.tag
+    fn on_fetched(&mut self, tag: &str) {
+        self.tag = tag.to_owned();
+    }
+}
+
 #[cfg_attr(feature = "async", async_trait)]
 impl FetchFrom<Player> for BattleLog {
     /// (Sync) Fetches a given player's battlelog (a `BattleLog` instance) by using data from
@@ -136,6 +163,7 @@ impl FetchFrom<Player> for BattleLog {
     ///
     /// [`Player`]: ../player/struct.Player.html
     /// [`BattleLog::fetch`]: #method.fetch
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(client: &Client, player: &Player) -> Result<BattleLog> {
         BattleLog::fetch(client, &player.tag)
     }
@@ -205,11 +233,9 @@ impl PropFetchable for BattleLog {
     /// [`Error::Status`]: error/enum.Error.html#variant.Status
     /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
     /// [`Error::Json`]: error/enum.Error.html#variant.Json
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch(client: &Client, tag: &str) -> Result<BattleLog> {
-        let route = Self::get_route(tag);
-        let mut battle_log = fetch_route::<BattleLog>(client, &route)?;
-        battle_log.tag = tag.to_owned();
-        Ok(battle_log)
+        client.get::<BattleLog>(tag)
     }
 
     /// (Async) Fetches a player's battlelog (most recent battles), given its tag.
@@ -247,10 +273,46 @@ impl PropFetchable for BattleLog {
         where Self: 'async_trait,
               Self::Property: 'async_trait,
     {
-        let route = BattleLog::get_route(tag);
-        let mut battle_log = a_fetch_route::<BattleLog>(client, &route).await?;
-        battle_log.tag = tag.to_owned();
-        Ok(battle_log)
+        client.a_get::<BattleLog>(tag).await
+    }
+}
+
+#[cfg(feature = "async")]
+impl BattleLog {
+    /// (Async) Fetches many players' battlelogs concurrently, given their tags, keeping at most
+    /// `concurrency` requests in-flight at once.
+    ///
+    /// Returns a `Stream` of `(tag, result)` pairs, yielded in completion order. Each fetch
+    /// may fail independently (see [`BattleLog::a_fetch`] for possible errors) without stopping
+    /// the others. This is equivalent to [`Client::a_get_many`] with `BattleLog`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, BattleLog, traits::*};
+    /// use futures::stream::StreamExt;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let tags = vec!["#PLAYER1", "#PLAYER2", "#PLAYER3"];
+    ///
+    /// let results: Vec<_> = BattleLog::a_fetch_many(&my_client, tags, 5).collect().await;
+    /// // each element is a (tag, Result<BattleLog>) pair
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`BattleLog::a_fetch`]: #method.a_fetch
+    /// [`Client::a_get_many`]: ../../../http/client/struct.Client.html#method.a_get_many
+    pub fn a_fetch_many<'a, I>(
+        client: &'a Client, tags: I, concurrency: usize
+    ) -> impl Stream<Item = (I::Item, Result<BattleLog>)> + 'a
+        where I: IntoIterator,
+              I::IntoIter: 'a,
+              I::Item: Borrow<str> + 'a,
+    {
+        client.a_get_many::<BattleLog, I>(tags, concurrency)
     }
 }
 
@@ -308,9 +370,11 @@ impl Default for Battle {
 /// [`Battle`]: struct.Battle.html
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BattleEvent {
-    /// The id of the event (an arbitrary number).
+    /// The id of the event (see [`EventId`]).
+    ///
+    /// [`EventId`]: ../../../ids/struct.EventId.html
     #[serde(default)]
-    pub id: usize,
+    pub id: EventId,
 
     /// The event mode (e.g. "brawlBall", "soloShowdown"...).
     #[serde(default)]
@@ -334,16 +398,16 @@ impl Default for BattleEvent {
     /// # Examples
     ///
     /// ```rust
-    /// use brawl_api::BattleEvent;
+    /// use brawl_api::{BattleEvent, EventId};
     ///
     /// assert_eq!(
     ///     BattleEvent::default(),
-    ///     BattleEvent { id: 0, mode: String::from(""), map: String::from("") }
+    ///     BattleEvent { id: EventId::default(), mode: String::from(""), map: String::from("") }
     /// )
     /// ```
     fn default() -> BattleEvent {
         BattleEvent {
-            id: 0,
+            id: EventId::default(),
             mode: String::from(""),
             map: String::from(""),
         }
@@ -403,6 +467,7 @@ pub struct BattleResultInfo {
     /// If this is `None`, then this is likely a weekend event.
     #[serde(default)]
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub battle_type: Option<String>,
 
     /// The duration of this battle, in seconds.
@@ -418,11 +483,13 @@ pub struct BattleResultInfo {
     /// If this was a solo mode match, then this is the player's final rank (1-10). Otherwise,
     /// `None`.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rank: Option<u8>,
 
     /// If this was a match with teams, then this is the outcome for the player
     /// (Victory/Defeat/Draw), otherwise `None`.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<BattleOutcome>,
 
     /// The data indicating who was the Star Player in the match. This is generally from the
@@ -430,6 +497,7 @@ pub struct BattleResultInfo {
     /// If this was a solo mode or boss fight match, for instance, then there is no star player
     /// (None).
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_player: Option<BattlePlayer>,
 
     /// If this was a match with teams, then this is a vector with all teams of players
@@ -437,11 +505,13 @@ pub struct BattleResultInfo {
     /// Duo Showdown, for example.
     /// Otherwise, `None`.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub teams: Option<Vec<Vec<BattlePlayer>>>,
 
     /// If this was a solo match or a mode without teams, such as Showdown, then this is a vector
     /// with all the players in the match. Otherwise, `None`.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub players: Option<Vec<BattlePlayer>>
 }
 
@@ -538,9 +608,11 @@ impl Default for BattlePlayer {
 /// [`BattlePlayer`]: ./struct.BattlePlayer.html
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BattleBrawler {
-    /// The brawler's id (an arbitrary number).
+    /// The brawler's id (see [`BrawlerId`]).
+    ///
+    /// [`BrawlerId`]: ../../../ids/struct.BrawlerId.html
     #[serde(default)]
-    pub id: usize,
+    pub id: BrawlerId,
 
     /// The brawler's name (e.g. "PENNY", "ROSA", "BROCK"...)
     #[serde(default)]
@@ -560,12 +632,12 @@ impl Default for BattleBrawler {
     /// # Examples
     ///
     /// ```rust
-    /// use brawl_api::BattleBrawler;
+    /// use brawl_api::{BattleBrawler, BrawlerId};
     ///
     /// assert_eq!(
     ///     BattleBrawler::default(),
     ///     BattleBrawler {
-    ///         id: 0,
+    ///         id: BrawlerId::default(),
     ///         name: String::from(""),
     ///         power: 1,
     ///         trophies: 0,
@@ -574,7 +646,7 @@ impl Default for BattleBrawler {
     /// ```
     fn default() -> BattleBrawler {
         BattleBrawler {
-            id: 0,
+            id: BrawlerId::default(),
             name: String::from(""),
             power: 1,
             trophies: 0,
@@ -588,6 +660,7 @@ impl Default for BattleBrawler {
 mod tests {
     use serde_json;
     use crate::time::TimeLike;
+    use crate::ids::{BrawlerId, EventId};
     use super::{
         BattleLog, BattleBrawler, BattlePlayer, Battle, BattleResultInfo, BattleEvent, BattleOutcome
     };
@@ -700,7 +773,7 @@ mod tests {
                     Battle {
                         battle_time: TimeLike(String::from("20200131T003432.000Z")),
                         event: BattleEvent {
-                            id: 15000163,
+                            id: EventId::new(15000163)?,
                             mode: String::from("brawlBall"),
                             map: String::from("Coarse Course")
                         },
@@ -714,7 +787,7 @@ mod tests {
                                 tag: String::from("#CCCCCCCC"),
                                 name: String::from("User"),
                                 brawler: BattleBrawler {
-                                    id: 16000008,
+                                    id: BrawlerId::new(16000008)?,
                                     name: String::from("NITA"),
                                     power: 10,
                                     trophies: 500
@@ -726,7 +799,7 @@ mod tests {
                                         tag: String::from("#CCCCCCCC"),
                                         name: String::from("User"),
                                         brawler: BattleBrawler {
-                                            id: 16000008,
+                                            id: BrawlerId::new(16000008)?,
                                             name: String::from("NITA"),
                                             power: 10,
                                             trophies: 500
@@ -736,7 +809,7 @@ mod tests {
                                         tag: String::from("#RRRAAALLL"),
                                         name: String::from("Other User"),
                                         brawler: BattleBrawler {
-                                            id: 16000001,
+                                            id: BrawlerId::new(16000001)?,
                                             name: String::from("COLT"),
                                             power: 8,
                                             trophies: 510
@@ -746,7 +819,7 @@ mod tests {
                                         tag: String::from("#GGGGGGGGG"),
                                         name: String::from("Another User"),
                                         brawler: BattleBrawler {
-                                            id: 16000018,
+                                            id: BrawlerId::new(16000018)?,
                                             name: String::from("DARRYL"),
                                             power: 10,
                                             trophies: 520
@@ -758,7 +831,7 @@ mod tests {
                                         tag: String::from("#777777777"),
                                         name: String::from("User User User"),
                                         brawler: BattleBrawler {
-                                            id: 16000032,
+                                            id: BrawlerId::new(16000032)?,
                                             name: String::from("MAX"),
                                             power: 10,
                                             trophies: 500
@@ -768,7 +841,7 @@ mod tests {
                                         tag: String::from("#SUVSUVSUV"),
                                         name: String::from("User.User?!"),
                                         brawler: BattleBrawler {
-                                            id: 16000024,
+                                            id: BrawlerId::new(16000024)?,
                                             name: String::from("ROSA"),
                                             power: 9,
                                             trophies: 400
@@ -778,7 +851,7 @@ mod tests {
                                         tag: String::from("#QCPJ09J"),
                                         name: String::from("пользователь"),
                                         brawler: BattleBrawler {
-                                            id: 16000028,
+                                            id: BrawlerId::new(16000028)?,
                                             name: String::from("SANDY"),
                                             power: 10,
                                             trophies: 450
@@ -793,6 +866,12 @@ mod tests {
             }
         );
 
+        // serializing gives back the API's JSON
+        assert_eq!(
+            serde_json::to_value(&battle_log)?,
+            serde_json::from_str::<serde_json::Value>(battlelog_json_s)?
+        );
+
         Ok(())
     }
 }
@@ -5,26 +5,38 @@ use serde::{self, Serialize, Deserialize};
 
 
 #[cfg(feature = "async")]
-
+use async_trait::async_trait;
 
 #[cfg(feature = "async")]
-use crate::util::a_fetch_route;
+use std::borrow::Borrow;
 
 #[cfg(feature = "async")]
-use async_trait::async_trait;
+use futures_util::stream::Stream;
+
+use crate::traits::{FetchFrom, PropFetchable, GetFetchProp, Endpoint};
 
-use crate::traits::{FetchFrom, PropFetchable, GetFetchProp};
-use crate::error::{Result};
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::error::Result;
 
 #[cfg(feature = "clubs")]
 use super::super::clubs::ClubMember;
 
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::http::Client;
+
 use crate::http::routes::Route;
-use crate::util::{auto_hashtag, fetch_route};
-use crate::serde::{deserialize_number_from_string, one_default, oxffffff_default};
+use crate::util::auto_hashtag;
+use crate::serde::{
+    deserialize_number_from_string, serialize_hex_string, one_default, oxffffff_default,
+};
+
+use super::super::common::{StarPower, Gadget, PlayerIcon};
 
-use super::super::common::StarPower;
+#[cfg(feature = "brawlers")]
+use super::super::brawlers::Brawler;
+
+use crate::constants::MAX_BRAWLER_POWER;
+use crate::ids::BrawlerId;
 
 use super::battlelog::{BattlePlayer};
 
@@ -46,6 +58,7 @@ pub struct Player {
     ///
     /// [`PlayerClub`]: ./struct.PlayerClub.html
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub club: Option<PlayerClub>,
 
     /// Whether or not the Player was qualified from the Championship challenge (2020).
@@ -64,6 +77,12 @@ pub struct Player {
     #[serde(default)]
     pub name: String,
 
+    /// The player's profile icon (see [`PlayerIcon`]).
+    ///
+    /// [`PlayerIcon`]: ../../common/struct.PlayerIcon.html
+    #[serde(default)]
+    pub icon: PlayerIcon,
+
     /// The player's current trophies.
     #[serde(default)]  // zero
     pub trophies: usize,
@@ -112,6 +131,7 @@ pub struct Player {
     /// when the data is not available).
     #[serde(default = "oxffffff_default")]
     #[serde(deserialize_with = "deserialize_number_from_string")]  // parse num
+    #[serde(serialize_with = "serialize_hex_string")]  // "0x..." str, like the API
     pub name_color: u64,
 }
 fn false_default() -> bool { false }
@@ -131,6 +151,8 @@ impl Default for Player {
 
             name: String::from(""),
 
+            icon: PlayerIcon::default(),
+
             trophies: 0,
 
             highest_trophies: 0,
@@ -158,6 +180,79 @@ impl Default for Player {
     }
 }
 
+impl Player {
+    /// Returns the player's brawlers which are at the maximum power level
+    /// (see [`PlayerBrawlerStat.is_maxed`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::{Player, PlayerBrawlerStat, Brawlers};
+    ///
+    /// let mut player = Player::default();
+    /// player.brawlers = vec![
+    ///     PlayerBrawlerStat { id: Brawlers::Shelly.into(), power: 11, ..PlayerBrawlerStat::default() },
+    ///     PlayerBrawlerStat { id: Brawlers::Colt.into(), power: 9, ..PlayerBrawlerStat::default() },
+    /// ];
+    ///
+    /// let maxed = player.maxed_brawlers();
+    /// assert_eq!(maxed.len(), 1);
+    /// assert_eq!(maxed[0].id, 16000000);
+    /// ```
+    ///
+    /// [`PlayerBrawlerStat.is_maxed`]: ./struct.PlayerBrawlerStat.html#method.is_maxed
+    pub fn maxed_brawlers(&self) -> Vec<&PlayerBrawlerStat> {
+        self.brawlers.iter().filter(|b| b.is_maxed()).collect()
+    }
+
+    /// Returns the total amount of gadgets the player has unlocked, across all brawlers.
+    pub fn total_gadgets(&self) -> usize {
+        self.brawlers.iter().map(|b| b.gadgets.len()).sum()
+    }
+
+    /// Compares the player's brawlers with a catalogue of all brawlers in the game (usually a
+    /// fetched [`BrawlerList`]), returning, for each of the player's brawlers which is missing
+    /// at least one star power or gadget, what is missing (see [`MissingAccessories`]).
+    ///
+    /// Brawlers the player has not unlocked yet, as well as brawlers that are not present in
+    /// the given catalogue, are ignored. (Feature-gated with the `brawlers` feature.)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, Player, BrawlerList, traits::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let player = Player::fetch(&my_client, "#PLAYERTAGHERE")?;
+    /// let catalogue = BrawlerList::fetch(&my_client)?;
+    ///
+    /// for missing in player.missing_accessories(&catalogue) {
+    ///     println!(
+    ///         "{}: {} star power(s) and {} gadget(s) missing",
+    ///         missing.name, missing.star_powers.len(), missing.gadgets.len()
+    ///     );
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`BrawlerList`]: ../../brawlers/struct.BrawlerList.html
+    /// [`MissingAccessories`]: ./struct.MissingAccessories.html
+    #[cfg(feature = "brawlers")]
+    pub fn missing_accessories(&self, catalogue: &[Brawler]) -> Vec<MissingAccessories> {
+        self.brawlers.iter()
+            .filter_map(|p_brawler| {
+                catalogue.iter()
+                    .find(|brawler| brawler.id == p_brawler.id)
+                    .map(|brawler| p_brawler.missing_accessories(brawler))
+            })
+            .filter(|missing| !missing.is_empty())
+            .collect()
+    }
+}
+
 impl GetFetchProp for Player {
     type Property = str;
 
@@ -166,6 +261,12 @@ impl GetFetchProp for Player {
     fn get_route(tag: &str) -> Route { Route::Player(auto_hashtag(tag)) }
 }
 
+impl Endpoint for Player {
+    type Params = str;
+
+    fn route(tag: &str) -> Route { <Player as GetFetchProp>::get_route(tag) }
+}
+
 #[cfg_attr(feature = "async", async_trait)]
 impl PropFetchable for Player {
     type Property = str;
@@ -200,9 +301,9 @@ impl PropFetchable for Player {
     /// [`Error::Status`]: error/enum.Error.html#variant.Status
     /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
     /// [`Error::Json`]: error/enum.Error.html#variant.Json
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch(client: &Client, tag: &str) -> Result<Player> {
-        let route = Self::get_route(tag);
-        fetch_route::<Player>(client, &route)
+        client.get::<Player>(tag)
     }
 
     /// (Async) Fetches a player from its tag.
@@ -240,8 +341,46 @@ impl PropFetchable for Player {
         where Self: 'async_trait,
               Self::Property: 'async_trait,
     {
-        let route = Player::get_route(&tag);
-        a_fetch_route::<Player>(client, &route).await
+        client.a_get::<Player>(tag).await
+    }
+}
+
+#[cfg(feature = "async")]
+impl Player {
+    /// (Async) Fetches many players concurrently, given their tags, keeping at most
+    /// `concurrency` requests in-flight at once.
+    ///
+    /// Returns a `Stream` of `(tag, result)` pairs, yielded in completion order. Each fetch
+    /// may fail independently (see [`Player::a_fetch`] for possible errors) without stopping
+    /// the others. This is equivalent to [`Client::a_get_many`] with `Player`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, Player, traits::*};
+    /// use futures::stream::StreamExt;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let tags = vec!["#PLAYER1", "#PLAYER2", "#PLAYER3"];
+    ///
+    /// let results: Vec<_> = Player::a_fetch_many(&my_client, tags, 5).collect().await;
+    /// // each element is a (tag, Result<Player>) pair
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Player::a_fetch`]: #method.a_fetch
+    /// [`Client::a_get_many`]: ../../../http/client/struct.Client.html#method.a_get_many
+    pub fn a_fetch_many<'a, I>(
+        client: &'a Client, tags: I, concurrency: usize
+    ) -> impl Stream<Item = (I::Item, Result<Player>)> + 'a
+        where I: IntoIterator,
+              I::IntoIter: 'a,
+              I::Item: Borrow<str> + 'a,
+    {
+        client.a_get_many::<Player, I>(tags, concurrency)
     }
 }
 
@@ -271,6 +410,7 @@ impl FetchFrom<ClubMember> for Player {
     /// ```
     ///
     /// [`Player::fetch`]: struct.Player.html#method.fetch
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(client: &Client, member: &ClubMember) -> Result<Player> {
         Player::fetch(client, &member.tag)
     }
@@ -338,6 +478,7 @@ impl FetchFrom<BattlePlayer> for Player {
     /// ```
     ///
     /// [`Player::fetch`]: struct.Player.html#method.fetch
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(client: &Client, b_player: &BattlePlayer) -> Result<Player> {
         Player::fetch(client, &b_player.tag)
     }
@@ -387,6 +528,7 @@ impl FetchFrom<PlayerRanking> for Player {
     /// (Sync) Fetches a `Player` using data from a [`PlayerRanking`] object.
     ///
     /// [`PlayerRanking`]: ../../rankings/players/struct.PlayerRanking.html
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(client: &Client, p_ranking: &PlayerRanking) -> Result<Player> {
         Player::fetch(client, &p_ranking.tag)
     }
@@ -458,9 +600,11 @@ pub struct PlayerBrawlerStat {
     #[serde(default)]
     pub star_powers: Vec<StarPower>,
 
-    /// The brawler's id (an arbitrary number).
+    /// The brawler's id (see [`BrawlerId`]).
+    ///
+    /// [`BrawlerId`]: ../../../ids/struct.BrawlerId.html
     #[serde(default)]  // zero
-    pub id: usize,
+    pub id: BrawlerId,
 
     /// The brawler's rank.
     #[serde(default = "one_default")]
@@ -474,13 +618,39 @@ pub struct PlayerBrawlerStat {
     #[serde(default)]  // zero
     pub highest_trophies: usize,
 
-    /// The brawler's power (1-10).
+    /// The brawler's power (1-11).
     #[serde(default = "one_default")]
     pub power: u8,
 
     /// The brawler's name.
     #[serde(default)]
     pub name: String,
+
+    /// A vector containing the brawler's unlocked gadgets (represented by [`Gadget`]),
+    /// if any (otherwise empty vector).
+    ///
+    /// [`Gadget`]: ../../common/struct.Gadget.html
+    #[serde(default)]
+    pub gadgets: Vec<Gadget>,
+
+    /// A vector containing the brawler's unlocked gears (represented by [`Gear`]),
+    /// if any (otherwise empty vector).
+    ///
+    /// [`Gear`]: ./struct.Gear.html
+    #[serde(default)]
+    pub gears: Vec<Gear>,
+
+    /// A vector containing the brawler's unlocked hypercharges (represented by [`HyperCharge`]),
+    /// if any (otherwise empty vector).
+    ///
+    /// [`HyperCharge`]: ./struct.HyperCharge.html
+    #[serde(default)]
+    pub hyper_charges: Vec<HyperCharge>,
+
+    /// The skin currently equipped on the brawler, or None if this data is not available.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skin: Option<BrawlerSkin>,
 }
 
 impl Default for PlayerBrawlerStat {
@@ -489,24 +659,270 @@ impl Default for PlayerBrawlerStat {
     fn default() -> PlayerBrawlerStat {
         PlayerBrawlerStat {
             star_powers: vec![],
-            id: 0,
+            id: BrawlerId::default(),
             rank: 1,
             trophies: 0,
             highest_trophies: 0,
             power: 1,
             name: String::from(""),
+            gadgets: vec![],
+            gears: vec![],
+            hyper_charges: vec![],
+            skin: None,
         }
     }
 }
 
+impl PlayerBrawlerStat {
+    /// Returns whether or not this brawler is at the maximum power level
+    /// (see [`constants::MAX_BRAWLER_POWER`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::PlayerBrawlerStat;
+    ///
+    /// let brawler = PlayerBrawlerStat { power: 11, ..PlayerBrawlerStat::default() };
+    /// assert!(brawler.is_maxed());
+    ///
+    /// assert!(!PlayerBrawlerStat::default().is_maxed());
+    /// ```
+    ///
+    /// [`constants::MAX_BRAWLER_POWER`]: ../../../constants/constant.MAX_BRAWLER_POWER.html
+    pub fn is_maxed(&self) -> bool {
+        self.power >= MAX_BRAWLER_POWER
+    }
+
+    /// Returns the star powers and gadgets of the given [`Brawler`] (usually obtained from the
+    /// `/brawlers/` endpoint) that this brawler has not unlocked yet. Note that the given
+    /// `Brawler` is assumed to be the same as this one. (Feature-gated with the `brawlers`
+    /// feature.)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::{
+    ///     PlayerBrawlerStat, Brawler, Brawlers, StarPower, StarPowerId, Gadget, GadgetId,
+    /// };
+    ///
+    /// let shell_shock = StarPower {
+    ///     id: StarPowerId::new(23000076)?, name: String::from("Shell Shock")
+    /// };
+    /// let band_aid = StarPower { id: StarPowerId::new(23000135)?, name: String::from("Band-Aid") };
+    /// let fast_forward = Gadget {
+    ///     id: GadgetId::new(23000255)?, name: String::from("Fast Forward")
+    /// };
+    ///
+    /// let brawler = Brawler {
+    ///     id: Brawlers::Shelly.into(),
+    ///     name: String::from("SHELLY"),
+    ///     star_powers: vec![shell_shock.clone(), band_aid.clone()],
+    ///     gadgets: vec![fast_forward.clone()],
+    /// };
+    ///
+    /// let p_brawler = PlayerBrawlerStat {
+    ///     id: Brawlers::Shelly.into(),
+    ///     star_powers: vec![shell_shock],
+    ///     ..PlayerBrawlerStat::default()
+    /// };
+    ///
+    /// let missing = p_brawler.missing_accessories(&brawler);
+    /// assert_eq!(missing.star_powers, vec![band_aid]);
+    /// assert_eq!(missing.gadgets, vec![fast_forward]);
+    ///
+    /// # Ok::<(), Box<dyn ::std::error::Error>>(())
+    /// ```
+    ///
+    /// [`Brawler`]: ../../brawlers/struct.Brawler.html
+    #[cfg(feature = "brawlers")]
+    pub fn missing_accessories(&self, brawler: &Brawler) -> MissingAccessories {
+        MissingAccessories {
+            id: self.id,
+            name: self.name.clone(),
+            star_powers: brawler.star_powers.iter()
+                .filter(|sp| !self.star_powers.iter().any(|own| own.id == sp.id))
+                .cloned()
+                .collect(),
+            gadgets: brawler.gadgets.iter()
+                .filter(|gadget| !self.gadgets.iter().any(|own| own.id == gadget.id))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// A struct representing a gear unlocked by a player's brawler (see
+/// [`PlayerBrawlerStat.gears`]).
+///
+/// [`PlayerBrawlerStat.gears`]: ./struct.PlayerBrawlerStat.html#structfield.gears
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Gear {
+
+    /// The gear's id (an arbitrary number).
+    #[serde(default)]
+    pub id: usize,
+
+    /// The gear's name (e.g. "SPEED", "DAMAGE"...).
+    #[serde(default)]
+    pub name: String,
+
+    /// The gear's level.
+    #[serde(default = "one_default")]
+    pub level: u8,
+}
+
+impl Default for Gear {
+
+    /// Returns an instance of `Gear` with initial values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::Gear;
+    ///
+    /// assert_eq!(
+    ///     Gear::default(),
+    ///     Gear {
+    ///         id: 0,
+    ///         name: String::from(""),
+    ///         level: 1,
+    ///     }
+    /// );
+    /// ```
+    fn default() -> Gear {
+        Gear {
+            id: 0,
+            name: String::from(""),
+            level: 1,
+        }
+    }
+}
+
+/// A struct representing a hypercharge unlocked by a player's brawler (see
+/// [`PlayerBrawlerStat.hyper_charges`]).
+///
+/// [`PlayerBrawlerStat.hyper_charges`]: ./struct.PlayerBrawlerStat.html#structfield.hyper_charges
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HyperCharge {
+
+    /// The hypercharge's id (an arbitrary number).
+    #[serde(default)]
+    pub id: usize,
+
+    /// The hypercharge's name.
+    #[serde(default)]
+    pub name: String,
+}
+
+impl Default for HyperCharge {
+
+    /// Returns an instance of `HyperCharge` with initial values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::HyperCharge;
+    ///
+    /// assert_eq!(
+    ///     HyperCharge::default(),
+    ///     HyperCharge {
+    ///         id: 0,
+    ///         name: String::from(""),
+    ///     }
+    /// );
+    /// ```
+    fn default() -> HyperCharge {
+        HyperCharge {
+            id: 0,
+            name: String::from(""),
+        }
+    }
+}
+
+/// A struct representing the skin equipped on a player's brawler (see
+/// [`PlayerBrawlerStat.skin`]).
+///
+/// [`PlayerBrawlerStat.skin`]: ./struct.PlayerBrawlerStat.html#structfield.skin
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrawlerSkin {
+
+    /// The skin's id (an arbitrary number).
+    #[serde(default)]
+    pub id: usize,
+
+    /// The skin's name (e.g. "BANDITA SHELLY").
+    #[serde(default)]
+    pub name: String,
+}
+
+impl Default for BrawlerSkin {
+
+    /// Returns an instance of `BrawlerSkin` with initial values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::BrawlerSkin;
+    ///
+    /// assert_eq!(
+    ///     BrawlerSkin::default(),
+    ///     BrawlerSkin {
+    ///         id: 0,
+    ///         name: String::from(""),
+    ///     }
+    /// );
+    /// ```
+    fn default() -> BrawlerSkin {
+        BrawlerSkin {
+            id: 0,
+            name: String::from(""),
+        }
+    }
+}
+
+/// Contains the star powers and gadgets a player's brawler has not unlocked yet, compared with
+/// the game's brawler catalogue. Obtained through [`Player.missing_accessories`] or
+/// [`PlayerBrawlerStat.missing_accessories`]. (Feature-gated with the `brawlers` feature.)
+///
+/// [`Player.missing_accessories`]: ./struct.Player.html#method.missing_accessories
+/// [`PlayerBrawlerStat.missing_accessories`]: ./struct.PlayerBrawlerStat.html#method.missing_accessories
+#[cfg(feature = "brawlers")]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+pub struct MissingAccessories {
+    /// The brawler's id.
+    pub id: BrawlerId,
+
+    /// The brawler's name.
+    pub name: String,
+
+    /// The star powers the brawler has not unlocked yet.
+    pub star_powers: Vec<StarPower>,
+
+    /// The gadgets the brawler has not unlocked yet.
+    pub gadgets: Vec<Gadget>,
+}
+
+#[cfg(feature = "brawlers")]
+impl MissingAccessories {
+    /// Returns `true` if nothing is missing (i.e., the brawler has unlocked all of its star
+    /// powers and gadgets).
+    pub fn is_empty(&self) -> bool {
+        self.star_powers.is_empty() && self.gadgets.is_empty()
+    }
+}
+
 ///////////////////////////////////   tests   ///////////////////////////////////
 
 #[cfg(test)]
 mod tests {
     use std::result::Result as StdResult;
-    use super::{Player, PlayerClub, PlayerBrawlerStat, StarPower};
+    use super::{
+        Player, PlayerClub, PlayerBrawlerStat, StarPower, Gadget, Gear, HyperCharge, BrawlerSkin,
+        PlayerIcon,
+    };
     use crate::error::Error as BrawlError;
-    use serde_json;
+    use crate::ids::{BrawlerId, StarPowerId, GadgetId};
+    use serde_json::{self, json};
 
     /// Tests for player deserialization from API-provided JSON.
     #[test]
@@ -515,6 +931,9 @@ mod tests {
   "tag": "#CCCCCC",
   "name": "User",
   "nameColor": "0xff1ba5f5",
+  "icon": {
+    "id": 28000003
+  },
   "trophies": 13370,
   "highestTrophies": 30000,
   "powerPlayPoints": 200,
@@ -539,7 +958,10 @@ mod tests {
       "rank": 20,
       "trophies": 500,
       "highestTrophies": 549,
-      "starPowers": []
+      "starPowers": [],
+      "gadgets": [],
+      "gears": [],
+      "hyperCharges": []
     },
     {
       "id": 16000001,
@@ -557,7 +979,35 @@ mod tests {
           "id": 23000077,
           "name": "Slick Boots"
         }
-      ]
+      ],
+      "gadgets": [
+        {
+          "id": 23000273,
+          "name": "Speedloader"
+        }
+      ],
+      "gears": [
+        {
+          "id": 62000000,
+          "name": "SPEED",
+          "level": 3
+        },
+        {
+          "id": 62000002,
+          "name": "DAMAGE",
+          "level": 3
+        }
+      ],
+      "hyperCharges": [
+        {
+          "id": 23000615,
+          "name": "Bullet Storm"
+        }
+      ],
+      "skin": {
+        "id": 29000003,
+        "name": "ROUGE COLT"
+      }
     }
   ]
 }"##;
@@ -570,6 +1020,7 @@ mod tests {
                 tag: String::from("#CCCCCC"),
                 name: String::from("User"),
                 name_color: 0xff1ba5f5,
+                icon: PlayerIcon { id: 28000003 },
                 trophies: 13370,
                 highest_trophies: 30000,
                 power_play_points: 200,
@@ -588,16 +1039,17 @@ mod tests {
                 }),
                 brawlers: vec![
                     PlayerBrawlerStat {
-                        id: 16000000,
+                        id: BrawlerId::new(16000000)?,
                         name: String::from("SHELLY"),
                         power: 9,
                         rank: 20,
                         trophies: 500,
                         highest_trophies: 549,
-                        star_powers: vec![]
+                        star_powers: vec![],
+                        ..PlayerBrawlerStat::default()
                     },
                     PlayerBrawlerStat {
-                        id: 16000001,
+                        id: BrawlerId::new(16000001)?,
                         name: String::from("COLT"),
                         power: 10,
                         rank: 18,
@@ -605,19 +1057,101 @@ mod tests {
                         highest_trophies: 440,
                         star_powers: vec![
                             StarPower {
-                                id: 23000138,
+                                id: StarPowerId::new(23000138)?,
                                 name: String::from("Magnum Special")
                             },
                             StarPower {
-                                id: 23000077,
+                                id: StarPowerId::new(23000077)?,
                                 name: String::from("Slick Boots")
                             }
-                        ]
+                        ],
+                        gadgets: vec![
+                            Gadget {
+                                id: GadgetId::new(23000273)?,
+                                name: String::from("Speedloader")
+                            }
+                        ],
+                        gears: vec![
+                            Gear {
+                                id: 62000000,
+                                name: String::from("SPEED"),
+                                level: 3
+                            },
+                            Gear {
+                                id: 62000002,
+                                name: String::from("DAMAGE"),
+                                level: 3
+                            }
+                        ],
+                        hyper_charges: vec![
+                            HyperCharge {
+                                id: 23000615,
+                                name: String::from("Bullet Storm")
+                            }
+                        ],
+                        skin: Some(BrawlerSkin {
+                            id: 29000003,
+                            name: String::from("ROUGE COLT")
+                        })
                     },
                 ]
             }
         );
+        // serializing gives back the API's JSON
+        assert_eq!(
+            serde_json::to_value(&player)?,
+            serde_json::from_str::<serde_json::Value>(player_json_s)?
+        );
+
         Ok(())
     }
-}
 
+    /// Tests that a player missing fields from the API's response (here, the icon, name color,
+    /// power play points, club and brawler accessories) is serialized with default values for
+    /// those (and without the club), and deserialized back to the same player.
+    #[test]
+    fn players_ser_minimal() -> StdResult<(), Box<dyn ::std::error::Error>> {
+        let player_json_s = r##"{
+  "tag": "#CCCCCC",
+  "name": "User",
+  "trophies": 1337,
+  "highestTrophies": 1500,
+  "expLevel": 20,
+  "expPoints": 7000,
+  "isQualifiedFromChampionshipChallenge": false,
+  "3vs3Victories": 333,
+  "soloVictories": 99,
+  "duoVictories": 33,
+  "bestRoboRumbleTime": 0,
+  "bestTimeAsBigBrawler": 0,
+  "brawlers": [
+    {
+      "id": 16000000,
+      "name": "SHELLY",
+      "power": 5,
+      "rank": 10,
+      "trophies": 250,
+      "highestTrophies": 300,
+      "starPowers": []
+    }
+  ]
+}"##;
+        let player = serde_json::from_str::<Player>(player_json_s)
+            .map_err(BrawlError::Json)?;
+        let player_json = serde_json::to_value(&player)?;
+
+        let mut expected = serde_json::from_str::<serde_json::Value>(player_json_s)?;
+        expected["icon"] = json!({ "id": 0 });
+        expected["nameColor"] = json!("0xffffff");
+        expected["powerPlayPoints"] = json!(0);
+        expected["highestPowerPlayPoints"] = json!(0);
+        for key in &["gadgets", "gears", "hyperCharges"] {
+            expected["brawlers"][0][*key] = json!([]);
+        }
+
+        assert_eq!(player_json, expected);
+        assert_eq!(serde_json::from_value::<Player>(player_json)?, player);
+
+        Ok(())
+    }
+}
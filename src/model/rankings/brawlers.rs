@@ -5,18 +5,22 @@
 use serde::{self, Serialize, Deserialize};
 
 use std::ops::{Deref, DerefMut};
-use crate::util::fetch_route;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::error::Result;
 
-#[cfg(feature = "async")]
-use crate::util::a_fetch_route;
+use crate::traits::{Refetchable, Endpoint, BrawlerRankingParams};
 
 #[cfg(feature = "async")]
+use async_trait::async_trait;
 
-
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::http::Client;
+
 use crate::http::routes::Route;
 use super::players::PlayerRanking;
+use super::super::common::Paging;
+use crate::ids::BrawlerId;
 
 
 /// Represents a leaderboard of [`PlayerRanking`]s - the top x players in a regional or global
@@ -35,6 +39,18 @@ use super::players::PlayerRanking;
 /// [`BrawlerLeaderboard::a_fetch`]: #method.a_fetch
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BrawlerLeaderboard {
+    /// The country code (or `"global"`) of this leaderboard, as given when fetching.
+    #[serde(skip)]  // artificial
+    pub country_code: String,
+
+    /// The ID of the brawler whose leaderboard this is, as given when fetching.
+    #[serde(skip)]  // artificial
+    pub brawler_id: BrawlerId,
+
+    /// The limit of rankings used when fetching this leaderboard.
+    #[serde(skip)]  // artificial
+    pub limit: u8,
+
     /// The players in the brawler ranking.
     ///
     /// **NOTE:** When the targeted brawler is a recent/new one, this vector *could* be empty, even
@@ -42,6 +58,13 @@ pub struct BrawlerLeaderboard {
     /// active players, it may not appear for a while (a few days?).
     #[serde(default)]
     pub items: Vec<PlayerRanking>,
+
+    /// The paging information given by the API along with the rankings, if any (see [`Paging`]).
+    ///
+    /// [`Paging`]: ../../common/struct.Paging.html
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<Paging>,
 }
 
 impl Deref for BrawlerLeaderboard {
@@ -59,7 +82,7 @@ impl Deref for BrawlerLeaderboard {
     /// let top50colts = BrawlerLeaderboard::fetch(
     ///     &client,   // <- the client containing the auth key
     ///     "global",  // <- the region of the leaderboard to fetch ("global" - world-wide)
-    ///     Brawlers::Colt as usize,  // <- whose brawler should this leaderboard be
+    ///     Brawlers::Colt.into(),  // <- whose brawler should this leaderboard be
     ///     50         // <- limit of rankings to fetch (i.e. top 50)
     /// )?;
     ///
@@ -89,7 +112,7 @@ impl DerefMut for BrawlerLeaderboard {
     /// let top50colts = BrawlerLeaderboard::fetch(
     ///     &client,   // <- the client containing the auth key
     ///     "global",  // <- the region of the leaderboard to fetch ("global" - world-wide)
-    ///     Brawlers::Colt as usize,  // <- whose brawler should this leaderboard be
+    ///     Brawlers::Colt.into(),  // <- whose brawler should this leaderboard be
     ///     50         // <- limit of rankings to fetch (i.e. top 50)
     /// )?;
     ///
@@ -109,7 +132,7 @@ impl DerefMut for BrawlerLeaderboard {
 impl BrawlerLeaderboard {
     /// (Sync) Fetches the top `limit <= 200` players in the regional (two-letter) `country_code`
     /// leaderboard (or global leaderboard, if `country_code == "global"`), **sorted by their
-    /// trophies with the brawler represented by `brawler_id`.** (Tip: use the [`Brawler`] enum
+    /// trophies with the brawler represented by `brawler_id`.** (Tip: use the [`Brawlers`] enum
     /// to easily obtain ID values, although it requires being updated, so fetching from the API
     /// might be more appropriate.)
     ///
@@ -137,7 +160,7 @@ impl BrawlerLeaderboard {
     /// // if the fetch is successful, then the variable below will have the global top 100 Tara
     /// // players in the 'items' field (i.e. '*top100taras').
     /// let top100taras: BrawlerLeaderboard = BrawlerLeaderboard::fetch(
-    ///     &client, "global", Brawlers::Tara as usize, 100
+    ///     &client, "global", Brawlers::Tara.into(), 100
     /// )?;
     ///
     /// // get player ranked #1. The items are usually sorted (i.e. rank 1 on index [0], rank 2
@@ -162,7 +185,7 @@ impl BrawlerLeaderboard {
     /// // if the fetch is successful, then the variable below will have the global top 150
     /// // zimbabwean Shelly players in the 'items' field (i.e. '*top150_zw_shelly').
     /// let top150_zw_shelly: BrawlerLeaderboard = BrawlerLeaderboard::fetch(
-    ///     &client, "ZW", Brawlers::Shelly as usize, 150
+    ///     &client, "ZW", Brawlers::Shelly.into(), 150
     /// )?;
     ///
     /// // get player ranked #1. The items are usually sorted (i.e. rank 1 on index [0], rank 2
@@ -179,20 +202,17 @@ impl BrawlerLeaderboard {
     /// [`Error::Status`]: error/enum.Error.html#variant.Status
     /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
     /// [`Error::Json`]: error/enum.Error.html#variant.Json
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     pub fn fetch(
-        client: &Client, country_code: &str, brawler_id: usize, limit: u8,
+        client: &Client, country_code: &str, brawler_id: BrawlerId, limit: u8,
     ) -> Result<BrawlerLeaderboard> {
-        let route = Route::BrawlerRankings {
-            country_code: country_code.to_owned(),
-            brawler_id,
-            limit
-        };
-        fetch_route::<BrawlerLeaderboard>(client, &route)
+        let params = BrawlerRankingParams::new(country_code, brawler_id, limit);
+        client.get::<BrawlerLeaderboard>(&params)
     }
 
     /// (Async) Fetches the top `limit <= 200` players in the regional (two-letter) `country_code`
     /// leaderboard (or global leaderboard, if `country_code == "global"`), **sorted by their
-    /// trophies with the brawler represented by `brawler_id`.** (Tip: use the [`Brawler`] enum
+    /// trophies with the brawler represented by `brawler_id`.** (Tip: use the [`Brawlers`] enum
     /// to easily obtain ID values, although it requires being updated, so fetching from the API
     /// might be more appropriate.)
     ///
@@ -220,7 +240,7 @@ impl BrawlerLeaderboard {
     /// // if the fetch is successful, then the variable below will have the global top 100 Tara
     /// // players in the 'items' field (i.e. '*top100taras').
     /// let top100taras: BrawlerLeaderboard = BrawlerLeaderboard::a_fetch(
-    ///     &client, "global", Brawlers::Tara as usize, 100
+    ///     &client, "global", Brawlers::Tara.into(), 100
     /// ).await?;
     ///
     /// // get player ranked #1. The items are usually sorted (i.e. rank 1 on index [0], rank 2
@@ -245,7 +265,7 @@ impl BrawlerLeaderboard {
     /// // if the fetch is successful, then the variable below will have the global top 150
     /// // zimbabwean Shelly players in the 'items' field (i.e. '*top150_zw_shelly').
     /// let top150_zw_shelly: BrawlerLeaderboard = BrawlerLeaderboard::a_fetch(
-    ///     &client, "ZW", Brawlers::Shelly as usize, 150
+    ///     &client, "ZW", Brawlers::Shelly.into(), 150
     /// ).await?;
     ///
     /// // get player ranked #1. The items are usually sorted (i.e. rank 1 on index [0], rank 2
@@ -264,14 +284,51 @@ impl BrawlerLeaderboard {
     /// [`Error::Json`]: error/enum.Error.html#variant.Json
     #[cfg(feature="async")]
     pub async fn a_fetch(
-        client: &Client, country_code: &str, brawler_id: usize, limit: u8,
+        client: &Client, country_code: &str, brawler_id: BrawlerId, limit: u8,
     ) -> Result<BrawlerLeaderboard> {
-        let route = Route::BrawlerRankings {
-            country_code: country_code.to_owned(),
-            brawler_id,
-            limit
-        };
-        a_fetch_route::<BrawlerLeaderboard>(client, &route).await
+        let params = BrawlerRankingParams::new(country_code, brawler_id, limit);
+        client.a_get::<BrawlerLeaderboard>(&params).await
+    }
+}
+
+impl Endpoint for BrawlerLeaderboard {
+    type Params = BrawlerRankingParams;
+
+    fn route(params: &BrawlerRankingParams) -> Route {
+        Route::BrawlerRankings {
+            country_code: params.country_code.clone(),
+            brawler_id: params.brawler_id,
+            limit: params.limit,
+        }
+    }
+
+    /// Sets the artificial [`country_code`], [`brawler_id`] and [`limit`] fields to the ones
+    /// used for fetching.
+    ///
+    /// [`country_code`]: #structfield.country_code
+    /// [`brawler_id`]: #structfield.brawler_id
+    /// [`limit`]: #structfield.limit
+    fn on_fetched(&mut self, params: &BrawlerRankingParams) {
+        self.country_code = params.country_code.clone();
+        self.brawler_id = params.brawler_id;
+        self.limit = params.limit;
+    }
+}
+
+/// Refetching reuses the parameters given when fetching, so it fails for deserialized
+/// leaderboards (see [the `rankings` module](../index.html#refetching)).
+#[cfg_attr(feature = "async", async_trait)]
+impl Refetchable for BrawlerLeaderboard {
+    /// (Sync) Fetches this leaderboard again, with the same country code, brawler and limit.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    fn refetch(&self, client: &Client) -> Result<BrawlerLeaderboard> {
+        BrawlerLeaderboard::fetch(client, &self.country_code, self.brawler_id, self.limit)
+    }
+
+    /// (Async) Fetches this leaderboard again, with the same country code, brawler and limit.
+    #[cfg(feature = "async")]
+    async fn a_refetch(&self, client: &Client) -> Result<BrawlerLeaderboard> {
+        BrawlerLeaderboard::a_fetch(client, &self.country_code, self.brawler_id, self.limit).await
     }
 }
 
@@ -283,6 +340,8 @@ mod tests {
     use super::BrawlerLeaderboard;
     use super::super::players::{PlayerRanking, PlayerRankingClub};
     use crate::error::Error;
+    use crate::model::common::{PlayerIcon, Paging};
+    use crate::ids::BrawlerId;
 
     /// Tests for BrawlerLeaderboard deserialization from API-provided JSON.
     #[test]
@@ -294,6 +353,9 @@ mod tests {
       "tag": "#AAAAAAAAA",
       "name": "Player",
       "nameColor": "0xfff05637",
+      "icon": {
+        "id": 28000010
+      },
       "trophies": 30000,
       "rank": 1,
       "club": {
@@ -304,6 +366,9 @@ mod tests {
       "tag": "#EEEEEEE",
       "name": "Also Player",
       "nameColor": "0xffa2e3fe",
+      "icon": {
+        "id": 28000000
+      },
       "trophies": 25000,
       "rank": 2,
       "club": {
@@ -314,6 +379,9 @@ mod tests {
       "tag": "#QQQQQQQ",
       "name": "Youtuber",
       "nameColor": "0xfff05637",
+      "icon": {
+        "id": 28000001
+      },
       "trophies": 23000,
       "rank": 3,
       "club": {
@@ -324,6 +392,9 @@ mod tests {
       "tag": "#55555553Q",
       "name": "Not a valid player",
       "nameColor": "0xfff9cf08",
+      "icon": {
+        "id": 28000042
+      },
       "trophies": 20000,
       "rank": 4,
       "club": {
@@ -343,11 +414,15 @@ mod tests {
         assert_eq!(
             b_leaders,
             BrawlerLeaderboard {
+                country_code: String::new(),
+                brawler_id: BrawlerId::default(),
+                limit: 0,
                 items: vec![
                     PlayerRanking {
                         tag: String::from("#AAAAAAAAA"),
                         name: String::from("Player"),
                         name_color: 0xfff05637,
+                        icon: PlayerIcon { id: 28000010 },
                         trophies: 30000,
                         rank: 1,
                         club: PlayerRankingClub {
@@ -358,6 +433,7 @@ mod tests {
                         tag: String::from("#EEEEEEE"),
                         name: String::from("Also Player"),
                         name_color: 0xffa2e3fe,
+                        icon: PlayerIcon { id: 28000000 },
                         trophies: 25000,
                         rank: 2,
                         club: PlayerRankingClub {
@@ -368,6 +444,7 @@ mod tests {
                         tag: String::from("#QQQQQQQ"),
                         name: String::from("Youtuber"),
                         name_color: 0xfff05637,
+                        icon: PlayerIcon { id: 28000001 },
                         trophies: 23000,
                         rank: 3,
                         club: PlayerRankingClub {
@@ -378,16 +455,39 @@ mod tests {
                         tag: String::from("#55555553Q"),
                         name: String::from("Not a valid player"),
                         name_color: 0xfff9cf08,
+                        icon: PlayerIcon { id: 28000042 },
                         trophies: 20000,
                         rank: 4,
                         club: PlayerRankingClub {
                             name: String::from("Different Club")
                         }
                     }
-                ]
+                ],
+                paging: Some(Paging::default()),
             }
         );
 
+        // serializing gives back the API's JSON
+        assert_eq!(
+            serde_json::to_value(&b_leaders)?,
+            serde_json::from_str::<serde_json::Value>(rb_json_s)?
+        );
+
         Ok(())
     }
+
+    /// Tests that fetched leaderboards keep their parameters, so that refetching repeats the same
+    /// request, and that deserialized ones can't be refetched.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn rankings_brawlers_refetch() {
+        use super::super::test_util::assert_refetch;
+
+        let leaders = assert_refetch(
+            |client| BrawlerLeaderboard::fetch(client, "ZW", BrawlerId(16000001), 5).unwrap(),
+            "/rankings/ZW/brawlers/16000001?limit=5",
+        );
+        assert_eq!((leaders.country_code.as_str(), leaders.limit), ("ZW", 5));
+        assert_eq!(leaders.brawler_id, BrawlerId(16000001));
+    }
 }
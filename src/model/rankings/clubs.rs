@@ -2,19 +2,21 @@
 //! Included by the feature `"rankings"`; removing that feature will disable the usage of this module.
 
 use serde::{self, Serialize, Deserialize};
-use crate::traits::{PropLimRouteable, PropLimFetchable};
+use crate::traits::{PropLimRouteable, PropLimFetchable, Refetchable, Endpoint, RankingParams};
 use crate::serde::one_default;
 use std::ops::{Deref, DerefMut};
-use crate::util::fetch_route;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::error::Result;
 
 #[cfg(feature = "async")]
 use async_trait::async_trait;
 
-#[cfg(feature = "async")]
-use crate::util::a_fetch_route;
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::http::Client;
+
 use crate::http::routes::Route;
+use super::super::common::Paging;
 
 /// Represents a leaderboard of [`ClubRanking`]s - the top x clubs in a regional or global
 /// leaderboard.
@@ -24,9 +26,24 @@ use crate::http::routes::Route;
 /// [`ClubRanking`]: ./struct.ClubRanking.html
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ClubLeaderboard {
+    /// The country code (or `"global"`) of this leaderboard, as given when fetching.
+    #[serde(skip)]  // artificial
+    pub country_code: String,
+
+    /// The limit of rankings used when fetching this leaderboard.
+    #[serde(skip)]  // artificial
+    pub limit: u8,
+
     /// The clubs in the ranking.
     #[serde(default)]
     pub items: Vec<ClubRanking>,
+
+    /// The paging information given by the API along with the rankings, if any (see [`Paging`]).
+    ///
+    /// [`Paging`]: ../../common/struct.Paging.html
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<Paging>,
 }
 
 impl Deref for ClubLeaderboard {
@@ -155,9 +172,9 @@ impl PropLimFetchable for ClubLeaderboard {
     /// [`Error::Status`]: error/enum.Error.html#variant.Status
     /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
     /// [`Error::Json`]: error/enum.Error.html#variant.Json
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch(client: &Client, country_code: &str, limit: u8) -> Result<ClubLeaderboard> {
-        let route = ClubLeaderboard::get_route(country_code, limit);
-        fetch_route::<ClubLeaderboard>(client, &route)
+        client.get::<ClubLeaderboard>(&RankingParams::new(country_code, limit))
     }
 
     /// (Async) Fetches the top `limit <= 200` clubs in the regional (two-letter) `country_code`
@@ -224,8 +241,7 @@ impl PropLimFetchable for ClubLeaderboard {
         where Self: 'async_trait,
               Self::Property: 'async_trait,
     {
-        let route = ClubLeaderboard::get_route(&country_code, limit);
-        a_fetch_route::<ClubLeaderboard>(client, &route).await
+        client.a_get::<ClubLeaderboard>(&RankingParams::new(country_code, limit)).await
     }
 }
 
@@ -243,6 +259,40 @@ impl PropLimRouteable for ClubLeaderboard {
     }
 }
 
+impl Endpoint for ClubLeaderboard {
+    type Params = RankingParams;
+
+    fn route(params: &RankingParams) -> Route {
+        ClubLeaderboard::get_route(&params.country_code, params.limit)
+    }
+
+    /// Sets the artificial [`country_code`] and [`limit`] fields to the ones used for fetching.
+    ///
+    /// [`country_code`]: #structfield.country_code
+    /// [`limit`]: #structfield.limit
+    fn on_fetched(&mut self, params: &RankingParams) {
+        self.country_code = params.country_code.clone();
+        self.limit = params.limit;
+    }
+}
+
+/// Refetching reuses the parameters given when fetching, so it fails for deserialized
+/// leaderboards (see [the `rankings` module](../index.html#refetching)).
+#[cfg_attr(feature = "async", async_trait)]
+impl Refetchable for ClubLeaderboard {
+    /// (Sync) Fetches this leaderboard again, with the same country code and limit.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    fn refetch(&self, client: &Client) -> Result<ClubLeaderboard> {
+        ClubLeaderboard::fetch(client, &self.country_code, self.limit)
+    }
+
+    /// (Async) Fetches this leaderboard again, with the same country code and limit.
+    #[cfg(feature = "async")]
+    async fn a_refetch(&self, client: &Client) -> Result<ClubLeaderboard> {
+        ClubLeaderboard::a_fetch(client, &self.country_code, self.limit).await
+    }
+}
+
 /// Represents a club's ranking, based on a regional or global leaderboard.
 /// To obtain the club's full data (a [`Club`] instance), see [`Club::fetch_from`].
 ///
@@ -259,6 +309,10 @@ pub struct ClubRanking {
     #[serde(default)]
     pub name: String,
 
+    /// The id of the club's badge (an arbitrary number).
+    #[serde(default)]
+    pub badge_id: usize,
+
     /// The club's current trophies.
     #[serde(default)]
     pub trophies: usize,
@@ -285,6 +339,7 @@ impl Default for ClubRanking {
     ///     ClubRanking {
     ///         tag: String::from(""),
     ///         name: String::from(""),
+    ///         badge_id: 0,
     ///         trophies: 0,
     ///         rank: 1,
     ///         member_count: 0,
@@ -295,6 +350,7 @@ impl Default for ClubRanking {
         ClubRanking {
             tag: String::from(""),
             name: String::from(""),
+            badge_id: 0,
             trophies: 0,
             rank: 1,
             member_count: 0,
@@ -315,10 +371,13 @@ impl PartialOrd for ClubRanking {
     /// let leaderboard: ClubLeaderboard;
     ///
     /// # leaderboard = ClubLeaderboard {
+    /// #     country_code: String::from("global"),
+    /// #     limit: 2,
     /// #     items: vec![
     /// #         ClubRanking { rank: 1, ..ClubRanking::default() },  // #1 position
     /// #         ClubRanking { rank: 2, ..ClubRanking::default() },  // #2 position
-    /// #     ]
+    /// #     ],
+    /// #     paging: None,
     /// # };
     ///
     /// // after fetching the leaderboard (see examples in ClubLeaderboard::fetch)...
@@ -350,10 +409,13 @@ impl Ord for ClubRanking {
     /// let leaderboard: ClubLeaderboard;
     ///
     /// # leaderboard = ClubLeaderboard {
+    /// #     country_code: String::from("global"),
+    /// #     limit: 2,
     /// #     items: vec![
     /// #         ClubRanking { rank: 1, ..ClubRanking::default() },  // #1 position
     /// #         ClubRanking { rank: 2, ..ClubRanking::default() },  // #2 position
-    /// #     ]
+    /// #     ],
+    /// #     paging: None,
     /// # };
     ///
     /// // after fetching the leaderboard (see examples in ClubLeaderboard::fetch)...
@@ -389,6 +451,7 @@ mod tests {
     {
       "tag": "#AAAAAAAAA",
       "name": "Club",
+      "badgeId": 8000012,
       "trophies": 30000,
       "rank": 1,
       "memberCount": 50
@@ -396,6 +459,7 @@ mod tests {
     {
       "tag": "#EEEEEEE",
       "name": "Also Club",
+      "badgeId": 8000000,
       "trophies": 25000,
       "rank": 2,
       "memberCount": 30
@@ -403,6 +467,7 @@ mod tests {
     {
       "tag": "#QQQQQQQ",
       "name": "Clubby Club",
+      "badgeId": 8000031,
       "trophies": 23000,
       "rank": 3,
       "memberCount": 25
@@ -410,6 +475,7 @@ mod tests {
     {
       "tag": "#55555553Q",
       "name": "Not a valid club",
+      "badgeId": 8000007,
       "trophies": 20000,
       "rank": 4,
       "memberCount": 10
@@ -423,10 +489,13 @@ mod tests {
         assert_eq!(
             c_leaders,
             ClubLeaderboard {
+                country_code: String::new(),
+                limit: 0,
                 items: vec![
                     ClubRanking {
                         tag: String::from("#AAAAAAAAA"),
                         name: String::from("Club"),
+                        badge_id: 8000012,
                         member_count: 50,
                         trophies: 30000,
                         rank: 1,
@@ -434,6 +503,7 @@ mod tests {
                     ClubRanking {
                         tag: String::from("#EEEEEEE"),
                         name: String::from("Also Club"),
+                        badge_id: 8000000,
                         member_count: 30,
                         trophies: 25000,
                         rank: 2,
@@ -441,6 +511,7 @@ mod tests {
                     ClubRanking {
                         tag: String::from("#QQQQQQQ"),
                         name: String::from("Clubby Club"),
+                        badge_id: 8000031,
                         member_count: 25,
                         trophies: 23000,
                         rank: 3,
@@ -448,14 +519,37 @@ mod tests {
                     ClubRanking {
                         tag: String::from("#55555553Q"),
                         name: String::from("Not a valid club"),
+                        badge_id: 8000007,
                         member_count: 10,
                         trophies: 20000,
                         rank: 4,
                     }
-                ]
+                ],
+                paging: None,
             }
         );
 
+        // serializing gives back the API's JSON
+        assert_eq!(
+            serde_json::to_value(&c_leaders)?,
+            serde_json::from_str::<serde_json::Value>(rc_json_s)?
+        );
+
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Tests that fetched leaderboards keep their parameters, so that refetching repeats the same
+    /// request, and that deserialized ones can't be refetched.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn rankings_clubs_refetch() {
+        use crate::traits::PropLimFetchable;
+        use super::super::test_util::assert_refetch;
+
+        let leaders = assert_refetch(
+            |client| ClubLeaderboard::fetch(client, "ZW", 5).unwrap(),
+            "/rankings/ZW/clubs?limit=5",
+        );
+        assert_eq!((leaders.country_code.as_str(), leaders.limit), ("ZW", 5));
+    }
+}
@@ -1,5 +1,16 @@
 //! Models for all `/rankings/:country_code/...` Brawl Stars API endpoints.
 //! Included by the feature `"rankings"`; removing that feature will disable the usage of this module.
+//!
+//! # Refetching
+//!
+//! Refetching a leaderboard (see [`Refetchable`]) reuses the parameters given when fetching it
+//! (its country code and limit, and the brawler for brawler leaderboards), which only fetched
+//! leaderboards know, as they aren't part of the API's JSON. A deserialized leaderboard has an
+//! empty country code and a limit of `0`, so refetching it fails with an
+//! [`Error::InvalidParameter`]; fetch it with the parameters instead.
+//!
+//! [`Refetchable`]: ../../traits/trait.Refetchable.html
+//! [`Error::InvalidParameter`]: ../../error/enum.Error.html#variant.InvalidParameter
 
 pub mod players;
 pub use players::*;
@@ -9,3 +20,6 @@ pub use clubs::*;
 
 pub mod brawlers;
 pub use brawlers::*;
+
+#[cfg(all(test, any(feature = "blocking", feature = "ureq")))]
+pub(crate) mod test_util;
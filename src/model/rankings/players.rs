@@ -2,19 +2,23 @@
 //! Included by the feature `"rankings"`; removing that feature will disable the usage of this module.
 
 use serde::{self, Serialize, Deserialize};
-use crate::traits::{PropLimRouteable, PropLimFetchable};
-use crate::serde::{one_default, oxffffff_default, deserialize_number_from_string};
+use crate::traits::{PropLimRouteable, PropLimFetchable, Refetchable, Endpoint, RankingParams};
+use crate::serde::{
+    one_default, oxffffff_default, deserialize_number_from_string, serialize_hex_string,
+};
 use std::ops::{Deref, DerefMut};
-use crate::util::fetch_route;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::error::Result;
 
 #[cfg(feature = "async")]
 use async_trait::async_trait;
 
-#[cfg(feature = "async")]
-use crate::util::a_fetch_route;
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::http::Client;
+
 use crate::http::routes::Route;
+use super::super::common::{PlayerIcon, Paging};
 
 /// Represents a leaderboard of [`PlayerRanking`]s - the top x players in a regional or global
 /// leaderboard, sorted by total trophies.
@@ -24,9 +28,24 @@ use crate::http::routes::Route;
 /// [`PlayerRanking`]: ./struct.PlayerRanking.html
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PlayerLeaderboard {
+    /// The country code (or `"global"`) of this leaderboard, as given when fetching.
+    #[serde(skip)]  // artificial
+    pub country_code: String,
+
+    /// The limit of rankings used when fetching this leaderboard.
+    #[serde(skip)]  // artificial
+    pub limit: u8,
+
     /// The players in the ranking.
     #[serde(default)]
     pub items: Vec<PlayerRanking>,
+
+    /// The paging information given by the API along with the rankings, if any (see [`Paging`]).
+    ///
+    /// [`Paging`]: ../../common/struct.Paging.html
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<Paging>,
 }
 
 impl Deref for PlayerLeaderboard {
@@ -155,9 +174,9 @@ impl PropLimFetchable for PlayerLeaderboard {
     /// [`Error::Status`]: error/enum.Error.html#variant.Status
     /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
     /// [`Error::Json`]: error/enum.Error.html#variant.Json
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch(client: &Client, country_code: &str, limit: u8) -> Result<PlayerLeaderboard> {
-        let route = PlayerLeaderboard::get_route(country_code, limit);
-        fetch_route::<PlayerLeaderboard>(client, &route)
+        client.get::<PlayerLeaderboard>(&RankingParams::new(country_code, limit))
     }
 
     /// (Async) Fetches the top `limit` players in the regional (two-letter) `country_code`
@@ -228,8 +247,7 @@ impl PropLimFetchable for PlayerLeaderboard {
         where Self: 'async_trait,
               Self::Property: 'async_trait,
     {
-        let route = PlayerLeaderboard::get_route(&country_code, limit);
-        a_fetch_route::<PlayerLeaderboard>(client, &route).await
+        client.a_get::<PlayerLeaderboard>(&RankingParams::new(country_code, limit)).await
     }
 }
 
@@ -247,6 +265,40 @@ impl PropLimRouteable for PlayerLeaderboard {
     }
 }
 
+impl Endpoint for PlayerLeaderboard {
+    type Params = RankingParams;
+
+    fn route(params: &RankingParams) -> Route {
+        PlayerLeaderboard::get_route(&params.country_code, params.limit)
+    }
+
+    /// Sets the artificial [`country_code`] and [`limit`] fields to the ones used for fetching.
+    ///
+    /// [`country_code`]: #structfield.country_code
+    /// [`limit`]: #structfield.limit
+    fn on_fetched(&mut self, params: &RankingParams) {
+        self.country_code = params.country_code.clone();
+        self.limit = params.limit;
+    }
+}
+
+/// Refetching reuses the parameters given when fetching, so it fails for deserialized
+/// leaderboards (see [the `rankings` module](../index.html#refetching)).
+#[cfg_attr(feature = "async", async_trait)]
+impl Refetchable for PlayerLeaderboard {
+    /// (Sync) Fetches this leaderboard again, with the same country code and limit.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    fn refetch(&self, client: &Client) -> Result<PlayerLeaderboard> {
+        PlayerLeaderboard::fetch(client, &self.country_code, self.limit)
+    }
+
+    /// (Async) Fetches this leaderboard again, with the same country code and limit.
+    #[cfg(feature = "async")]
+    async fn a_refetch(&self, client: &Client) -> Result<PlayerLeaderboard> {
+        PlayerLeaderboard::a_fetch(client, &self.country_code, self.limit).await
+    }
+}
+
 /// Represents a player's ranking, based on a regional or global leaderboard.
 /// To obtain the player's full data (a [`Player`] instance), see [`Player::fetch_from`].
 ///
@@ -271,6 +323,12 @@ pub struct PlayerRanking {
     #[serde(default)]
     pub name: String,
 
+    /// The player's profile icon (see [`PlayerIcon`]).
+    ///
+    /// [`PlayerIcon`]: ../../common/struct.PlayerIcon.html
+    #[serde(default)]
+    pub icon: PlayerIcon,
+
     /// The player's trophies.
     #[serde(default)]
     pub trophies: usize,
@@ -282,9 +340,44 @@ pub struct PlayerRanking {
     /// The player's name color. Defaults to `0xffffff` (white).
     #[serde(default = "oxffffff_default")]
     #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(serialize_with = "serialize_hex_string")]  // "0x..." str, like the API
     pub name_color: u64,
 }
 
+impl Default for PlayerRanking {
+    /// Returns an instance of `PlayerRanking` with initial values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brawl_api::{PlayerRanking, PlayerRankingClub, PlayerIcon};
+    ///
+    /// assert_eq!(
+    ///     PlayerRanking::default(),
+    ///     PlayerRanking {
+    ///         club: PlayerRankingClub::default(),
+    ///         tag: String::from(""),
+    ///         name: String::from(""),
+    ///         icon: PlayerIcon::default(),
+    ///         trophies: 0,
+    ///         rank: 1,
+    ///         name_color: 0xff_ff_ff,
+    ///     }
+    /// );
+    /// ```
+    fn default() -> PlayerRanking {
+        PlayerRanking {
+            club: PlayerRankingClub::default(),
+            tag: String::from(""),
+            name: String::from(""),
+            icon: PlayerIcon::default(),
+            trophies: 0,
+            rank: 1,
+            name_color: 0xff_ff_ff,
+        }
+    }
+}
+
 /// Represents the club in a player's ranking (a [`PlayerRanking`] object). Since the only data
 /// available at the moment is its name, it cannot be converted into a full [`Club`] object
 /// using a convenient method. For that, one must have the original `PlayerRanking` object,
@@ -335,6 +428,7 @@ mod tests {
     use serde_json;
     use super::{PlayerLeaderboard, PlayerRanking, PlayerRankingClub};
     use crate::error::Error;
+    use crate::model::common::{PlayerIcon, Paging};
 
     /// Tests for PlayerLeaderboard deserialization from API-provided JSON.
     #[test]
@@ -346,6 +440,9 @@ mod tests {
       "tag": "#AAAAAAAAA",
       "name": "Player",
       "nameColor": "0xfff05637",
+      "icon": {
+        "id": 28000010
+      },
       "trophies": 30000,
       "rank": 1,
       "club": {
@@ -356,6 +453,9 @@ mod tests {
       "tag": "#EEEEEEE",
       "name": "Also Player",
       "nameColor": "0xffa2e3fe",
+      "icon": {
+        "id": 28000000
+      },
       "trophies": 25000,
       "rank": 2,
       "club": {
@@ -366,6 +466,9 @@ mod tests {
       "tag": "#QQQQQQQ",
       "name": "Youtuber",
       "nameColor": "0xfff05637",
+      "icon": {
+        "id": 28000001
+      },
       "trophies": 23000,
       "rank": 3,
       "club": {
@@ -376,6 +479,9 @@ mod tests {
       "tag": "#55555553Q",
       "name": "Not a valid player",
       "nameColor": "0xfff9cf08",
+      "icon": {
+        "id": 28000042
+      },
       "trophies": 20000,
       "rank": 4,
       "club": {
@@ -395,11 +501,14 @@ mod tests {
         assert_eq!(
             p_leaders,
             PlayerLeaderboard {
+                country_code: String::new(),
+                limit: 0,
                 items: vec![
                     PlayerRanking {
                         tag: String::from("#AAAAAAAAA"),
                         name: String::from("Player"),
                         name_color: 0xfff05637,
+                        icon: PlayerIcon { id: 28000010 },
                         trophies: 30000,
                         rank: 1,
                         club: PlayerRankingClub {
@@ -410,6 +519,7 @@ mod tests {
                         tag: String::from("#EEEEEEE"),
                         name: String::from("Also Player"),
                         name_color: 0xffa2e3fe,
+                        icon: PlayerIcon { id: 28000000 },
                         trophies: 25000,
                         rank: 2,
                         club: PlayerRankingClub {
@@ -420,6 +530,7 @@ mod tests {
                         tag: String::from("#QQQQQQQ"),
                         name: String::from("Youtuber"),
                         name_color: 0xfff05637,
+                        icon: PlayerIcon { id: 28000001 },
                         trophies: 23000,
                         rank: 3,
                         club: PlayerRankingClub {
@@ -430,16 +541,39 @@ mod tests {
                         tag: String::from("#55555553Q"),
                         name: String::from("Not a valid player"),
                         name_color: 0xfff9cf08,
+                        icon: PlayerIcon { id: 28000042 },
                         trophies: 20000,
                         rank: 4,
                         club: PlayerRankingClub {
                             name: String::from("Different Club")
                         }
                     }
-                ]
+                ],
+                paging: Some(Paging::default()),
             }
         );
         
+        // serializing gives back the API's JSON
+        assert_eq!(
+            serde_json::to_value(&p_leaders)?,
+            serde_json::from_str::<serde_json::Value>(rp_json_s)?
+        );
+
         Ok(())
     }
+
+    /// Tests that fetched leaderboards keep their parameters, so that refetching repeats the same
+    /// request, and that deserialized ones can't be refetched.
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn rankings_players_refetch() {
+        use crate::traits::PropLimFetchable;
+        use super::super::test_util::assert_refetch;
+
+        let leaders = assert_refetch(
+            |client| PlayerLeaderboard::fetch(client, "ZW", 5).unwrap(),
+            "/rankings/ZW/players?limit=5",
+        );
+        assert_eq!((leaders.country_code.as_str(), leaders.limit), ("ZW", 5));
+    }
 }
@@ -0,0 +1,55 @@
+//! Checks shared by the tests of every leaderboard's [`Refetchable`] implementation.
+//!
+//! [`Refetchable`]: ../../../traits/trait.Refetchable.html
+
+use std::fmt::Debug;
+use std::ops::DerefMut;
+use std::sync::{Arc, Mutex};
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+use crate::error::Error;
+use crate::http::{Client, Middleware, RawResponse};
+use crate::http::request::Request;
+use crate::traits::Refetchable;
+
+/// Records the endpoint of every request, answering each with a leaderboard of one ranking.
+struct Rankings(Arc<Mutex<Vec<String>>>);
+
+impl Middleware for Rankings {
+    fn on_request(&self, request: &mut Request<'_>) -> Option<RawResponse> {
+        self.0.lock().unwrap().push(request.endpoint.clone());
+        Some(RawResponse::new(StatusCode::OK, r##"{"items": [{"tag": "#AAA"}]}"##))
+    }
+}
+
+/// Fetches a leaderboard through `fetch`, then checks that refetching it (with both `refetch`
+/// and `refetch_update`) repeats the same request, to an endpoint ending with `endpoint`, and
+/// that a deserialized leaderboard can't be refetched. Returns the fetched leaderboard, so that
+/// the parameters it kept can be checked.
+pub(crate) fn assert_refetch<L, T, F>(fetch: F, endpoint: &str) -> L
+    where
+        L: Refetchable + DeserializeOwned + DerefMut<Target = Vec<T>> + Clone + PartialEq + Debug,
+        F: FnOnce(&Client) -> L,
+{
+    let endpoints = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::new("my auth key").with_middleware(Rankings(Arc::clone(&endpoints)));
+
+    let fetched = fetch(&client);
+    let refetched = fetched.refetch(&client).unwrap();
+    assert_eq!(refetched, fetched);
+
+    let mut leaders = fetched.clone();
+    leaders.clear();
+    leaders.refetch_update(&client).unwrap();
+    assert_eq!(leaders, refetched);
+
+    let endpoints = endpoints.lock().unwrap();
+    assert_eq!(endpoints.len(), 3);
+    assert!(endpoints[0].ends_with(endpoint), "{}", endpoints[0]);
+    assert!(endpoints.iter().all(|requested| *requested == endpoints[0]));
+
+    let deserialized = serde_json::from_str::<L>(r#"{"items": []}"#).unwrap();
+    assert!(matches!(deserialized.refetch(&client), Err(Error::InvalidParameter { .. })));
+
+    fetched
+}
@@ -1,6 +1,6 @@
 #![doc(hidden)]
 use std::str::FromStr;
-use std::fmt::Display;
+use std::fmt::{Display, LowerHex};
 use std::result::Result as StdResult;  // in order to not confuse with the library's Result
 use serde::{self, Deserializer, Deserialize, Serialize, Serializer};
 use num_traits::{PrimInt, Num as NTraitNum};
@@ -35,6 +35,18 @@ pub(crate) fn deserialize_number_from_string<'de, T, D>(deserializer: D) -> Resu
     }
 }
 
+/// Serializes a number as a hexadecimal string (e.g. `"0xff1ba5f5"`), which is how the API gives
+/// name colors (see [`deserialize_number_from_string`]).
+///
+/// [`deserialize_number_from_string`]: fn.deserialize_number_from_string.html
+pub(crate) fn serialize_hex_string<T, S>(number: &T, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: LowerHex,
+{
+    serializer.collect_str(&format_args!("0x{:x}", number))
+}
+
 /// Serializes a smart pointer class.
 pub(crate) fn serialize_smt_pointer<T, S>(
     object: &T, serializer: S
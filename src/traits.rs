@@ -6,15 +6,21 @@
 //! `PropLimFetchable` (there are some that do not implement either, and rather have their own
 //! implementation of a `fetch` function, because they have 3 or more parameters).
 
-use crate::error::{Result};
-
 #[cfg(feature = "async")]
 use async_trait::async_trait;
-use crate::http::Client;
-// use serde::de::DeserializeOwned;
+use serde::de::DeserializeOwned;
 
 use crate::http::routes::Route;
 
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::error::Result;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::http::Client;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::http::fetched::Fetched;
+
 pub mod propfetch {
     use super::*;
 
@@ -96,6 +102,7 @@ pub mod propfetch {
         /// [`Error::Status`]: error/enum.Error.html#variant.Status
         /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
         /// [`Error::Json`]: error/enum.Error.html#variant.Json
+        #[cfg(any(feature = "blocking", feature = "ureq"))]
         fn fetch(client: &Client, prop: &Self::Property) -> Result<Self>;
 
         /// (Async) Fetch and construct a new instance of this type.
@@ -135,6 +142,54 @@ pub mod propfetch {
         async fn a_fetch(client: &Client, prop: &'async_trait Self::Property) -> Result<Self>
             where Self: 'async_trait,
                   Self::Property: 'async_trait;
+
+        /// (Sync) Like `fetch`, but also returns metadata about the response (status, headers,
+        /// fetch time and ratelimit state), through a [`Fetched`] instance.
+        ///
+        /// # Errors
+        ///
+        /// See `fetch`.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,ignore
+        /// use brawl_api::{Client, Player, traits::*};
+        ///
+        /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+        /// let my_client = Client::new("my auth token");
+        /// let fetched = Player::fetch_with_meta(&my_client, "#PLAYERTAGHERE")?;
+        /// let remaining_requests = fetched.ratelimit.remaining;
+        /// let player = fetched.into_inner();
+        ///
+        /// #     Ok(())
+        /// # }
+        /// ```
+        ///
+        /// [`Fetched`]: ../../http/fetched/struct.Fetched.html
+        #[cfg(any(feature = "blocking", feature = "ureq"))]
+        fn fetch_with_meta(client: &Client, prop: &Self::Property) -> Result<Fetched<Self>>
+            where Self: Endpoint<Params = <Self as PropFetchable>::Property>,
+        {
+            client.get_with_meta::<Self>(prop)
+        }
+
+        /// (Async) Like `a_fetch`, but also returns metadata about the response (status,
+        /// headers, fetch time and ratelimit state), through a [`Fetched`] instance.
+        ///
+        /// # Errors
+        ///
+        /// See `fetch`.
+        ///
+        /// [`Fetched`]: ../../http/fetched/struct.Fetched.html
+        #[cfg(feature = "async")]
+        async fn a_fetch_with_meta(
+            client: &Client, prop: &'async_trait Self::Property
+        ) -> Result<Fetched<Self>>
+            where Self: Endpoint<Params = <Self as PropFetchable>::Property> + Send + 'async_trait,
+                  Self::Property: Sync + 'async_trait,
+        {
+            client.a_get_with_meta::<Self>(prop).await
+        }
     }
 }
 
@@ -205,6 +260,7 @@ pub mod proplimfetch {
         /// [`Error::Status`]: error/enum.Error.html#variant.Status
         /// [`Error::Ratelimited`]: error/enum.Error.html#variant.Ratelimited
         /// [`Error::Json`]: error/enum.Error.html#variant.Json
+        #[cfg(any(feature = "blocking", feature = "ureq"))]
         fn fetch(client: &Client, prop: &Self::Property, limit: Self::Limit) -> Result<Self>;
 
         /// (Async) Fetch and construct a new instance of this type.
@@ -252,11 +308,139 @@ pub mod proplimfetch {
             where Self: 'async_trait,
                   Self::Property: 'async_trait,
                   Self::Limit: 'async_trait;
+
+        /// (Sync) Like `fetch`, but also returns metadata about the response (status, headers,
+        /// fetch time and ratelimit state), through a [`Fetched`] instance.
+        ///
+        /// # Errors
+        ///
+        /// See `fetch`.
+        ///
+        /// [`Fetched`]: ../../http/fetched/struct.Fetched.html
+        #[cfg(any(feature = "blocking", feature = "ureq"))]
+        fn fetch_with_meta(
+            client: &Client, prop: &Self::Property, limit: Self::Limit
+        ) -> Result<Fetched<Self>>
+            where Self: Endpoint<Params = RankingParams>,
+                  Self::Property: AsRef<str>,
+                  Self::Limit: Into<u8>,
+        {
+            client.get_with_meta::<Self>(&RankingParams::new(prop.as_ref(), limit.into()))
+        }
+
+        /// (Async) Like `a_fetch`, but also returns metadata about the response (status,
+        /// headers, fetch time and ratelimit state), through a [`Fetched`] instance.
+        ///
+        /// # Errors
+        ///
+        /// See `fetch`.
+        ///
+        /// [`Fetched`]: ../../http/fetched/struct.Fetched.html
+        #[cfg(feature = "async")]
+        async fn a_fetch_with_meta(
+            client: &Client, prop: &'async_trait Self::Property, limit: Self::Limit
+        ) -> Result<Fetched<Self>>
+            where Self: Endpoint<Params = RankingParams> + Send + 'async_trait,
+                  Self::Property: AsRef<str> + Sync + 'async_trait,
+                  Self::Limit: Into<u8> + Send + 'async_trait,
+        {
+            let params = RankingParams::new(prop.as_ref(), limit.into());
+            client.a_get_with_meta::<Self>(&params).await
+        }
     }
 }
 
 pub use proplimfetch::*;
 
+pub mod endpoint {
+    use super::*;
+    use crate::ids::BrawlerId;
+
+    /// A trait representing a model which is the output of one of the API's endpoints, and
+    /// which can be fetched by specifying some parameters (see [`Params`]) that are converted
+    /// into a [`Route`].
+    ///
+    /// This is the trait used by the generic [`Client::get`] and [`Client::a_get`] methods
+    /// (and, in turn, by all of the `Client` facade methods, such as [`Client::player`]).
+    /// The older fetching traits ([`PropFetchable`], [`PropLimFetchable`]) and the `fetch`
+    /// functions of each model are thin wrappers over it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use brawl_api::{Client, Player, traits::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// let my_client = Client::new("my auth token");
+    /// let player = my_client.get::<Player>("#PLAYERTAGHERE")?;
+    /// // equivalent to Player::fetch(&my_client, "#PLAYERTAGHERE")?
+    ///
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Params`]: #associatedtype.Params
+    /// [`Route`]: ../../http/routes/enum.Route.html
+    /// [`Client::get`]: ../../http/client/struct.Client.html#method.get
+    /// [`Client::a_get`]: ../../http/client/struct.Client.html#method.a_get
+    /// [`Client::player`]: ../../http/client/struct.Client.html#method.player
+    /// [`PropFetchable`]: ../propfetch/trait.PropFetchable.html
+    /// [`PropLimFetchable`]: ../proplimfetch/trait.PropLimFetchable.html
+    pub trait Endpoint: DeserializeOwned + Sized {
+        /// The parameters required for fetching (e.g. `str` for a tag).
+        type Params: ?Sized;
+
+        /// Obtain the route for fetching by using the given parameters.
+        fn route(params: &Self::Params) -> Route;
+
+        /// Performs any post-processing on a freshly-fetched instance, such as filling in
+        /// artificial fields (which are not given by the API). Does nothing by default.
+        fn on_fetched(&mut self, _params: &Self::Params) {}
+    }
+
+    /// Parameters for fetching player or club rankings: a country code (or `"global"`) and a
+    /// limit of rankings to get.
+    #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+    pub struct RankingParams {
+        /// The two-letter country code of the leaderboard, or `"global"` for global rankings.
+        pub country_code: String,
+
+        /// The limit of rankings to get (at most `200`).
+        pub limit: u8,
+    }
+
+    impl RankingParams {
+        /// Creates a new `RankingParams` instance with the given country code and limit.
+        pub fn new(country_code: &str, limit: u8) -> RankingParams {
+            RankingParams { country_code: country_code.to_owned(), limit }
+        }
+    }
+
+    /// Parameters for fetching brawler rankings: a country code (or `"global"`), the ID of the
+    /// brawler and a limit of rankings to get.
+    #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+    pub struct BrawlerRankingParams {
+        /// The two-letter country code of the leaderboard, or `"global"` for global rankings.
+        pub country_code: String,
+
+        /// The ID of the brawler whose rankings should be fetched.
+        pub brawler_id: BrawlerId,
+
+        /// The limit of rankings to get (at most `200`).
+        pub limit: u8,
+    }
+
+    impl BrawlerRankingParams {
+        /// Creates a new `BrawlerRankingParams` instance with the given country code, brawler
+        /// ID and limit.
+        pub fn new(country_code: &str, brawler_id: BrawlerId, limit: u8) -> BrawlerRankingParams {
+            BrawlerRankingParams { country_code: country_code.to_owned(), brawler_id, limit }
+        }
+    }
+}
+
+pub use endpoint::*;
+
 // endregion:PropFetch
 
 /// A trait representing a type whose instance can be fetched again.
@@ -288,6 +472,7 @@ pub trait Refetchable: Sized {
     /// #     Ok(())
     /// # }
     /// ```
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn refetch(&self, client: &Client) -> Result<Self>;
 
     /// (Sync) Like `refetch`, but mutates the instance, returning an immutable reference to it.
@@ -295,6 +480,7 @@ pub trait Refetchable: Sized {
     /// Its usage and errors are the same (it is called, after all), but the old variable does
     /// not need to be assigned; rather, that is done for the programmer (the variable's value
     /// **is entirely replaced** by a new one, if the fetching is successful).
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn refetch_update(&mut self, client: &Client) -> Result<&Self> {
         *self = self.refetch(client)?;
         Ok(self as &Self)
@@ -342,6 +528,7 @@ pub trait Refetchable: Sized {
 impl<T> Refetchable for T
     where T: PropFetchable<Property=<T as GetFetchProp>::Property> + GetFetchProp + Sized + Send + Sync,
           <T as GetFetchProp>::Property: Sync + Send {
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn refetch(&self, client: &Client) -> Result<Self> {
         Self::fetch(client, self.get_fetch_prop())
     }
@@ -386,6 +573,7 @@ pub trait FetchFrom<T>: Sized {
     /// #     Ok(())
     /// # }
     /// ```
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(client: &Client, value: &T) -> Result<Self>;
 
     /// (Async) Attempts to request to the API and return a new instance of the type being turned
@@ -433,6 +621,7 @@ pub trait FetchInto<T>: Sized {
     /// # Examples
     ///
     /// See [`FetchFrom::<T>::fetch_from`].
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_into(&self, client: &Client) -> Result<T>;
 
     #[cfg(feature = "async")]
@@ -456,6 +645,7 @@ pub trait FetchInto<T>: Sized {
 impl<T, U> FetchInto<U> for T
     where T: Sync + Send, U: FetchFrom<T> + Sync + Send
 {
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_into(&self, client: &Client) -> Result<U> {
         U::fetch_from(client, self)
     }
@@ -480,6 +670,7 @@ impl<T: Sync + Send + Clone> FetchFrom<T> for T {
     ///
     /// [`Refetchable`]: trait.Refetchable.html
     /// [`Result`]: ../error/type.Result.html
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
     fn fetch_from(_: &Client, t: &T) -> Result<T> { Ok(t.to_owned()) }
 
     /// (Async) Returns a copy of the current instance when attempting to fetch from itself.
@@ -1,59 +1,433 @@
-use std::result::Result as StdResult;
-use serde::de::DeserializeOwned;
 use serde_json::{Map as SerdeJsonMap, Value};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use serde::de::DeserializeOwned;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::error::{Result, Error};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
 use crate::http::Client;
-use crate::http::routes::Route;
-use reqwest::{Error as ReqwestError, StatusCode};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::http::routes::{Route, RouteKind};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::http::request::Request;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::http::fetched::{Fetched, RatelimitInfo};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::http::middleware::RawResponse;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use crate::http::trace::RequestTrace;
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use std::time::{Instant, SystemTime};
+
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+use http::StatusCode;
+
+#[cfg(any(all(feature = "blocking", not(feature = "ureq")), feature = "async"))]
+use std::result::Result as StdResult;
+
+#[cfg(any(all(feature = "blocking", not(feature = "ureq")), feature = "async"))]
+use reqwest::Error as ReqwestError;
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+use crate::http::secret::AuthKey;
+
+#[cfg(all(feature = "blocking", not(feature = "ureq")))]
 use reqwest::blocking::{
     Response,
 };
 
+#[cfg(feature = "ureq")]
+use std::io::Read;
+
+#[cfg(feature = "ureq")]
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+
+#[cfg(feature = "ureq")]
+use ureq::Error as UreqError;
+
 #[cfg(feature = "async")]
 use reqwest::{
     Response as AResponse,
 };
 
 pub(crate) fn auto_hashtag(tag: &str) -> String {
-    let mut new_tag = tag.to_owned();
     if tag.starts_with('#') {
-        new_tag = new_tag.replacen("#", "%23", 1);
-    } else if cfg!(feature = "auto-hashtag") && !tag.starts_with("%23") {
-        new_tag = format!("%23{}", new_tag);  // automtically add the hashtag if missing
+        tag.to_owned()
+    } else if let Some(rest) = tag.strip_prefix("%23") {
+        format!("#{}", rest)  // the hashtag is percent-encoded when building the route's URL
+    } else if cfg!(feature = "auto-hashtag") {
+        format!("#{}", tag)  // automtically add the hashtag if missing
+    } else {
+        tag.to_owned()
     }
-    new_tag
 }
 
 pub(crate) type JsonMap = SerdeJsonMap<String, Value>;
 
+/// Creates the request for a route, after validating its parameters (see [`Route::to_url`]).
+///
+/// [`Route::to_url`]: ../http/routes/enum.Route.html#method.to_url
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+pub(crate) fn route_request<'a>(client: &'a Client, route: &Route) -> Result<Request<'a>> {
+    Ok(client.endpoint_request(route.to_url()?.as_str()))
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+/// (Sync) Sends a request with the client's authentication (using a key from its key pool, if
+/// any), running the client's middleware, and returns the complete response if it was
+/// successful, or the respective error otherwise.
+pub(crate) fn send_request(client: &Client, request: &Request<'_>) -> Result<RawResponse> {
+    let mut request = request.clone();
+    let (ran, short_circuit) = client.middleware.run_request(&mut request);
+    let trace = RequestTrace::new(&request, short_circuit.is_some());
+    let start = Instant::now();
+
+    let mut picked = None;
+    let result = match short_circuit {
+        Some(response) => Ok(response),
+        None => trace.in_scope(|| {
+            picked = client.key_pool.as_ref().map(|pool| pool.pick());
+            let key = picked.map_or(&client.auth_key, |(_, key)| key);
+
+            send_raw(client, &request, key)
+        }),
+    };
+
+    let result = complete_request(client, ran, &request, &trace, start, result);
+
+    if let (Some(pool), Some((index, _))) = (client.key_pool.as_ref(), picked) {
+        pool.report(index, result.as_ref().err());
+    }
+
+    result
+}
+
+/// (Sync) Sends a (built) request through `reqwest`'s blocking client, and reads its response.
+#[cfg(all(feature = "blocking", not(feature = "ureq")))]
+fn send_raw(client: &Client, request: &Request<'_>, key: &AuthKey) -> Result<RawResponse> {
+    request.build_with_key(client, key)
+        .and_then(|request_b| {
+            let response: StdResult<Response, ReqwestError> = request_b.send();
+            response.map_err(Error::from)
+        })
+        .and_then(|response| {
+            let status: StatusCode = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().map_err(Error::from)?;
+            Ok(RawResponse { status, headers, body: body.to_vec() })
+        })
+}
+
+/// (Sync) Sends a (built) request through the client's `ureq` agent, and reads its response.
+/// Responses with a bad status code are read as well, so that they are turned into errors the
+/// same way as with `reqwest`.
+#[cfg(feature = "ureq")]
+fn send_raw(client: &Client, request: &Request<'_>, key: &AuthKey) -> Result<RawResponse> {
+    let request_b = request.u_build_with_key(client, key)?;
+    let sent = match request.body {
+        Some(body) => request_b.send_bytes(body),
+        None => request_b.call(),
+    };
+
+    let response = match sent {
+        Ok(response) | Err(UreqError::Status(_, response)) => response,
+        Err(err) => return Err(Error::from(err)),
+    };
+
+    // ureq only gives out 3-digit status codes, which are always valid
+    let status = StatusCode::from_u16(response.status()).unwrap_or(StatusCode::BAD_GATEWAY);
+
+    let mut headers = HeaderMap::new();
+    for name in response.headers_names() {
+        for value in response.all(&name) {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()), HeaderValue::from_str(value)
+            ) {
+                headers.append(name, value);
+            }
+        }
+    }
+
+    let mut body = Vec::new();
+    response.into_reader().read_to_end(&mut body)
+        .map_err(|err| Error::from(UreqError::from(err)))?;
+
+    Ok(RawResponse { status, headers, body })
+}
+
+/// Completes a request: runs the `on_response` hooks of the middleware that ran for it (if there
+/// was a response), checks the response's status, and records the outcome in the client's
+/// metrics and the request's trace.
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+fn complete_request(
+    client: &Client, ran: usize, request: &Request<'_>, trace: &RequestTrace, start: Instant,
+    result: Result<RawResponse>,
+) -> Result<RawResponse> {
+    let kind = RouteKind::from_endpoint(&request.endpoint);
+
+    let result = match result {
+        Ok(mut response) => {
+            client.middleware.run_response(ran, request, &mut response);
+            trace.response(&response);
+
+            let error = if response.status.is_success() {
+                None
+            } else {
+                Some(Error::from_raw_response(&response))
+            };
+            client.metrics.record(kind, Some(&response), error.as_ref(), start.elapsed());
+
+            match error {
+                Some(error) => Err(error),
+                None => Ok(response),
+            }
+        },
+
+        Err(error) => {
+            client.metrics.record(kind, None, Some(&error), start.elapsed());
+            Err(error)
+        },
+    };
+
+    trace.finish(&result);
+    result
+}
+
+/// Deserializes the (JSON) body of a response.
+#[cfg(any(feature = "blocking", feature = "ureq", feature = "async"))]
+pub(crate) fn deserialize_body<T>(body: &[u8]) -> Result<T>
+    where T: DeserializeOwned {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::trace_span!(
+        "brawl_api.deserialize", model = ::std::any::type_name::<T>(), size = body.len()
+    ).entered();
+
+    let result = serde_json::from_slice::<T>(body).map_err(Error::Json);
+
+    #[cfg(feature = "tracing")]
+    {
+        if let Err(ref err) = result {
+            tracing::warn!(error = %err, "failed to deserialize response");
+        }
+    }
+
+    result
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
 /// (Sync) Fetches a deserializable struct/enum/... from some route.
+///
+/// If the client has request coalescing enabled, identical in-flight requests are shared.
 pub(crate) fn fetch_route<T>(client: &Client, route: &Route) -> Result<T>
     where T: DeserializeOwned {
-    let request_b = client.build_endpoint_get(&*route.to_url_str())?;
-    let response: StdResult<Response, ReqwestError> = request_b.send();
-    let response = response.map_err(Error::Request)?;
+    if let Some(ref coalescer) = client.coalescer {
+        let body = coalescer.fetch(client, route)?;
+        return deserialize_body::<T>(&body);
+    }
 
-    let status: StatusCode = response.status();
-    if status.is_success() {
-        serde_json::from_reader::<Response, T>(response).map_err(Error::Json)
-    } else {
-        Err(Error::from_response(response, None))
+    request_json::<T>(client, &route_request(client, route)?)
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+/// (Sync) Sends an arbitrary request, deserializing the (successful) response's JSON body.
+pub(crate) fn request_json<T>(client: &Client, request: &Request<'_>) -> Result<T>
+    where T: DeserializeOwned {
+    let response = send_request(client, request)?;
+    deserialize_body::<T>(&response.body)
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+/// (Sync) Fetches the raw body of a successful response from some route.
+pub(crate) fn fetch_route_raw(client: &Client, route: &Route) -> Result<Vec<u8>> {
+    let response = send_request(client, &route_request(client, route)?)?;
+    Ok(response.body)
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+/// (Sync) Fetches a deserializable struct/enum/... from some route, alongside metadata about the
+/// response. (This is never coalesced, as each caller needs their own response's metadata.)
+pub(crate) fn fetch_route_meta<T>(client: &Client, route: &Route) -> Result<Fetched<T>>
+    where T: DeserializeOwned {
+    let response = send_request(client, &route_request(client, route)?)?;
+    let fetched_at = SystemTime::now();
+
+    let value = deserialize_body::<T>(&response.body)?;
+
+    Ok(Fetched {
+        value,
+        status: response.status,
+        ratelimit: RatelimitInfo::from_headers(&response.headers),
+        headers: response.headers,
+        fetched_at,
+    })
+}
+
+/// (Async) Sends a request with the client's authentication (using a key from its key pool, if
+/// any), running the client's middleware, and returns the complete response if it was
+/// successful, or the respective error otherwise.
+#[cfg(feature = "async")]
+pub(crate) async fn a_send_request(
+    client: &Client, request: &Request<'_>
+) -> Result<RawResponse> {
+    let mut request = request.clone();
+    let (ran, short_circuit) = client.middleware.run_request(&mut request);
+    let trace = RequestTrace::new(&request, short_circuit.is_some());
+    let start = Instant::now();
+
+    let mut picked = None;
+    let result = match short_circuit {
+        Some(response) => Ok(response),
+        None => trace.instrument(async {
+            picked = client.key_pool.as_ref().map(|pool| pool.pick());
+            let key = picked.map_or(&client.auth_key, |(_, key)| key);
+
+            match request.a_build_with_key(client, key) {
+                Ok(request_b) => {
+                    let response: StdResult<AResponse, ReqwestError> = request_b.send().await;
+                    match response.map_err(Error::from) {
+                        Ok(response) => {
+                            let status: StatusCode = response.status();
+                            let headers = response.headers().clone();
+                            response.bytes().await
+                                .map(|body| RawResponse { status, headers, body: body.to_vec() })
+                                .map_err(Error::from)
+                        },
+                        Err(err) => Err(err),
+                    }
+                },
+                Err(err) => Err(err),
+            }
+        }).await,
+    };
+
+    let result = complete_request(client, ran, &request, &trace, start, result);
+
+    if let (Some(pool), Some((index, _))) = (client.key_pool.as_ref(), picked) {
+        pool.report(index, result.as_ref().err());
     }
+
+    result
 }
 
 /// (Async) Fetches a deserializable struct/enum/... from some route.
+///
+/// If the client has request coalescing enabled, identical in-flight requests are shared.
 #[cfg(feature = "async")]
 pub(crate) async fn a_fetch_route<T>(client: &Client, route: &Route) -> Result<T>
     where T: DeserializeOwned {
-    let request_b = client.a_build_endpoint_get(&*route.to_url_str())?;
-    let response: StdResult<AResponse, ReqwestError> = request_b.send().await;
-    let response = response.map_err(Error::Request)?;
-
-    let status: StatusCode = response.status();
-    if status.is_success() {
-        let full_bytes = response.bytes().await.map_err(Error::Request)?;
-        serde_json::from_slice::<T>(&full_bytes).map_err(Error::Json)
-    } else {
-        Err(Error::a_from_response(response, None).await)
+    if let Some(ref coalescer) = client.coalescer {
+        let body = coalescer.a_fetch(client, route).await?;
+        return deserialize_body::<T>(&body);
+    }
+
+    a_request_json::<T>(client, &route_request(client, route)?).await
+}
+
+/// (Async) Sends an arbitrary request, deserializing the (successful) response's JSON body.
+#[cfg(feature = "async")]
+pub(crate) async fn a_request_json<T>(client: &Client, request: &Request<'_>) -> Result<T>
+    where T: DeserializeOwned {
+    let response = a_send_request(client, request).await?;
+    deserialize_body::<T>(&response.body)
+}
+
+/// (Async) Fetches the raw body of a successful response from some route.
+#[cfg(feature = "async")]
+pub(crate) async fn a_fetch_route_raw(client: &Client, route: &Route) -> Result<Vec<u8>> {
+    let request = route_request(client, route)?;
+    let response = a_send_request(client, &request).await?;
+    Ok(response.body)
+}
+
+/// (Async) Fetches a deserializable struct/enum/... from some route, alongside metadata about
+/// the response. (This is never coalesced, as each caller needs their own response's metadata.)
+#[cfg(feature = "async")]
+pub(crate) async fn a_fetch_route_meta<T>(client: &Client, route: &Route) -> Result<Fetched<T>>
+    where T: DeserializeOwned {
+    let request = route_request(client, route)?;
+    let response = a_send_request(client, &request).await?;
+    let fetched_at = SystemTime::now();
+
+    let value = deserialize_body::<T>(&response.body)?;
+
+    Ok(Fetched {
+        value,
+        status: response.status,
+        ratelimit: RatelimitInfo::from_headers(&response.headers),
+        headers: response.headers,
+        fetched_at,
+    })
+}
+
+///////////////////////////////////   tests   ///////////////////////////////////
+
+#[cfg(all(test, feature = "ureq"))]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use super::send_request;
+    use crate::error::Error;
+    use crate::http::Client;
+    use crate::http::fetched::RatelimitInfo;
+
+    /// Answers each of the given (raw HTTP) responses to one connection, on a local port.
+    fn serve(responses: Vec<&'static str>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buf = [0; 4096];
+                let _ = stream.read(&mut buf).unwrap();
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        format!("http://{}/v1/brawlers/", addr)
+    }
+
+    /// Tests that responses through `ureq` are turned into results like with `reqwest`.
+    #[test]
+    fn ureq_send_request() {
+        let url = serve(vec![
+            "HTTP/1.1 200 OK\r\nx-ratelimit-remaining: 5\r\ncontent-length: 13\r\n\
+             connection: close\r\n\r\n{\"items\": []}",
+            "HTTP/1.1 429 Too Many Requests\r\nx-ratelimit-remaining: 0\r\n\
+             x-ratelimit-reset: 1600000000\r\ncontent-length: 2\r\nconnection: close\r\n\r\n{}",
+        ]);
+        let client = Client::new("my auth key");
+        let request = client.endpoint_request(&url);
+
+        let response = send_request(&client, &request).unwrap();
+        assert_eq!(response.body, br#"{"items": []}"#.to_vec());
+        assert_eq!(RatelimitInfo::from_headers(&response.headers).remaining, Some(5));
+
+        match send_request(&client, &request) {
+            Err(Error::Ratelimited { remaining: Some(0), .. }) => {},
+            other => panic!("Expected a Ratelimited error, got {:?}", other),
+        }
+    }
+
+    /// Tests that transport errors through `ureq` are turned into `Request` errors.
+    #[test]
+    fn ureq_transport_error() {
+        let addr = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();  // closed
+        let client = Client::new("my auth key");
+        let request = client.endpoint_request(&format!("http://{}/v1/brawlers/", addr));
+
+        match send_request(&client, &request) {
+            Err(Error::Request(ref err)) => assert!(err.ureq_error().is_some()),
+            other => panic!("Expected a Request error, got {:?}", other),
+        }
     }
 }
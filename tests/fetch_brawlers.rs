@@ -7,7 +7,7 @@ mod tests {
     use brawl_api::prelude::*;
     use super::common;
 
-    const TEST_BRAWLER_ID: usize = Brawlers::Shelly as usize;
+    const TEST_BRAWLER: Brawlers = Brawlers::Shelly;
 
     /// Checks if brawler list etching does not error.
     #[test]
@@ -22,6 +22,6 @@ mod tests {
     fn brawler_fetch() {
         let client = common::create_test_client();
 
-        Brawler::fetch(&client, TEST_BRAWLER_ID).unwrap();
+        Brawler::fetch(&client, TEST_BRAWLER.into()).unwrap();
     }
 }
\ No newline at end of file
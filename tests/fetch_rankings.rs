@@ -8,7 +8,7 @@ mod tests {
     use super::common;
 
     const TEST_RANK_REGION: &str = "global";
-    const TEST_BRAWLER_ID: usize = Brawlers::Shelly as usize;
+    const TEST_BRAWLER: Brawlers = Brawlers::Shelly;
 
     /// Checks if player ranking fetching does not error.
     #[test]
@@ -31,7 +31,7 @@ mod tests {
     fn rankings_brawlers_fetch() {
         let client = common::create_test_client();
 
-        BrawlerLeaderboard::fetch(&client, TEST_RANK_REGION, TEST_BRAWLER_ID, 5)
+        BrawlerLeaderboard::fetch(&client, TEST_RANK_REGION, TEST_BRAWLER.into(), 5)
             .unwrap();
     }
 }
\ No newline at end of file